@@ -1,5 +1,10 @@
 use std::env;
 
+use crate::models::quiz::{OptionLimits, ParseSettings};
+
+/// Where the quiz library lives unless `QUIZ_LIBRARY_DIR` says otherwise.
+pub const DEFAULT_QUIZ_LIBRARY_DIR: &str = "./data/quizzes";
+
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub port: u16,
@@ -7,7 +12,13 @@ pub struct AppConfig {
     pub max_players: usize,
     pub question_time_sec: u64,
     pub reconnect_timeout_sec: u64,
+    /// Maximum size of a quiz upload, and of a bundle's extracted contents.
+    pub max_upload_bytes: usize,
     pub static_dir: Option<String>,
+    /// Option counts every question must stay within; quizzes may narrow them.
+    pub option_limits: OptionLimits,
+    /// Directory the quiz library is stored in.
+    pub quiz_library_dir: String,
 }
 
 impl AppConfig {
@@ -18,9 +29,39 @@ impl AppConfig {
             max_players: env_var_or("MAX_PLAYERS", 50),
             question_time_sec: env_var_or("QUESTION_TIME_SEC", 20),
             reconnect_timeout_sec: env_var_or("RECONNECT_TIMEOUT", 120),
+            max_upload_bytes: env_var_or("MAX_UPLOAD_BYTES", 10 * 1024 * 1024),
             static_dir: env::var("STATIC_DIR").ok(),
+            option_limits: option_limits_from_env(),
+            quiz_library_dir: env::var("QUIZ_LIBRARY_DIR")
+                .unwrap_or_else(|_| DEFAULT_QUIZ_LIBRARY_DIR.into()),
         }
     }
+
+    /// Settings for parsing uploaded quizzes on this server.
+    pub fn parse_settings(&self) -> ParseSettings {
+        ParseSettings {
+            default_time_limit: self.question_time_sec,
+            option_limits: self.option_limits,
+        }
+    }
+}
+
+fn option_limits_from_env() -> OptionLimits {
+    let default = OptionLimits::default();
+    let min = env_var_or("MIN_OPTIONS", default.min);
+    let max = env_var_or("MAX_OPTIONS", default.max);
+    OptionLimits::new(min, max).unwrap_or_else(|| {
+        tracing::warn!(
+            min,
+            max,
+            "MIN_OPTIONS/MAX_OPTIONS must satisfy {} <= min <= max <= {}; using {}..={}",
+            OptionLimits::FLOOR,
+            OptionLimits::CEILING,
+            default.min,
+            default.max
+        );
+        default
+    })
 }
 
 fn env_var_or<T: std::str::FromStr>(key: &str, default: T) -> T {
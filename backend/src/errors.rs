@@ -5,7 +5,11 @@ use serde_json::json;
 #[derive(Debug)]
 pub enum AppError {
     InvalidUpload(String),
+    InvalidRequest(String),
     InvalidQuizFile(Vec<ParseError>),
+    UploadTooLarge(usize),
+    UnsupportedMediaType(String),
+    AssetNotFound,
     QuizNotFound,
     SessionNotFound,
     SessionNotJoinable,
@@ -14,12 +18,130 @@ pub enum AppError {
     Internal(String),
 }
 
+/// A problem found while reading a quiz document. Text-format errors point at a
+/// 1-based `line`; structured (JSON/YAML) errors also carry a `path` such as
+/// `questions[2].options`, with `line` set to 0 when no line is known. CSV errors
+/// carry the spreadsheet `row` (header is row 1) and, when one cell is at fault,
+/// its header as `column`.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ParseError {
     pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    /// Character range in the source, filled in for errors that have a `line`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<TextSpan>,
     pub message: String,
 }
 
+/// A character range in a quiz document. Lines and columns are 1-based, columns
+/// count characters and `end_column` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct TextSpan {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl TextSpan {
+    /// The non-blank part of `line` in `content`, or `None` if there is no such line.
+    pub fn of_line(content: &str, line: usize) -> Option<Self> {
+        let text = content.lines().nth(line.checked_sub(1)?)?;
+        let start = text.chars().take_while(|c| c.is_whitespace()).count();
+        let end = text.trim_end().chars().count();
+        Some(Self {
+            start_line: line,
+            start_column: start + 1,
+            end_line: line,
+            end_column: end.max(start) + 1,
+        })
+    }
+}
+
+/// A problem that does not stop the quiz from being used, located the same way
+/// as `ParseError`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ParseWarning {
+    pub code: WarningCode,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<TextSpan>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningCode {
+    DuplicateQuestion,
+    DuplicateOption,
+    LongText,
+    IgnoredTitle,
+    SuspiciousWhitespace,
+    TooManyQuestions,
+}
+
+impl ParseWarning {
+    pub fn new(code: WarningCode, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            line,
+            path: None,
+            row: None,
+            span: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            path: None,
+            row: None,
+            column: None,
+            span: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_path(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..Self::new(0, message)
+        }
+    }
+
+    pub fn at_row(line: usize, row: usize, message: impl Into<String>) -> Self {
+        Self {
+            row: Some(row),
+            ..Self::new(line, message)
+        }
+    }
+
+    pub fn at_cell(
+        line: usize,
+        row: usize,
+        column: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            row: Some(row),
+            column: Some(column.into()),
+            ..Self::new(line, message)
+        }
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, body) = match self {
@@ -27,10 +149,26 @@ impl IntoResponse for AppError {
                 StatusCode::BAD_REQUEST,
                 json!({ "error": "invalid_upload", "message": msg }),
             ),
+            AppError::InvalidRequest(msg) => (
+                StatusCode::BAD_REQUEST,
+                json!({ "error": "invalid_request", "message": msg }),
+            ),
             AppError::InvalidQuizFile(errors) => (
                 StatusCode::BAD_REQUEST,
                 json!({ "error": "invalid_quiz_file", "messages": errors }),
             ),
+            AppError::UploadTooLarge(max_bytes) => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                json!({ "error": "upload_too_large", "message": format!("Upload exceeds the maximum size of {max_bytes} bytes.") }),
+            ),
+            AppError::UnsupportedMediaType(path) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                json!({ "error": "unsupported_media_type", "message": format!("'{path}' is not a supported image or audio file (png, jpg, gif, webp, mp3, ogg, wav, m4a).") }),
+            ),
+            AppError::AssetNotFound => (
+                StatusCode::NOT_FOUND,
+                json!({ "error": "asset_not_found", "message": "No media file found at that path for this session." }),
+            ),
             AppError::QuizNotFound => (
                 StatusCode::NOT_FOUND,
                 json!({ "error": "quiz_not_found", "message": "No uploaded quiz found with the given ID. Please re-upload." }),
@@ -0,0 +1,21 @@
+use axum::Json;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use serde_json::json;
+
+use crate::AppState;
+use crate::models::session::{MAX_TIME_LIMIT_SEC, MIN_TIME_LIMIT_SEC};
+
+/// Limits the frontend needs to guide quiz authors before they upload.
+pub async fn server_info(State(state): State<AppState>) -> impl IntoResponse {
+    let config = &state.config;
+    Json(json!({
+        "option_limits": config.option_limits,
+        "time_limit_sec": {
+            "min": MIN_TIME_LIMIT_SEC,
+            "max": MAX_TIME_LIMIT_SEC,
+            "default": config.question_time_sec,
+        },
+        "max_upload_bytes": config.max_upload_bytes,
+    }))
+}
@@ -1,3 +1,7 @@
+pub mod info;
+pub mod quiz_export;
+pub mod quiz_library;
 pub mod quiz_upload;
+pub mod scoring_rules;
 pub mod session;
 pub mod ws;
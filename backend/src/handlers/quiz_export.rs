@@ -0,0 +1,55 @@
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+
+use crate::AppState;
+use crate::errors::AppError;
+use crate::services::quiz_export::export_quiz;
+
+/// Downloads an uploaded quiz in the text format: GET /api/quiz/:quiz_id/export
+pub async fn export_quiz_text(
+    State(state): State<AppState>,
+    Path(quiz_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let quiz = state
+        .session_manager
+        .get_quiz(&quiz_id)
+        .ok_or(AppError::QuizNotFound)?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/plain; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.txt\"", file_stem(&quiz.title)),
+            ),
+        ],
+        export_quiz(&quiz),
+    ))
+}
+
+/// ASCII-only, header-safe file name derived from the quiz title.
+fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if stem.is_empty() {
+        "quiz".to_string()
+    } else {
+        stem
+    }
+}
@@ -0,0 +1,137 @@
+use std::io;
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Multipart, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::AppState;
+use crate::errors::AppError;
+use crate::handlers::quiz_upload::{check_upload, read_upload};
+use crate::services::quiz_library::{QuizLibrary, StoredQuiz, is_valid_quiz_id};
+
+#[derive(Deserialize)]
+pub struct RevisionQuery {
+    pub version: Option<u32>,
+}
+
+/// Lists the latest revision of every saved quiz: GET /api/quizzes
+pub async fn list_quizzes(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let quizzes = with_library(&state, |library| library.list()).await?;
+    Ok(Json(json!({ "quizzes": quizzes })))
+}
+
+/// Fetches a saved quiz, the latest revision unless `?version=` is given:
+/// GET /api/quizzes/:id
+pub async fn get_quiz(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<RevisionQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let stored = load_quiz(&state, &id, query.version).await?;
+    Ok(Json(stored))
+}
+
+/// Lists every saved revision of a quiz: GET /api/quizzes/:id/revisions
+pub async fn list_revisions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    check_id(&id)?;
+    let revisions = with_library(&state, move |library| library.revisions(&id)).await?;
+    if revisions.is_empty() {
+        return Err(AppError::QuizNotFound);
+    }
+    Ok(Json(json!({ "revisions": revisions })))
+}
+
+/// Saves an uploaded quiz file as the next revision of `id`: PUT /api/quizzes/:id
+///
+/// Takes the same multipart form as `POST /api/quiz`. Media bundles are not
+/// stored in the library.
+pub async fn save_quiz(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    check_id(&id)?;
+    let upload = read_upload(multipart, state.config.max_upload_bytes).await?;
+    if !upload.assets.is_empty() {
+        return Err(AppError::InvalidUpload(
+            "The quiz library cannot store media bundles; upload them directly instead".into(),
+        ));
+    }
+    let report = check_upload(&upload, &state.config.parse_settings());
+    let quiz = report.quiz.map_err(AppError::InvalidQuizFile)?;
+
+    let stored = with_library(&state, move |library| library.save(&id, quiz)).await?;
+    let status = if stored.version == 1 {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+    Ok((
+        status,
+        Json(json!({
+            "quiz": stored.summary(),
+            "warnings": report.warnings,
+        })),
+    ))
+}
+
+/// Removes a quiz and all its revisions: DELETE /api/quizzes/:id
+pub async fn delete_quiz(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    check_id(&id)?;
+    if with_library(&state, move |library| library.delete(&id)).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::QuizNotFound)
+    }
+}
+
+/// A revision of a saved quiz, or `QuizNotFound`.
+pub(crate) async fn load_quiz(
+    state: &AppState,
+    id: &str,
+    version: Option<u32>,
+) -> Result<StoredQuiz, AppError> {
+    check_id(id)?;
+    let id = id.to_string();
+    with_library(state, move |library| library.get(&id, version))
+        .await?
+        .ok_or(AppError::QuizNotFound)
+}
+
+/// Run a library call on the blocking pool, since implementations may do file I/O.
+async fn with_library<T: Send + 'static>(
+    state: &AppState,
+    call: impl FnOnce(&dyn QuizLibrary) -> io::Result<T> + Send + 'static,
+) -> Result<T, AppError> {
+    let library = Arc::clone(&state.library);
+    tokio::task::spawn_blocking(move || call(library.as_ref()))
+        .await
+        .map_err(io::Error::other)
+        .and_then(|result| result)
+        .map_err(storage_error)
+}
+
+fn check_id(id: &str) -> Result<(), AppError> {
+    if is_valid_quiz_id(id) {
+        Ok(())
+    } else {
+        Err(AppError::InvalidRequest(format!(
+            "Invalid quiz ID '{id}' (use 1-64 lowercase letters, digits, '-' or '_')"
+        )))
+    }
+}
+
+fn storage_error(e: io::Error) -> AppError {
+    tracing::error!(error = %e, "quiz library storage failed");
+    AppError::Internal("Quiz library storage failed".into())
+}
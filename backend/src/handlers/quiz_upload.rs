@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use axum::Json;
 use axum::extract::{Multipart, State};
 use axum::http::StatusCode;
@@ -5,39 +7,50 @@ use axum::response::IntoResponse;
 use serde_json::json;
 
 use crate::AppState;
-use crate::errors::AppError;
-use crate::models::quiz::parse_quiz;
+use crate::errors::{AppError, TextSpan};
+use crate::models::asset::QuizAssets;
+use crate::models::quiz::ParseSettings;
+use crate::services::quiz_bundle::{check_media_references, is_zip, read_bundle};
+use crate::services::quiz_import::{QuizFormat, QuizReport, lint_quiz_document};
 
-pub async fn upload_quiz(
-    State(state): State<AppState>,
-    mut multipart: Multipart,
-) -> Result<impl IntoResponse, AppError> {
-    let mut file_content = None;
+/// A quiz document read from the upload form, before parsing.
+pub(crate) struct QuizUpload {
+    pub content: String,
+    pub file_name: Option<String>,
+    pub format: QuizFormat,
+    pub assets: QuizAssets,
+}
 
-    while let Some(field) = multipart
-        .next_field()
-        .await
-        .map_err(|e| AppError::InvalidUpload(e.to_string()))?
-    {
-        if field.name() == Some("quiz_file") {
-            let bytes = field
-                .bytes()
-                .await
-                .map_err(|e| AppError::InvalidUpload(e.to_string()))?;
-            file_content = Some(
-                String::from_utf8(bytes.to_vec())
-                    .map_err(|_| AppError::InvalidUpload("File is not valid UTF-8".into()))?,
-            );
-            break;
+/// Parse an upload and check its media references, attaching source spans to
+/// line-located errors.
+pub(crate) fn check_upload(upload: &QuizUpload, settings: &ParseSettings) -> QuizReport {
+    let mut report = lint_quiz_document(
+        upload.format,
+        &upload.content,
+        upload.file_name.as_deref(),
+        settings,
+    );
+    if let Ok(quiz) = &report.quiz {
+        let media_errors = check_media_references(quiz, &upload.assets, &upload.content);
+        if !media_errors.is_empty() {
+            report.quiz = Err(media_errors);
         }
     }
+    if let Err(errors) = &mut report.quiz {
+        for error in errors.iter_mut().filter(|e| e.line > 0 && e.span.is_none()) {
+            error.span = TextSpan::of_line(&upload.content, error.line);
+        }
+    }
+    report
+}
 
-    let content = file_content.ok_or_else(|| {
-        AppError::InvalidUpload("Expected a text file upload in the 'quiz_file' field".into())
-    })?;
-
-    let quiz =
-        parse_quiz(&content, state.config.question_time_sec).map_err(AppError::InvalidQuizFile)?;
+pub async fn upload_quiz(
+    State(state): State<AppState>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    let upload = read_upload(multipart, state.config.max_upload_bytes).await?;
+    let report = check_upload(&upload, &state.config.parse_settings());
+    let quiz = report.quiz.map_err(AppError::InvalidQuizFile)?;
 
     let preview: Vec<_> = quiz
         .questions
@@ -46,6 +59,8 @@ pub async fn upload_quiz(
             json!({
                 "text": q.text,
                 "option_count": q.options.len(),
+                "has_explanation": q.explanation.is_some(),
+                "has_media": q.media.is_some() || q.options.iter().any(|o| o.media.is_some()),
             })
         })
         .collect();
@@ -53,20 +68,112 @@ pub async fn upload_quiz(
     let quiz_id = uuid::Uuid::new_v4().to_string();
     let question_count = quiz.questions.len();
     let title = quiz.title.clone();
+    let metadata = serde_json::to_value(&quiz.metadata).unwrap_or_default();
+    let assets = upload.assets;
+    let asset_count = assets.len();
 
     state.session_manager.store_quiz(quiz_id.clone(), quiz);
+    if !assets.is_empty() {
+        state
+            .session_manager
+            .store_quiz_assets(quiz_id.clone(), Arc::new(assets));
+    }
 
-    let mut response = json!({
-        "title": title,
-        "question_count": question_count,
-        "preview": preview,
-        "quiz_id": quiz_id,
-    });
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "title": title,
+            "question_count": question_count,
+            "preview": preview,
+            "quiz_id": quiz_id,
+            "asset_count": asset_count,
+            "metadata": metadata,
+            "warnings": report.warnings,
+        })),
+    ))
+}
+
+/// Checks a quiz upload without storing it: POST /api/quiz/lint
+pub async fn lint_quiz(
+    State(state): State<AppState>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    let upload = read_upload(multipart, state.config.max_upload_bytes).await?;
+    let report = check_upload(&upload, &state.config.parse_settings());
+    let errors = report.quiz.err().unwrap_or_default();
+
+    Ok(Json(json!({
+        "valid": errors.is_empty(),
+        "errors": errors,
+        "warnings": report.warnings,
+    })))
+}
+
+pub(crate) async fn read_upload(
+    mut multipart: Multipart,
+    max_bytes: usize,
+) -> Result<QuizUpload, AppError> {
+    let mut file_bytes = None;
+    let mut file_name = None;
+    let mut requested_format = None;
 
-    if question_count > 100 {
-        response["warning"] =
-            json!("Quiz has more than 100 questions. This may result in very long game sessions.");
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| multipart_error(e, max_bytes))?
+    {
+        match field.name() {
+            Some("quiz_file") => {
+                file_name = field.file_name().map(str::to_string);
+                let bytes = field
+                    .bytes()
+                    .await
+                    .map_err(|e| multipart_error(e, max_bytes))?;
+                file_bytes = Some(bytes);
+            }
+            Some("format") => {
+                let name = field
+                    .text()
+                    .await
+                    .map_err(|e| multipart_error(e, max_bytes))?;
+                let format = QuizFormat::from_name(&name).ok_or_else(|| {
+                    AppError::InvalidUpload(format!(
+                        "Unsupported format '{name}' (expected text, json, yaml or csv)"
+                    ))
+                })?;
+                requested_format = Some(format);
+            }
+            _ => {}
+        }
     }
 
-    Ok((StatusCode::OK, Json(response)))
+    let bytes = file_bytes.ok_or_else(|| {
+        AppError::InvalidUpload("Expected a text file upload in the 'quiz_file' field".into())
+    })?;
+
+    let (content, file_name, assets) = if is_zip(&bytes) {
+        let bundle = read_bundle(&bytes, max_bytes)?;
+        (bundle.quiz_text, Some(bundle.quiz_file_name), bundle.assets)
+    } else {
+        let content = String::from_utf8(bytes.to_vec())
+            .map_err(|_| AppError::InvalidUpload("File is not valid UTF-8".into()))?;
+        (content, file_name, QuizAssets::new())
+    };
+
+    let format =
+        requested_format.unwrap_or_else(|| QuizFormat::detect(file_name.as_deref(), &content));
+    Ok(QuizUpload {
+        content,
+        file_name,
+        format,
+        assets,
+    })
+}
+
+fn multipart_error(e: axum::extract::multipart::MultipartError, max_bytes: usize) -> AppError {
+    if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::UploadTooLarge(max_bytes)
+    } else {
+        AppError::InvalidUpload(e.to_string())
+    }
 }
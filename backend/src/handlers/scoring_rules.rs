@@ -0,0 +1,23 @@
+use axum::Json;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use serde_json::json;
+
+use crate::AppState;
+
+/// Every scoring rule a host can pick, built-in or registered by the embedding
+/// application, with a JSON Schema for its parameters.
+pub async fn list_scoring_rules(State(state): State<AppState>) -> impl IntoResponse {
+    let rules: Vec<_> = state
+        .scorers
+        .iter()
+        .map(|scorer| {
+            json!({
+                "name": scorer.name(),
+                "display_name": scorer.display_name(),
+                "params_schema": scorer.params_schema(),
+            })
+        })
+        .collect();
+    Json(rules)
+}
@@ -1,28 +1,77 @@
+use std::collections::BTreeMap;
+
 use axum::Json;
 use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::response::IntoResponse;
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::AppState;
 use crate::errors::AppError;
+use crate::handlers::quiz_library::load_quiz;
+use crate::models::draw::{DrawRequest, QuestionDraw};
+use crate::models::quiz::Quiz;
 
+/// Names either an uploaded quiz (`quiz_id`) or a saved one (`library_id`, at
+/// its latest revision unless `version` is given).
+///
+/// With `question_count` or `tag_quotas`, the session plays a random subset of
+/// the quiz, drawn with `draw_seed` (random if absent).
 #[derive(Deserialize)]
 pub struct CreateSessionRequest {
-    pub quiz_id: String,
+    pub quiz_id: Option<String>,
+    pub library_id: Option<String>,
+    pub version: Option<u32>,
+    pub question_count: Option<usize>,
+    #[serde(default)]
+    pub tag_quotas: BTreeMap<String, usize>,
+    pub draw_seed: Option<u64>,
 }
 
 pub async fn create_session(
     State(state): State<AppState>,
     Json(req): Json<CreateSessionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let quiz = state
-        .session_manager
-        .get_quiz(&req.quiz_id)
-        .ok_or(AppError::QuizNotFound)?;
+    let (quiz, assets) = match (&req.quiz_id, &req.library_id) {
+        (Some(quiz_id), None) => (
+            state
+                .session_manager
+                .get_quiz(quiz_id)
+                .ok_or(AppError::QuizNotFound)?,
+            state.session_manager.get_quiz_assets(quiz_id),
+        ),
+        (None, Some(library_id)) => (load_quiz(&state, library_id, req.version).await?.quiz, None),
+        _ => {
+            return Err(AppError::InvalidRequest(
+                "Expected exactly one of 'quiz_id' or 'library_id'".into(),
+            ));
+        }
+    };
+
+    let draw = if req.question_count.is_some() || !req.tag_quotas.is_empty() {
+        let request = DrawRequest {
+            question_count: req.question_count,
+            tag_quotas: req.tag_quotas,
+            seed: req.draw_seed,
+        };
+        Some(request.draw(&quiz).map_err(AppError::InvalidRequest)?)
+    } else {
+        None
+    };
+    let quiz = match &draw {
+        Some(draw) => draw.apply(quiz),
+        None => quiz,
+    };
 
     let session = state.session_manager.create_session(quiz)?;
+    {
+        let mut session_write = session.write().await;
+        if let Some(assets) = assets {
+            session_write.assets = assets;
+        }
+        session_write.draw = draw;
+    }
     let session_read = session.read().await;
     let join_code = session_read.join_code.clone();
 
@@ -32,10 +81,30 @@ pub async fn create_session(
             "join_code": join_code,
             "session_status": "lobby",
             "ws_url": format!("/ws/host/{}", join_code),
+            "scoring_rule": session_read.scoring_rule.name(),
+            "time_limit_sec": session_read.time_limit_sec,
+            "shuffle": session_read.shuffle,
+            "total_questions": session_read.total_questions(),
+            "draw": session_read.draw.as_ref().map(|d| draw_json(d, &session_read.quiz)),
         })),
     ))
 }
 
+/// The draw for the host, listing each drawn question next to its bank index.
+fn draw_json(draw: &QuestionDraw, quiz: &Quiz) -> Value {
+    let questions: Vec<Value> = draw
+        .question_indices
+        .iter()
+        .zip(&quiz.questions)
+        .map(|(index, q)| json!({ "bank_index": index, "text": q.text, "tags": q.tags }))
+        .collect();
+    json!({
+        "seed": draw.seed,
+        "bank_size": draw.bank_size,
+        "questions": questions,
+    })
+}
+
 pub async fn get_session(
     State(state): State<AppState>,
     Path(join_code): Path<String>,
@@ -59,3 +128,28 @@ pub async fn get_session(
         "ws_url": format!("/ws/player/{}", session_read.join_code),
     })))
 }
+
+/// Serves a media file from the session's quiz bundle: GET /api/sessions/:join_code/assets/*path
+pub async fn get_session_asset(
+    State(state): State<AppState>,
+    Path((join_code, path)): Path<(String, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    let session = state
+        .session_manager
+        .get_session(&join_code)
+        .ok_or(AppError::SessionNotFound)?;
+
+    let session_read = session.read().await;
+    let asset = session_read
+        .assets
+        .get(&path)
+        .ok_or(AppError::AssetNotFound)?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, asset.content_type),
+            (header::CACHE_CONTROL, "private, max-age=3600"),
+        ],
+        asset.data.clone(),
+    ))
+}
@@ -12,10 +12,9 @@ use tokio::sync::broadcast;
 
 use crate::AppState;
 use crate::models::player::{ConnectionStatus, DEFAULT_AVATAR, Player};
-use crate::models::scoring_rule::ScoringRule;
 use crate::models::session::SessionStatus;
-use crate::services::game_engine::do_end_question;
 use crate::services::game_engine::{self, GameEvent};
+use crate::services::game_engine::{do_end_question, end_tiebreak_question};
 
 /// Reconnection timeout — after this, player is permanently removed.
 const RECONNECT_TIMEOUT_SECS: u64 = 120;
@@ -28,6 +27,8 @@ static BROADCAST_CHANNELS: Lazy<DashMap<String, broadcast::Sender<GameEvent>>> =
 pub struct PlayerParams {
     pub name: Option<String>,
     pub avatar: Option<String>,
+    /// Team to join in a team game; a new name adds the team.
+    pub team: Option<String>,
 }
 
 /// Host WebSocket: GET /ws/host/:join_code
@@ -38,6 +39,7 @@ pub async fn ws_host(
 ) -> impl IntoResponse {
     let session = state.session_manager.get_session(&join_code);
     let session_manager = state.session_manager.clone();
+    let scorers = state.scorers.clone();
 
     ws.on_upgrade(move |socket| async move {
         let Some(session) = session else {
@@ -49,7 +51,11 @@ pub async fn ws_host(
             let host_id = uuid::Uuid::new_v4().to_string();
             s.host_id = Some(host_id);
             if s.status == SessionStatus::Paused {
-                s.status = SessionStatus::Active;
+                s.status = if s.tiebreak.is_some() {
+                    SessionStatus::Tiebreak
+                } else {
+                    SessionStatus::Active
+                };
                 true
             } else {
                 false
@@ -125,14 +131,13 @@ pub async fn ws_host(
                                     break;
                                 }
                                 Some("set_scoring_rule") => {
-                                    if let Ok(rule) = serde_json::from_value::<ScoringRule>(
-                                        parsed["payload"]["rule"].clone(),
-                                    ) {
-                                        let mut s = recv_session.write().await;
-                                        game_engine::handle_set_scoring_rule(
-                                            &mut s, rule, &recv_tx,
-                                        );
-                                    }
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_set_scoring_rule(
+                                        &mut s,
+                                        &scorers,
+                                        &parsed["payload"],
+                                        &recv_tx,
+                                    );
                                 }
                                 Some("set_time_limit") => {
                                     if let Some(seconds) = parsed["payload"]["seconds"].as_u64() {
@@ -142,15 +147,63 @@ pub async fn ws_host(
                                         );
                                     }
                                 }
+                                Some("set_shuffle") => {
+                                    let payload = &parsed["payload"];
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_set_shuffle(
+                                        &mut s,
+                                        payload["questions"].as_bool().unwrap_or(false),
+                                        payload["options"].as_bool().unwrap_or(false),
+                                        payload["seed"].as_u64(),
+                                        &recv_tx,
+                                    );
+                                }
+                                Some("set_tie_breaker") => {
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_set_tie_breaker(
+                                        &mut s,
+                                        &parsed["payload"],
+                                        &recv_tx,
+                                    );
+                                }
+                                Some("set_elimination") => {
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_set_elimination(
+                                        &mut s,
+                                        &parsed["payload"],
+                                        &recv_tx,
+                                    );
+                                }
+                                Some("set_teams") => {
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_set_teams(
+                                        &mut s,
+                                        &parsed["payload"],
+                                        &recv_tx,
+                                    );
+                                }
+                                Some("auto_balance_teams") => {
+                                    let mut s = recv_session.write().await;
+                                    game_engine::handle_auto_balance_teams(&mut s, &recv_tx);
+                                }
                                 Some("end_question") => {
-                                    let current_question = {
+                                    let (status, current_question, tiebreak_question) = {
                                         let s = recv_session.read().await;
-                                        s.current_question
+                                        let tiebreak_question =
+                                            s.tiebreak.as_ref().and_then(|t| t.question);
+                                        (s.status, s.current_question, tiebreak_question)
                                     };
-                                    if current_question >= 0 {
-                                        let s = recv_session.clone();
-                                        let t = recv_tx.clone();
-                                        let sm = recv_sm.clone();
+                                    let s = recv_session.clone();
+                                    let t = recv_tx.clone();
+                                    let sm = recv_sm.clone();
+                                    if status == SessionStatus::Tiebreak {
+                                        if let Some(tiebreak_index) = tiebreak_question {
+                                            tokio::spawn(async move {
+                                                end_tiebreak_question(s, t, tiebreak_index, sm)
+                                                    .await;
+                                            });
+                                        }
+                                    } else if current_question >= 0 {
                                         tokio::spawn(async move {
                                             do_end_question(s, t, current_question as usize, sm)
                                                 .await;
@@ -172,10 +225,10 @@ pub async fn ws_host(
             _ = recv_task => {},
         }
 
-        // Host disconnected — pause game if active, start reconnection timeout
+        // Host disconnected — pause game if active or in a tiebreak, start reconnection timeout
         let was_active = {
             let mut s = session.write().await;
-            if s.status == SessionStatus::Active {
+            if matches!(s.status, SessionStatus::Active | SessionStatus::Tiebreak) {
                 s.status = SessionStatus::Paused;
                 let _ = tx.send(GameEvent::BroadcastAll(
                     json!({
@@ -201,11 +254,9 @@ pub async fn ws_host(
                 let mut s = timeout_session.write().await;
                 if s.status == SessionStatus::Paused {
                     s.status = SessionStatus::Finished;
+                    s.tiebreak = None;
 
-                    let player_refs: Vec<&crate::models::player::Player> =
-                        s.players.values().collect();
-                    let leaderboard =
-                        crate::models::leaderboard::compute_leaderboard(&player_refs, true);
+                    let leaderboard = s.leaderboard(true);
                     let leaderboard_json: Vec<_> = leaderboard
                         .iter()
                         .map(|e| {
@@ -243,7 +294,7 @@ pub async fn ws_host(
     })
 }
 
-/// Player WebSocket: GET /ws/player/:join_code?name=DisplayName
+/// Player WebSocket: GET /ws/player/:join_code?name=DisplayName&team=TeamName
 pub async fn ws_player(
     ws: WebSocketUpgrade,
     State(state): State<AppState>,
@@ -342,7 +393,7 @@ pub async fn ws_player(
                 // New player join
                 let player_id = uuid::Uuid::new_v4().to_string();
 
-                let (final_name, name_was_changed, player_count) = {
+                let (final_name, name_was_changed, player_count, team, team_update) = {
                     let mut s = session.write().await;
                     if !s.is_joinable() {
                         return;
@@ -369,9 +420,26 @@ pub async fn ws_player(
                     }
 
                     let name_changed = final_name != requested_name;
-                    let player = Player::new(player_id.clone(), final_name.clone(), avatar.clone());
+                    let mut player =
+                        Player::new(player_id.clone(), final_name.clone(), avatar.clone());
+                    let team = params
+                        .team
+                        .as_deref()
+                        .filter(|t| !t.trim().is_empty())
+                        .map(|t| s.teams.join(t));
+                    if let Some(Ok(name)) = &team {
+                        player.team = Some(name.clone());
+                    }
                     s.players.insert(player_id.clone(), player);
-                    (final_name, name_changed, s.player_count())
+                    let team_update =
+                        matches!(team, Some(Ok(_))).then(|| game_engine::teams_message(&s));
+                    (
+                        final_name,
+                        name_changed,
+                        s.player_count(),
+                        team,
+                        team_update,
+                    )
                 };
 
                 // Send name_assigned if name was modified
@@ -397,10 +465,27 @@ pub async fn ws_player(
                             "display_name": final_name,
                             "avatar": avatar,
                             "player_count": player_count,
+                            "team": team.as_ref().and_then(|t| t.as_ref().ok()),
                         }
                     })
                     .to_string(),
                 ));
+                match (team, team_update) {
+                    (_, Some(message)) => {
+                        let _ = tx.send(GameEvent::BroadcastAll(message));
+                    }
+                    (Some(Err(message)), _) => {
+                        let _ = tx.send(GameEvent::PlayerOnly {
+                            player_id: player_id.clone(),
+                            message: json!({
+                                "type": "error",
+                                "payload": { "code": "invalid_team", "message": message }
+                            })
+                            .to_string(),
+                        });
+                    }
+                    _ => {}
+                }
 
                 (player_id, final_name, false)
             };
@@ -436,22 +521,40 @@ pub async fn ws_player(
             while let Some(Ok(msg)) = ws_receiver.next().await {
                 match msg {
                     Message::Text(ref text) => {
-                        if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(text)
-                            && parsed["type"].as_str() == Some("submit_answer")
-                        {
-                            let qi =
-                                parsed["payload"]["question_index"].as_u64().unwrap_or(0) as usize;
+                        if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(text) {
                             let si =
                                 parsed["payload"]["selected_index"].as_u64().unwrap_or(0) as usize;
-                            game_engine::handle_answer(
-                                &recv_session,
-                                &recv_tx,
-                                &pid_for_recv,
-                                qi,
-                                si,
-                                recv_sm.clone(),
-                            )
-                            .await;
+                            match parsed["type"].as_str() {
+                                Some("submit_answer") => {
+                                    let qi =
+                                        parsed["payload"]["question_index"].as_u64().unwrap_or(0)
+                                            as usize;
+                                    game_engine::handle_answer(
+                                        &recv_session,
+                                        &recv_tx,
+                                        &pid_for_recv,
+                                        qi,
+                                        si,
+                                        recv_sm.clone(),
+                                    )
+                                    .await;
+                                }
+                                Some("submit_tiebreak_answer") => {
+                                    let ti =
+                                        parsed["payload"]["tiebreak_index"].as_u64().unwrap_or(0)
+                                            as usize;
+                                    game_engine::handle_tiebreak_answer(
+                                        &recv_session,
+                                        &recv_tx,
+                                        &pid_for_recv,
+                                        ti,
+                                        si,
+                                        recv_sm.clone(),
+                                    )
+                                    .await;
+                                }
+                                _ => {}
+                            }
                         }
                     }
                     Message::Close(_) => break,
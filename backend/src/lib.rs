@@ -4,33 +4,101 @@ pub mod handlers;
 pub mod models;
 pub mod services;
 
+use std::sync::Arc;
+
 use axum::Router;
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{get, post};
 use tower_http::cors::CorsLayer;
 
 use crate::config::AppConfig;
+use crate::services::quiz_library::{FsQuizLibrary, QuizLibrary};
+use crate::services::scorer_registry::ScorerRegistry;
 use crate::services::session_manager::SessionManager;
 
 #[derive(Clone)]
 pub struct AppState {
     pub session_manager: SessionManager,
     pub config: AppConfig,
+    pub library: Arc<dyn QuizLibrary>,
+    pub scorers: Arc<ScorerRegistry>,
 }
 
 pub fn build_router(session_manager: SessionManager, config: AppConfig) -> Router {
+    build_router_with_scorers(session_manager, config, ScorerRegistry::default())
+}
+
+/// Like [`build_router`], offering hosts the custom scoring rules registered in
+/// `scorers` alongside the built-in ones. Saved quizzes go to an [`FsQuizLibrary`]
+/// under `config.quiz_library_dir`.
+pub fn build_router_with_scorers(
+    session_manager: SessionManager,
+    config: AppConfig,
+    scorers: ScorerRegistry,
+) -> Router {
+    let library = Arc::new(FsQuizLibrary::new(&config.quiz_library_dir));
+    build_router_with_library(session_manager, config, scorers, library)
+}
+
+/// Like [`build_router_with_scorers`], storing saved quizzes in `library` instead
+/// of under `config.quiz_library_dir`: a [`MemoryQuizLibrary`] to keep nothing on
+/// disk, or an embedder's own [`QuizLibrary`].
+///
+/// [`MemoryQuizLibrary`]: crate::services::quiz_library::MemoryQuizLibrary
+pub fn build_router_with_library(
+    session_manager: SessionManager,
+    config: AppConfig,
+    scorers: ScorerRegistry,
+    library: Arc<dyn QuizLibrary>,
+) -> Router {
+    let max_upload_bytes = config.max_upload_bytes;
     let state = AppState {
         session_manager,
         config,
+        library,
+        scorers: Arc::new(scorers),
     };
 
     Router::new()
         .route("/api/health", get(|| async { "ok" }))
-        .route("/api/quiz", post(handlers::quiz_upload::upload_quiz))
+        .route("/api/info", get(handlers::info::server_info))
+        .route(
+            "/api/scoring-rules",
+            get(handlers::scoring_rules::list_scoring_rules),
+        )
+        .route(
+            "/api/quiz",
+            post(handlers::quiz_upload::upload_quiz).layer(DefaultBodyLimit::max(max_upload_bytes)),
+        )
+        .route(
+            "/api/quiz/lint",
+            post(handlers::quiz_upload::lint_quiz).layer(DefaultBodyLimit::max(max_upload_bytes)),
+        )
+        .route(
+            "/api/quiz/{quiz_id}/export",
+            get(handlers::quiz_export::export_quiz_text),
+        )
+        .route("/api/quizzes", get(handlers::quiz_library::list_quizzes))
+        .route(
+            "/api/quizzes/{id}",
+            get(handlers::quiz_library::get_quiz)
+                .put(handlers::quiz_library::save_quiz)
+                .delete(handlers::quiz_library::delete_quiz)
+                .layer(DefaultBodyLimit::max(max_upload_bytes)),
+        )
+        .route(
+            "/api/quizzes/{id}/revisions",
+            get(handlers::quiz_library::list_revisions),
+        )
         .route("/api/sessions", post(handlers::session::create_session))
         .route(
             "/api/sessions/{join_code}",
             get(handlers::session::get_session),
         )
+        .route(
+            "/api/sessions/{join_code}/assets/{*path}",
+            get(handlers::session::get_session_asset),
+        )
         .route("/ws/host/{join_code}", get(handlers::ws::ws_host))
         .route("/ws/player/{join_code}", get(handlers::ws::ws_player))
         .layer(CorsLayer::permissive())
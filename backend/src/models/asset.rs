@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+/// A media file extracted from an uploaded quiz bundle.
+#[derive(Debug, Clone)]
+pub struct QuizAsset {
+    pub content_type: &'static str,
+    pub data: Vec<u8>,
+}
+
+/// Bundle assets keyed by their normalized relative path (see `normalize_media_path`).
+pub type QuizAssets = HashMap<String, QuizAsset>;
+
+/// MIME type for an asset path, or `None` if the extension is not an accepted
+/// image or audio format.
+pub fn content_type_for(path: &str) -> Option<&'static str> {
+    let ext = path.rsplit_once('.')?.1.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "mp3" => Some("audio/mpeg"),
+        "ogg" => Some("audio/ogg"),
+        "wav" => Some("audio/wav"),
+        "m4a" => Some("audio/mp4"),
+        _ => None,
+    }
+}
+
+/// Session-scoped URL under which an asset is served.
+pub fn asset_url(join_code: &str, path: &str) -> String {
+    let encoded: Vec<String> = path.split('/').map(percent_encode).collect();
+    format!("/api/sessions/{join_code}/assets/{}", encoded.join("/"))
+}
+
+fn percent_encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::quiz::{Quiz, Round};
+use super::shuffle::{MAX_SEED, permutation, random_seed};
+
+/// Which questions a session drew from a larger bank. `question_indices` point
+/// into the uploaded quiz, in bank order; replaying the same request with the
+/// same seed draws the same questions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuestionDraw {
+    pub seed: u64,
+    pub bank_size: usize,
+    pub question_indices: Vec<usize>,
+}
+
+/// How many questions to draw, and how many of them must carry each tag.
+#[derive(Debug, Clone, Default)]
+pub struct DrawRequest {
+    /// Defaults to the sum of the quotas.
+    pub question_count: Option<usize>,
+    pub tag_quotas: BTreeMap<String, usize>,
+    /// A fresh random seed when `None`.
+    pub seed: Option<u64>,
+}
+
+impl DrawRequest {
+    /// Draw from `bank`, filling each tag quota first (in tag order) and then the
+    /// rest of the count from any remaining question. A question counts towards
+    /// at most one quota.
+    pub fn draw(&self, bank: &Quiz) -> Result<QuestionDraw, String> {
+        let quota_total: usize = self.tag_quotas.values().sum();
+        let count = self.question_count.unwrap_or(quota_total);
+        let bank_size = bank.questions.len();
+        if count == 0 {
+            return Err("question_count must be at least 1".into());
+        }
+        if count > bank_size {
+            return Err(format!(
+                "Cannot draw {count} questions from a quiz with {bank_size}"
+            ));
+        }
+        if quota_total > count {
+            return Err(format!(
+                "Tag quotas add up to {quota_total}, more than the {count} questions drawn"
+            ));
+        }
+
+        let seed = self.seed.map_or_else(random_seed, |s| s & MAX_SEED);
+        let order = permutation(bank_size, seed);
+        let mut drawn = vec![false; bank_size];
+        for (tag, &quota) in &self.tag_quotas {
+            let candidates: Vec<usize> = order
+                .iter()
+                .copied()
+                .filter(|&i| !drawn[i] && bank.questions[i].tags.contains(tag))
+                .take(quota)
+                .collect();
+            if candidates.len() < quota {
+                return Err(format!(
+                    "Tag '{tag}' has a quota of {quota} but only {} matching question(s) are available",
+                    candidates.len()
+                ));
+            }
+            for index in candidates {
+                drawn[index] = true;
+            }
+        }
+        let rest: Vec<usize> = order
+            .into_iter()
+            .filter(|&i| !drawn[i])
+            .take(count - quota_total)
+            .collect();
+        for index in rest {
+            drawn[index] = true;
+        }
+
+        Ok(QuestionDraw {
+            seed,
+            bank_size,
+            question_indices: (0..bank_size).filter(|&i| drawn[i]).collect(),
+        })
+    }
+}
+
+impl QuestionDraw {
+    /// The quiz to play: `bank` cut down to the drawn questions. Rounds keep their
+    /// order and shrink to the questions drawn from them; rounds left empty are dropped.
+    pub fn apply(&self, bank: Quiz) -> Quiz {
+        let ranges = bank.round_ranges();
+        let rounds = bank
+            .rounds
+            .iter()
+            .zip(&ranges)
+            .map(|(round, range)| Round {
+                question_count: self
+                    .question_indices
+                    .iter()
+                    .filter(|i| range.contains(i))
+                    .count(),
+                ..round.clone()
+            })
+            .filter(|round| round.question_count > 0)
+            .collect();
+        let mut questions: Vec<_> = bank.questions.into_iter().map(Some).collect();
+        Quiz {
+            questions: self
+                .question_indices
+                .iter()
+                .filter_map(|&i| questions.get_mut(i).and_then(Option::take))
+                .collect(),
+            rounds,
+            ..bank
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use super::player::Answer;
+use super::quiz::Question;
+use super::session::MAX_TIME_LIMIT_SEC;
+
+/// Most lives a player can start an elimination game with.
+pub const MAX_LIVES: u32 = 5;
+
+/// Survival play: players who miss a question lose a life, and those out of lives
+/// are eliminated and watch the rest of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EliminationSettings {
+    pub enabled: bool,
+    /// Questions a player can miss before being eliminated, from 1 to `MAX_LIVES`.
+    pub lives: u32,
+    /// When set, a correct answer taking longer than this also costs a life.
+    pub max_answer_sec: Option<u64>,
+}
+
+impl Default for EliminationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lives: 1,
+            max_answer_sec: None,
+        }
+    }
+}
+
+impl EliminationSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=MAX_LIVES).contains(&self.lives) {
+            return Err(format!("Lives must be between 1 and {MAX_LIVES}"));
+        }
+        if let Some(seconds) = self.max_answer_sec
+            && !(1..=MAX_TIME_LIMIT_SEC).contains(&seconds)
+        {
+            return Err(format!(
+                "Answer time limit must be between 1 and {MAX_TIME_LIMIT_SEC} seconds"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether `answer` to `question` costs a life: no answer, a wrong one, or a
+    /// correct one slower than `max_answer_sec`. Polls never do.
+    pub fn costs_life(&self, question: &Question, answer: Option<&Answer>) -> bool {
+        if question.is_poll() {
+            return false;
+        }
+        let Some(answer) = answer else {
+            return true;
+        };
+        answer.selected_index != question.correct_index
+            || self
+                .max_answer_sec
+                .is_some_and(|seconds| answer.time_taken_ms > seconds * 1000)
+    }
+}
@@ -1,57 +1,275 @@
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 
 use super::player::Player;
+use super::team::{TeamAggregation, TeamSettings};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaderboardEntry {
     pub rank: u32,
     pub display_name: String,
     pub avatar: String,
-    pub score: u32,
+    pub score: i32,
+    pub correct_count: u32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_winner: bool,
+    /// Out of an elimination game.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub eliminated: bool,
+}
+
+/// A team's place in a team game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamLeaderboardEntry {
+    pub rank: u32,
+    pub team: String,
+    /// The members' scores combined by the session's `TeamAggregation`.
+    pub score: i32,
+    /// Correct answers across all members.
     pub correct_count: u32,
+    pub member_count: u32,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub is_winner: bool,
 }
 
+/// How players with equal scores are ordered. Players still tied afterwards
+/// share a rank.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreaker {
+    /// Equal scores share a rank.
+    #[default]
+    None,
+    /// Less cumulative answer time over the scored questions asked ranks higher.
+    /// An unanswered question counts as its full time limit; polls do not count.
+    AnswerTime,
+    /// More correct answers ranks higher.
+    CorrectCount,
+    /// Players tied for first answer the quiz's tiebreaker questions until one is
+    /// left; surviving more of them ranks higher.
+    SuddenDeath,
+}
+
+/// A scored question the session has asked, for the `AnswerTime` tie-breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AskedQuestion {
+    /// Position in the session's play order, as in `Answer::question_index`.
+    pub position: usize,
+    pub time_limit_ms: u64,
+}
+
+impl TieBreaker {
+    /// Sort key among equal scores; lower ranks higher.
+    fn key(self, player: &Player, asked: &[AskedQuestion]) -> i64 {
+        match self {
+            TieBreaker::None => 0,
+            TieBreaker::AnswerTime => asked
+                .iter()
+                .filter(|q| player.in_game_at(q.position))
+                .map(|q| {
+                    player
+                        .answer_to(q.position)
+                        .map_or(q.time_limit_ms, |a| a.time_taken_ms) as i64
+                })
+                .sum(),
+            TieBreaker::CorrectCount => -i64::from(player.correct_count),
+            TieBreaker::SuddenDeath => -i64::from(player.tiebreak_survived),
+        }
+    }
+}
+
 /// Compute leaderboard from a collection of players.
 /// Sorted by score descending, then display_name ascending for ties.
 /// Ties share the same rank.
 pub fn compute_leaderboard(players: &[&Player], mark_winner: bool) -> Vec<LeaderboardEntry> {
-    let mut entries: Vec<_> = players
+    compute_leaderboard_with(players, mark_winner, TieBreaker::None, &[])
+}
+
+/// Like `compute_leaderboard`, ordering equal scores by `tie_breaker` before
+/// display_name; only players tied on both share a rank. `asked` lists the scored
+/// questions asked so far, which only `TieBreaker::AnswerTime` looks at.
+pub fn compute_leaderboard_with(
+    players: &[&Player],
+    mark_winner: bool,
+    tie_breaker: TieBreaker,
+    asked: &[AskedQuestion],
+) -> Vec<LeaderboardEntry> {
+    let entries = players
+        .iter()
+        .map(|p| (player_entry(p), (0, tie_breaker.key(p, asked))))
+        .collect();
+    rank_entries(entries, mark_winner)
+}
+
+/// The leaderboard of an elimination game: players still in rank above everyone
+/// eliminated, and the later a player was eliminated the higher they rank. Score,
+/// then `tie_breaker`, then display_name order players who went out together.
+pub fn compute_survival_leaderboard(
+    players: &[&Player],
+    mark_winner: bool,
+    tie_breaker: TieBreaker,
+    asked: &[AskedQuestion],
+) -> Vec<LeaderboardEntry> {
+    let entries = players
         .iter()
         .map(|p| {
-            (
-                p.display_name.clone(),
-                p.score,
-                p.correct_count,
-                p.avatar.clone(),
-            )
+            let survival = p.eliminated_at.map_or(i64::MIN, |at| -(at as i64));
+            (player_entry(p), (survival, tie_breaker.key(p, asked)))
+        })
+        .collect();
+    rank_entries(entries, mark_winner)
+}
+
+fn player_entry(player: &Player) -> LeaderboardEntry {
+    LeaderboardEntry {
+        rank: 0,
+        display_name: player.display_name.clone(),
+        avatar: player.avatar.clone(),
+        score: player.score,
+        correct_count: player.correct_count,
+        is_winner: false,
+        eliminated: player.is_eliminated(),
+    }
+}
+
+/// The team variant of `compute_leaderboard`: one entry per team with members,
+/// sorted by the score aggregated from their answers' points, then team name. Teams with equal scores share a rank.
+pub fn compute_team_leaderboard(
+    players: &[&Player],
+    teams: &TeamSettings,
+    mark_winner: bool,
+) -> Vec<TeamLeaderboardEntry> {
+    let members = |team: &str| -> Vec<&Player> {
+        players
+            .iter()
+            .copied()
+            .filter(|p| p.team.as_deref() == Some(team))
+            .collect()
+    };
+    let entries = teams
+        .names
+        .iter()
+        .filter_map(|team| {
+            let members = members(team);
+            if members.is_empty() {
+                return None;
+            }
+            Some(LeaderboardEntry {
+                rank: 0,
+                display_name: team.clone(),
+                avatar: String::new(),
+                score: team_score(&members, teams.aggregation),
+                correct_count: members.iter().map(|p| p.correct_count).sum(),
+                is_winner: false,
+                eliminated: false,
+            })
         })
         .collect();
+    rank_leaderboard(entries, mark_winner)
+        .into_iter()
+        .map(|entry| TeamLeaderboardEntry {
+            rank: entry.rank,
+            member_count: members(&entry.display_name).len() as u32,
+            team: entry.display_name,
+            score: entry.score,
+            correct_count: entry.correct_count,
+            is_winner: entry.is_winner,
+        })
+        .collect()
+}
 
-    // Sort by score desc, then name asc
-    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+/// The members' points aggregated question by question, then totalled; clamped
+/// to the `i32` range of a leaderboard score.
+fn team_score(members: &[&Player], aggregation: TeamAggregation) -> i32 {
+    let questions: BTreeSet<usize> = members
+        .iter()
+        .flat_map(|p| p.answers.iter().map(|a| a.question_index))
+        .collect();
+    let total: i64 = questions
+        .into_iter()
+        .map(|question| {
+            let points: Vec<i32> = members
+                .iter()
+                .map(|p| {
+                    p.answers
+                        .iter()
+                        .find(|a| a.question_index == question)
+                        .map_or(0, |a| a.points_awarded)
+                })
+                .collect();
+            aggregation.aggregate(&points)
+        })
+        .sum();
+    total.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+}
 
-    let mut result: Vec<LeaderboardEntry> = Vec::with_capacity(entries.len());
-    let mut current_rank = 1u32;
+/// Sort entries and fill in `rank` (and `is_winner`) the way `compute_leaderboard`
+/// does, for standings that are not the players' running totals.
+pub fn rank_leaderboard(
+    entries: Vec<LeaderboardEntry>,
+    mark_winner: bool,
+) -> Vec<LeaderboardEntry> {
+    rank_entries(
+        entries.into_iter().map(|e| (e, (0, 0))).collect(),
+        mark_winner,
+    )
+}
 
-    for (i, (name, score, correct, avatar)) in entries.iter().enumerate() {
-        let rank = if i > 0 && *score == entries[i - 1].1 {
-            result[i - 1].rank
+/// Rank entries paired with their `(standing, tie-break)` keys; lower ranks higher
+/// for both, and standing counts before score.
+fn rank_entries(
+    mut entries: Vec<(LeaderboardEntry, (i64, i64))>,
+    mark_winner: bool,
+) -> Vec<LeaderboardEntry> {
+    // Sort by standing asc, then score desc, then tie-break key asc, then name asc
+    entries.sort_by(|(a, (a_standing, a_key)), (b, (b_standing, b_key))| {
+        a_standing
+            .cmp(b_standing)
+            .then_with(|| b.score.cmp(&a.score))
+            .then_with(|| a_key.cmp(b_key))
+            .then_with(|| a.display_name.cmp(&b.display_name))
+    });
+    let mut current_rank = 1u32;
+    for i in 0..entries.len() {
+        let tied = i > 0 && {
+            let ((prev, prev_key), (entry, key)) = (&entries[i - 1], &entries[i]);
+            prev.score == entry.score && prev_key == key
+        };
+        let rank = if tied {
+            entries[i - 1].0.rank
         } else {
             current_rank
         };
         current_rank = (i as u32) + 2;
 
-        result.push(LeaderboardEntry {
-            rank,
-            display_name: name.clone(),
-            avatar: avatar.clone(),
-            score: *score,
-            correct_count: *correct,
-            is_winner: mark_winner && rank == 1,
-        });
+        entries[i].0.rank = rank;
+        entries[i].0.is_winner = mark_winner && rank == 1;
     }
 
-    result
+    entries.into_iter().map(|(entry, _)| entry).collect()
+}
+
+/// How an entry moved since an earlier leaderboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RankMovement {
+    /// Score gained (or lost, for a penalty) since the earlier leaderboard.
+    pub points_gained: i32,
+    /// `None` when the player was not on the earlier leaderboard.
+    pub previous_rank: Option<u32>,
+    /// Places climbed: positive moved up, negative moved down.
+    pub rank_change: i32,
+}
+
+/// Compares `entry` with the same player's entry in `previous`, matched by
+/// display name (unique within a session).
+pub fn rank_movement(entry: &LeaderboardEntry, previous: &[LeaderboardEntry]) -> RankMovement {
+    let before = previous
+        .iter()
+        .find(|e| e.display_name == entry.display_name);
+    RankMovement {
+        points_gained: entry.score - before.map_or(0, |e| e.score),
+        previous_rank: before.map(|e| e.rank),
+        rank_change: before.map_or(0, |e| e.rank as i32 - entry.rank as i32),
+    }
 }
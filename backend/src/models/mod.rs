@@ -1,5 +1,10 @@
+pub mod asset;
+pub mod draw;
+pub mod elimination;
 pub mod leaderboard;
 pub mod player;
 pub mod quiz;
 pub mod scoring_rule;
 pub mod session;
+pub mod shuffle;
+pub mod team;
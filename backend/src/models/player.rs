@@ -14,10 +14,12 @@ pub enum ConnectionStatus {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Answer {
+    /// Position in the session's play order, as sent in `question` messages.
     pub question_index: usize,
+    /// Index into the quiz question's options, whatever order the player saw them in.
     pub selected_index: usize,
     pub time_taken_ms: u64,
-    pub points_awarded: u32,
+    pub points_awarded: i32,
 }
 
 #[derive(Debug)]
@@ -25,9 +27,17 @@ pub struct Player {
     pub id: String,
     pub display_name: String,
     pub avatar: String,
-    pub score: u32,
+    /// Name of the player's team in a team game.
+    pub team: Option<String>,
+    pub score: i32,
     pub correct_count: u32,
     pub correct_streak: u32,
+    /// Sudden-death tiebreaker questions this player got through.
+    pub tiebreak_survived: u32,
+    /// Positions of the questions that cost this player a life in an elimination game.
+    pub lives_lost_at: Vec<usize>,
+    /// Position of the question that eliminated this player.
+    pub eliminated_at: Option<usize>,
     pub answers: Vec<Answer>,
     pub connection_status: ConnectionStatus,
     pub disconnected_at: Option<Instant>,
@@ -39,15 +49,34 @@ impl Player {
             id,
             display_name,
             avatar,
+            team: None,
             score: 0,
             correct_count: 0,
             correct_streak: 0,
+            tiebreak_survived: 0,
+            lives_lost_at: Vec::new(),
+            eliminated_at: None,
             answers: Vec::new(),
             connection_status: ConnectionStatus::Connected,
             disconnected_at: None,
         }
     }
 
+    pub fn is_eliminated(&self) -> bool {
+        self.eliminated_at.is_some()
+    }
+
+    /// Whether the player was still in the game for the question at `position`.
+    pub fn in_game_at(&self, position: usize) -> bool {
+        self.eliminated_at.is_none_or(|at| at >= position)
+    }
+
+    pub fn answer_to(&self, question_index: usize) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|a| a.question_index == question_index)
+    }
+
     pub fn has_answered(&self, question_index: usize) -> bool {
         self.answers
             .iter()
@@ -1,176 +1,1082 @@
+use std::ops::Range;
+
 use serde::{Deserialize, Serialize};
 
-use crate::errors::ParseError;
+use crate::errors::{ParseError, ParseWarning, TextSpan, WarningCode};
+use crate::models::scoring_rule::{MAX_WEIGHT, ScoringRule};
+use crate::models::session::{MAX_TIME_LIMIT_SEC, MIN_TIME_LIMIT_SEC};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Quiz {
     pub title: String,
     pub questions: Vec<Question>,
+    #[serde(default)]
+    pub metadata: QuizMetadata,
+    /// Sections of consecutive questions, in play order. Empty for a quiz that is
+    /// not split into rounds.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rounds: Vec<Round>,
+    /// Questions held back for a sudden-death tiebreak, asked in order only to
+    /// players tied for first place.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiebreakers: Vec<Question>,
+}
+
+impl Quiz {
+    /// The question indices of each round; a single range covering every question
+    /// when the quiz has no rounds.
+    pub fn round_ranges(&self) -> Vec<Range<usize>> {
+        if self.rounds.is_empty() {
+            return std::iter::once(0..self.questions.len()).collect();
+        }
+        let mut start = 0;
+        self.rounds
+            .iter()
+            .map(|round| {
+                let range = start..start + round.question_count;
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    /// Index of the round holding `question`, or `None` for a quiz without rounds.
+    pub fn round_of(&self, question: usize) -> Option<usize> {
+        if self.rounds.is_empty() {
+            return None;
+        }
+        self.round_ranges()
+            .iter()
+            .position(|range| range.contains(&question))
+    }
+}
+
+/// A named section of a quiz: the next `question_count` questions after the
+/// previous round's, optionally played with their own scoring rule and time limit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Round {
+    pub title: String,
+    pub question_count: usize,
+    /// Overrides the session's scoring rule for this round.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring_rule: Option<ScoringRule>,
+    /// Overrides the session's time limit for this round.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_limit_sec: Option<u64>,
+}
+
+/// Optional settings and descriptive fields from the quiz front-matter block.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuizMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Seeds `GameSession::scoring_rule` when a session is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoring_rule: Option<ScoringRule>,
+    /// Seeds `GameSession::time_limit_sec` and every question's time limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_limit_sec: Option<u64>,
+    pub shuffle_questions: bool,
+    pub shuffle_options: bool,
+    pub tags: Vec<String>,
+    /// Narrow the server's option-count limits for this quiz.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_options: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_options: Option<usize>,
+}
+
+impl QuizMetadata {
+    /// The option-count limits for this quiz: the server's, narrowed by front-matter.
+    pub fn option_limits(&self, server: OptionLimits) -> OptionLimits {
+        OptionLimits {
+            min: self.min_options.unwrap_or(server.min),
+            max: self.max_options.unwrap_or(server.max),
+        }
+    }
+}
+
+/// Fewest and most options a question may have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct OptionLimits {
+    pub min: usize,
+    pub max: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl OptionLimits {
+    /// Bounds no deployment can go beyond: a question needs two options to be a
+    /// choice, and more than ten no longer fit on a phone screen.
+    pub const FLOOR: usize = 2;
+    pub const CEILING: usize = 10;
+
+    /// `None` unless `FLOOR <= min <= max <= CEILING`.
+    pub fn new(min: usize, max: usize) -> Option<Self> {
+        (Self::FLOOR <= min && min <= max && max <= Self::CEILING).then_some(Self { min, max })
+    }
+
+    /// Shared option-count rule for every quiz format.
+    pub fn violation(&self, count: usize) -> Option<String> {
+        if count < self.min {
+            Some(format!(
+                "Question has {count} option(s), minimum is {}",
+                self.min
+            ))
+        } else if count > self.max {
+            Some(format!(
+                "Question has {count} options, maximum is {}",
+                self.max
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for OptionLimits {
+    fn default() -> Self {
+        Self { min: 2, max: 4 }
+    }
+}
+
+/// Server-wide settings applied while parsing any quiz format.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseSettings {
+    /// Question time limit when the quiz does not set one.
+    pub default_time_limit: u64,
+    /// What front-matter may narrow but never widen.
+    pub option_limits: OptionLimits,
+}
+
+impl ParseSettings {
+    pub fn with_time_limit(default_time_limit: u64) -> Self {
+        Self {
+            default_time_limit,
+            option_limits: OptionLimits::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Question {
     pub text: String,
     pub options: Vec<QuizOption>,
+    /// Index of the correct option. Meaningless (always 0) for poll questions.
+    /// `NO_CORRECT_INDEX` when a structured document leaves it out, which validation
+    /// rejects for every question but a poll.
+    #[serde(default = "no_correct_index")]
     pub correct_index: usize,
+    /// Filled from the quiz default when a structured document leaves it out (or sets 0).
+    #[serde(default)]
     pub time_limit_sec: u64,
+    #[serde(default)]
+    pub kind: QuestionKind,
+    /// Optional "why" shown to everyone once the question ends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    /// Image or audio asset path, relative to the root of the uploaded bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+    /// Labels for drawing questions from a bank by topic, e.g. `geography`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Points multiplier, e.g. `2.0` for a double-points question. `None` scores normally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// `correct_index` of a deserialized question that did not give one.
+pub const NO_CORRECT_INDEX: usize = usize::MAX;
+
+fn no_correct_index() -> usize {
+    NO_CORRECT_INDEX
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionKind {
+    /// Regular question with exactly one correct option.
+    #[default]
+    Standard,
+    /// Opinion poll: no correct option, awards no points and leaves streaks untouched.
+    Poll,
+}
+
+impl Question {
+    pub fn is_poll(&self) -> bool {
+        self.kind == QuestionKind::Poll
+    }
+
+    /// The multiplier applied to every point this question awards.
+    pub fn weight(&self) -> f64 {
+        self.weight.unwrap_or(1.0)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct QuizOption {
     pub text: String,
+    /// Image or audio asset path, relative to the root of the uploaded bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+}
+
+/// The text field a `|` continuation line appends to.
+#[derive(Debug, Clone, Copy)]
+enum TextField {
+    Question,
+    Option,
+    Explanation,
+}
+
+/// A question whose lines are still being read.
+struct PendingQuestion {
+    text: String,
+    kind: QuestionKind,
+    /// Each option paired with whether it is marked correct.
+    options: Vec<(QuizOption, bool)>,
+    explanation: Option<String>,
+    media: Option<String>,
+    tags: Vec<String>,
+    weight: Option<f64>,
+    /// Whether the most recent line was an option, so `@` attaches to it.
+    last_line_was_option: bool,
+    start_line: usize,
+    option_lines: Vec<usize>,
+    explanation_line: Option<usize>,
+}
+
+/// Where each part of a parsed text quiz starts, for pointing warnings at lines.
+#[derive(Debug, Clone, Default)]
+pub struct TextSourceMap {
+    pub title: usize,
+    /// One entry per accepted question, in order, followed by the tiebreakers.
+    pub questions: Vec<QuestionLines>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QuestionLines {
+    pub line: usize,
+    pub options: Vec<usize>,
+    pub explanation: Option<usize>,
+}
+
+/// Everything learned from reading a text quiz, including what did not stop it parsing.
+#[derive(Debug)]
+pub struct TextParse {
+    pub result: Result<Quiz, Vec<ParseError>>,
+    pub source_map: TextSourceMap,
+    pub warnings: Vec<ParseWarning>,
 }
 
 /// Parse a quiz from the line-based text format.
 ///
 /// Format:
+/// - An optional front-matter block at the top, between two `---` lines, holding
+///   `key: value` pairs: `author`, `description`, `scoring_rule`, `time_limit`,
+///   `shuffle_questions`, `shuffle_options`, `tags` (comma-separated), and
+///   `min_options`/`max_options` (within the server's limits)
 /// - `# Title` — quiz title (first occurrence)
+/// - `= Round title` — starts a round; every question must then belong to one. An
+///   optional trailing `[time_limit: 30, scoring_rule: fixed_score]` sets the round's
+///   own time limit and scoring rule
+/// - `== Tiebreaker` — starts the final section of sudden-death questions, kept out
+///   of the normal game and asked only to break a tie for first place. They use the
+///   quiz's time limit, cannot be polls, and no round may follow
 /// - `? Question text` — begins a new question
+/// - `~ Question text` — begins a new poll question (no correct answer)
 /// - `- Option text` — incorrect answer
-/// - `* Option text` — correct answer (exactly one per question)
+/// - `* Option text` — correct answer (exactly one per question, none for polls)
+/// - `> Explanation` — optional explanation revealed after the question ends
+/// - `@ path/to/file.png` — image or audio from the uploaded bundle, attached to the
+///   option on the previous line, or to the question otherwise
+/// - `% tag, tag` — comma-separated tags for the current question, used to draw
+///   questions from a bank by topic
+/// - `! 2` — points multiplier for the current question, e.g. `! 2` for double
+///   points or `! 0.5` for half
+/// - `| More text` — continues the question, option or explanation on the line above
+///   with a line break; one space after `|` is dropped and the rest is kept verbatim,
+///   so indented code snippets survive
 /// - `//` — comment (ignored)
-/// - Blank lines are ignored
+/// - Blank lines are ignored, and end any continuation
+///
+/// Text after `#`, `=`, `?`, `~`, `-`, `*` or `>` may start with `\` to keep its
+/// leading whitespace, e.g. `- \  indented`; a backslash that is itself followed by
+/// whitespace takes one more, so `- \\ x` reads as `\ x`. Any other backslash is kept
+/// as written, so `\?`, `\-5` and `C:\temp` are unchanged.
 pub fn parse_quiz(content: &str, default_time_limit: u64) -> Result<Quiz, Vec<ParseError>> {
+    parse_quiz_with_source_map(content, &ParseSettings::with_time_limit(default_time_limit)).result
+}
+
+/// `parse_quiz` with explicit server settings, also returning line numbers and
+/// parser warnings for linting.
+pub fn parse_quiz_with_source_map(content: &str, settings: &ParseSettings) -> TextParse {
     let mut title = String::new();
     let mut questions: Vec<Question> = Vec::new();
     let mut errors: Vec<ParseError> = Vec::new();
+    let mut warnings: Vec<ParseWarning> = Vec::new();
+    let mut source_map = TextSourceMap::default();
 
-    let mut current_question: Option<String> = None;
-    let mut current_options: Vec<(String, bool)> = Vec::new();
-    let mut question_start_line: usize = 0;
-
-    let finalize_question = |questions: &mut Vec<Question>,
-                             errors: &mut Vec<ParseError>,
-                             text: &str,
-                             options: &[(String, bool)],
-                             start_line: usize,
-                             default_time: u64| {
-        let correct_count = options.iter().filter(|(_, c)| *c).count();
-        if correct_count == 0 {
-            errors.push(ParseError {
-                line: start_line,
-                message: "Question has no correct answer (no line starting with *)".into(),
-            });
-        } else if correct_count > 1 {
-            errors.push(ParseError {
-                line: start_line,
-                message: "Question has multiple correct answers (only one * allowed)".into(),
-            });
-        }
-        if options.len() < 2 {
-            errors.push(ParseError {
-                line: start_line,
-                message: format!("Question has {} option(s), minimum is 2", options.len()),
-            });
-        }
-        if options.len() > 4 {
-            errors.push(ParseError {
-                line: start_line,
-                message: format!("Question has {} options, maximum is 4", options.len()),
-            });
-        }
+    let mut current_question: Option<PendingQuestion> = None;
+    let mut open_field: Option<TextField> = None;
+    // Each round with the number of questions before it and its header line.
+    let mut rounds: Vec<(Round, usize, usize)> = Vec::new();
+    // The number of questions before the tiebreaker section and its header line.
+    let mut tiebreak: Option<(usize, usize)> = None;
 
-        if correct_count == 1 && options.len() >= 2 && options.len() <= 4 {
-            let correct_index = options.iter().position(|(_, c)| *c).unwrap();
-            questions.push(Question {
-                text: text.to_string(),
-                options: options
-                    .iter()
-                    .map(|(t, _)| QuizOption { text: t.clone() })
-                    .collect(),
-                correct_index,
-                time_limit_sec: default_time,
-            });
-        }
-    };
+    let (metadata, body_start) = parse_front_matter(content, settings.option_limits, &mut errors);
+    let default_time_limit = metadata
+        .time_limit_sec
+        .unwrap_or(settings.default_time_limit);
+    let limits = metadata.option_limits(settings.option_limits);
 
-    for (line_num, line) in content.lines().enumerate() {
+    for (line_num, line) in content.lines().enumerate().skip(body_start) {
         let line_num = line_num + 1; // 1-based
         let trimmed = line.trim();
 
         if trimmed.is_empty() || trimmed.starts_with("//") {
+            open_field = None;
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('|') {
+            let text = rest.strip_prefix(' ').unwrap_or(rest);
+            let target = match (open_field, current_question.as_mut()) {
+                (Some(field), Some(q)) => q.text_field(field),
+                _ => None,
+            };
+            match target {
+                Some(target) => {
+                    target.push('\n');
+                    target.push_str(text);
+                }
+                None => errors.push(ParseError::new(
+                    line_num,
+                    "Continuation line (|) must follow a question, option or explanation line",
+                )),
+            }
+            continue;
+        }
+        open_field = None;
+
+        if let Some(header) = trimmed.strip_prefix("==") {
+            if let Some(q) = current_question.take() {
+                finalize_question(
+                    &mut questions,
+                    &mut errors,
+                    &mut source_map,
+                    q,
+                    round_time_limit(&rounds, tiebreak.is_some(), default_time_limit),
+                    limits,
+                );
+            }
+            if !header.trim().eq_ignore_ascii_case("tiebreaker") {
+                errors.push(ParseError::new(
+                    line_num,
+                    "Unknown section (expected == Tiebreaker)",
+                ));
+            } else if let Some((_, first)) = tiebreak {
+                errors.push(ParseError::new(
+                    line_num,
+                    format!("Tiebreaker section already started on line {first}"),
+                ));
+            } else {
+                tiebreak = Some((questions.len(), line_num));
+            }
+            continue;
+        }
+
+        if let Some(header) = trimmed.strip_prefix('=') {
+            if let Some(q) = current_question.take() {
+                finalize_question(
+                    &mut questions,
+                    &mut errors,
+                    &mut source_map,
+                    q,
+                    round_time_limit(&rounds, tiebreak.is_some(), default_time_limit),
+                    limits,
+                );
+            }
+            if let Some((_, first)) = tiebreak {
+                errors.push(ParseError::new(
+                    line_num,
+                    format!(
+                        "Round comes after the tiebreaker section (line {first}); tiebreaker questions must come last"
+                    ),
+                ));
+                continue;
+            }
+            match parse_round_header(header.trim()) {
+                Ok(round) => rounds.push((round, questions.len(), line_num)),
+                Err(message) => errors.push(ParseError::new(line_num, message)),
+            }
             continue;
         }
 
         if trimmed.starts_with('#') {
             if title.is_empty() {
-                title = trimmed.trim_start_matches('#').trim().to_string();
+                title = unescape(trimmed.trim_start_matches('#').trim());
+                source_map.title = line_num;
+            } else {
+                let mut warning = ParseWarning::new(
+                    WarningCode::IgnoredTitle,
+                    line_num,
+                    format!(
+                        "Extra title line ignored; the title was already set on line {}",
+                        source_map.title
+                    ),
+                );
+                warning.span = TextSpan::of_line(content, line_num);
+                warnings.push(warning);
             }
             continue;
         }
 
-        if trimmed.starts_with('?') {
+        if trimmed.starts_with('?') || trimmed.starts_with('~') {
             // Finalize previous question if any
-            if let Some(ref q_text) = current_question {
+            if let Some(q) = current_question.take() {
                 finalize_question(
                     &mut questions,
                     &mut errors,
-                    q_text,
-                    &current_options,
-                    question_start_line,
-                    default_time_limit,
+                    &mut source_map,
+                    q,
+                    round_time_limit(&rounds, tiebreak.is_some(), default_time_limit),
+                    limits,
                 );
             }
-            current_question = Some(trimmed.trim_start_matches('?').trim().to_string());
-            current_options = Vec::new();
-            question_start_line = line_num;
+            let (kind, text) = match trimmed.strip_prefix('~') {
+                Some(rest) => (QuestionKind::Poll, rest),
+                None => (QuestionKind::Standard, trimmed.trim_start_matches('?')),
+            };
+            current_question = Some(PendingQuestion {
+                text: unescape(text.trim()),
+                kind,
+                options: Vec::new(),
+                explanation: None,
+                media: None,
+                tags: Vec::new(),
+                weight: None,
+                last_line_was_option: false,
+                start_line: line_num,
+                option_lines: Vec::new(),
+                explanation_line: None,
+            });
+            open_field = Some(TextField::Question);
             continue;
         }
 
         if trimmed.starts_with('-') || trimmed.starts_with('*') {
             let is_correct = trimmed.starts_with('*');
-            let text = trimmed[1..].trim().to_string();
+            let text = unescape(trimmed[1..].trim());
             if text.is_empty() {
-                errors.push(ParseError {
-                    line: line_num,
-                    message: "Option text is empty".into(),
-                });
-            } else if current_question.is_some() {
-                current_options.push((text, is_correct));
+                errors.push(ParseError::new(line_num, "Option text is empty"));
+            } else if let Some(q) = current_question.as_mut() {
+                q.options
+                    .push((QuizOption { text, media: None }, is_correct));
+                q.last_line_was_option = true;
+                q.option_lines.push(line_num);
+                open_field = Some(TextField::Option);
             } else {
-                errors.push(ParseError {
-                    line: line_num,
-                    message: "Option found before any question".into(),
-                });
+                errors.push(ParseError::new(
+                    line_num,
+                    "Option found before any question",
+                ));
             }
             continue;
         }
 
-        errors.push(ParseError {
-            line: line_num,
-            message: "Unrecognized line format: expected #, ?, -, *, or //".to_string(),
-        });
+        if let Some(rest) = trimmed.strip_prefix('>') {
+            let text = unescape(rest.trim());
+            match current_question.as_mut() {
+                None => errors.push(ParseError::new(
+                    line_num,
+                    "Explanation found before any question",
+                )),
+                Some(_) if text.is_empty() => {
+                    errors.push(ParseError::new(line_num, "Explanation text is empty"))
+                }
+                Some(q) if q.explanation.is_some() => errors.push(ParseError::new(
+                    line_num,
+                    "Question already has an explanation (only one > line allowed)",
+                )),
+                Some(q) => {
+                    q.explanation = Some(text);
+                    q.explanation_line = Some(line_num);
+                    q.last_line_was_option = false;
+                    open_field = Some(TextField::Explanation);
+                }
+            }
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('@') {
+            let path = rest.trim();
+            let Some(q) = current_question.as_mut() else {
+                errors.push(ParseError::new(line_num, "Media found before any question"));
+                continue;
+            };
+            if let Err(message) = validate_media_path(path) {
+                errors.push(ParseError::new(line_num, message));
+                continue;
+            }
+            let slot = match q.options.last_mut() {
+                Some((option, _)) if q.last_line_was_option => &mut option.media,
+                _ => &mut q.media,
+            };
+            if slot.is_some() {
+                errors.push(ParseError::new(
+                    line_num,
+                    "Media already attached (only one @ line per question or option)",
+                ));
+            } else {
+                *slot = Some(normalize_media_path(path));
+            }
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('%') {
+            let tags = split_tags(rest);
+            match current_question.as_mut() {
+                None => errors.push(ParseError::new(line_num, "Tags found before any question")),
+                Some(_) if tags.is_empty() => {
+                    errors.push(ParseError::new(line_num, "Tag line is empty"))
+                }
+                Some(q) => q.tags = dedup_tags(q.tags.drain(..).chain(tags)),
+            }
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('!') {
+            match current_question.as_mut() {
+                None => errors.push(ParseError::new(
+                    line_num,
+                    "Weight found before any question",
+                )),
+                Some(q) if q.weight.is_some() => errors.push(ParseError::new(
+                    line_num,
+                    "Question already has a weight (only one ! line allowed)",
+                )),
+                Some(q) => match parse_weight(rest.trim()) {
+                    Ok(weight) => q.weight = Some(weight),
+                    Err(message) => errors.push(ParseError::new(line_num, message)),
+                },
+            }
+            continue;
+        }
+
+        errors.push(ParseError::new(
+            line_num,
+            "Unrecognized line format: expected #, =, ?, ~, -, *, >, @, %, !, |, or //",
+        ));
     }
 
     // Finalize last question
-    if let Some(ref q_text) = current_question {
+    if let Some(q) = current_question.take() {
         finalize_question(
             &mut questions,
             &mut errors,
-            q_text,
-            &current_options,
-            question_start_line,
-            default_time_limit,
+            &mut source_map,
+            q,
+            round_time_limit(&rounds, tiebreak.is_some(), default_time_limit),
+            limits,
         );
     }
 
     if title.is_empty() {
-        errors.push(ParseError {
-            line: 1,
-            message: "Quiz has no title (expected a line starting with #)".into(),
-        });
+        errors.push(ParseError::new(
+            1,
+            "Quiz has no title (expected a line starting with #)",
+        ));
     }
 
+    let tiebreakers = match tiebreak {
+        Some((start, line)) => {
+            let tiebreakers = questions.split_off(start);
+            if tiebreakers.is_empty() && errors.is_empty() {
+                errors.push(ParseError::new(line, "Tiebreaker section has no questions"));
+            }
+            for (i, question) in tiebreakers.iter().enumerate() {
+                if question.is_poll() {
+                    errors.push(ParseError::new(
+                        source_map.questions[start + i].line,
+                        "Tiebreaker question cannot be a poll",
+                    ));
+                }
+            }
+            tiebreakers
+        }
+        None => Vec::new(),
+    };
+
     if questions.is_empty() && errors.is_empty() {
-        errors.push(ParseError {
-            line: 1,
-            message: "Quiz has no valid questions".into(),
-        });
+        errors.push(ParseError::new(1, "Quiz has no valid questions"));
     }
 
-    if errors.is_empty() {
-        Ok(Quiz { title, questions })
+    let rounds = close_rounds(rounds, questions.len(), &source_map, &mut errors);
+
+    let result = if errors.is_empty() {
+        Ok(Quiz {
+            title,
+            questions,
+            metadata,
+            rounds,
+            tiebreakers,
+        })
     } else {
         Err(errors)
+    };
+    TextParse {
+        result,
+        source_map,
+        warnings,
+    }
+}
+
+/// Time limit for questions in the round being read, if it sets one. Tiebreaker
+/// questions always use the quiz's own limit.
+fn round_time_limit(
+    rounds: &[(Round, usize, usize)],
+    in_tiebreak: bool,
+    default_time_limit: u64,
+) -> u64 {
+    if in_tiebreak {
+        return default_time_limit;
+    }
+    rounds
+        .last()
+        .and_then(|(round, _, _)| round.time_limit_sec)
+        .unwrap_or(default_time_limit)
+}
+
+/// Parse the text of a `=` round line: a title, then optional `[key: value, ...]` settings.
+fn parse_round_header(header: &str) -> Result<Round, String> {
+    let (title, settings) = match header.strip_suffix(']').and_then(|h| h.rsplit_once('[')) {
+        Some((title, settings)) => (title.trim_end(), Some(settings)),
+        None => (header, None),
+    };
+    let mut round = Round {
+        title: unescape(title),
+        ..Default::default()
+    };
+    if round.title.is_empty() {
+        return Err("Round title is empty".into());
+    }
+    for setting in settings
+        .into_iter()
+        .flat_map(|s| s.split(','))
+        .filter(|s| !s.trim().is_empty())
+    {
+        let Some((key, value)) = setting.split_once(':') else {
+            return Err(format!(
+                "Round setting '{}' must be 'key: value'",
+                setting.trim()
+            ));
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "scoring_rule" => round.scoring_rule = Some(parse_scoring_rule(value)?),
+            "time_limit" => round.time_limit_sec = Some(parse_time_limit(value)?),
+            key => {
+                return Err(format!(
+                    "Unknown round setting '{key}' (expected time_limit or scoring_rule)"
+                ));
+            }
+        }
+    }
+    Ok(round)
+}
+
+/// Turn `(round, questions before it, header line)` entries into rounds with
+/// question counts, reporting empty rounds and questions outside any round.
+fn close_rounds(
+    rounds: Vec<(Round, usize, usize)>,
+    question_count: usize,
+    source_map: &TextSourceMap,
+    errors: &mut Vec<ParseError>,
+) -> Vec<Round> {
+    if let Some(&(_, first_start, _)) = rounds.first()
+        && first_start > 0
+    {
+        errors.push(ParseError::new(
+            source_map.questions[0].line,
+            "Question comes before the first round (= line); once a quiz has rounds, every question needs one",
+        ));
+    }
+    let ends: Vec<usize> = rounds
+        .iter()
+        .skip(1)
+        .map(|&(_, start, _)| start)
+        .chain([question_count])
+        .collect();
+    rounds
+        .into_iter()
+        .zip(ends)
+        .map(|((mut round, start, line), end)| {
+            round.question_count = end - start;
+            if round.question_count == 0 {
+                errors.push(ParseError::new(
+                    line,
+                    format!("Round '{}' has no questions", round.title),
+                ));
+            }
+            round
+        })
+        .collect()
+}
+
+/// Shared rule for the rounds of a structured quiz: every question belongs to
+/// exactly one round, and no round is empty.
+pub(crate) fn round_layout_violation(rounds: &[Round], question_count: usize) -> Option<String> {
+    if rounds.is_empty() {
+        return None;
+    }
+    if let Some(round) = rounds.iter().find(|r| r.question_count == 0) {
+        return Some(format!("Round '{}' has no questions", round.title));
+    }
+    let total: usize = rounds.iter().map(|r| r.question_count).sum();
+    (total != question_count)
+        .then(|| format!("Rounds cover {total} questions but the quiz has {question_count}"))
+}
+
+impl PendingQuestion {
+    fn text_field(&mut self, field: TextField) -> Option<&mut String> {
+        match field {
+            TextField::Question => Some(&mut self.text),
+            TextField::Option => self.options.last_mut().map(|(option, _)| &mut option.text),
+            TextField::Explanation => self.explanation.as_mut(),
+        }
     }
 }
+
+/// Whether directive text needs a leading `\` to survive the parser: text that
+/// starts with whitespace, possibly behind further backslashes. Only this form is an
+/// escape, so any other backslash reads as written, as it always has.
+pub(crate) fn needs_escape(text: &str) -> bool {
+    text.trim_start_matches('\\')
+        .starts_with(char::is_whitespace)
+}
+
+/// Drop the leading `\` of an escaped text (see `needs_escape`).
+fn unescape(text: &str) -> String {
+    match text.strip_prefix('\\') {
+        Some(rest) if needs_escape(rest) => rest.to_string(),
+        _ => text.to_string(),
+    }
+}
+
+/// Parse the `---`-delimited front-matter block if the first non-blank line opens one.
+/// Returns the metadata and the 0-based index of the first line after the block.
+fn parse_front_matter(
+    content: &str,
+    server_limits: OptionLimits,
+    errors: &mut Vec<ParseError>,
+) -> (QuizMetadata, usize) {
+    let mut metadata = QuizMetadata::default();
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .skip_while(|(_, l)| l.trim().is_empty());
+
+    let Some((open_idx, first)) = lines.next() else {
+        return (metadata, 0);
+    };
+    if first.trim() != "---" {
+        return (metadata, 0);
+    }
+
+    let mut seen_keys: Vec<String> = Vec::new();
+    for (idx, line) in lines {
+        let line_num = idx + 1;
+        let trimmed = line.trim();
+
+        if trimmed == "---" {
+            if let Some(message) = option_limits_conflict(&metadata, server_limits) {
+                errors.push(ParseError::new(open_idx + 1, message));
+            }
+            return (metadata, idx + 1);
+        }
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once(':') else {
+            errors.push(ParseError::new(
+                line_num,
+                "Front-matter line must be 'key: value'",
+            ));
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim();
+
+        if seen_keys.contains(&key) {
+            errors.push(ParseError::new(
+                line_num,
+                format!("Duplicate front-matter key '{key}'"),
+            ));
+            continue;
+        }
+        seen_keys.push(key.clone());
+
+        if let Err(message) = apply_front_matter_field(&mut metadata, &key, value, server_limits) {
+            errors.push(ParseError::new(line_num, message));
+        }
+    }
+
+    errors.push(ParseError::new(
+        open_idx + 1,
+        "Front-matter block is not closed (expected a closing --- line)",
+    ));
+    (metadata, content.lines().count())
+}
+
+fn apply_front_matter_field(
+    metadata: &mut QuizMetadata,
+    key: &str,
+    value: &str,
+    server_limits: OptionLimits,
+) -> Result<(), String> {
+    let non_empty = |value: &str| {
+        if value.is_empty() {
+            Err(format!("Front-matter key '{key}' has an empty value"))
+        } else {
+            Ok(value.to_string())
+        }
+    };
+    let boolean = |value: &str| match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" => Ok(true),
+        "false" | "no" => Ok(false),
+        _ => Err(format!(
+            "Front-matter key '{key}' must be true or false, got '{value}'"
+        )),
+    };
+
+    match key {
+        "author" => metadata.author = Some(non_empty(value)?),
+        "description" => metadata.description = Some(non_empty(value)?),
+        "scoring_rule" => metadata.scoring_rule = Some(parse_scoring_rule(value)?),
+        "time_limit" => metadata.time_limit_sec = Some(parse_time_limit(value)?),
+        "shuffle_questions" => metadata.shuffle_questions = boolean(value)?,
+        "shuffle_options" => metadata.shuffle_options = boolean(value)?,
+        "tags" => metadata.tags = split_tags(value),
+        "min_options" | "max_options" => {
+            let count: usize = value
+                .parse()
+                .map_err(|_| format!("{key} must be a whole number, got '{value}'"))?;
+            if let Some(message) = option_limit_violation(key, count, server_limits) {
+                return Err(message);
+            }
+            if key == "min_options" {
+                metadata.min_options = Some(count);
+            } else {
+                metadata.max_options = Some(count);
+            }
+        }
+        _ => return Err(format!("Unknown front-matter key '{key}'")),
+    }
+    Ok(())
+}
+
+/// Shared rule for a quiz's `min_options`/`max_options`: they may only narrow the
+/// server's limits.
+pub(crate) fn option_limit_violation(
+    key: &str,
+    count: usize,
+    server: OptionLimits,
+) -> Option<String> {
+    if (server.min..=server.max).contains(&count) {
+        None
+    } else {
+        Some(format!(
+            "{key} must be between {} and {} on this server",
+            server.min, server.max
+        ))
+    }
+}
+
+/// A quiz that sets both bounds, or one bound past the server's other, must
+/// still allow some option count.
+pub(crate) fn option_limits_conflict(
+    metadata: &QuizMetadata,
+    server: OptionLimits,
+) -> Option<String> {
+    let limits = metadata.option_limits(server);
+    (limits.min > limits.max).then(|| {
+        format!(
+            "min_options ({}) is greater than max_options ({})",
+            limits.min, limits.max
+        )
+    })
+}
+
+fn parse_scoring_rule(value: &str) -> Result<ScoringRule, String> {
+    ScoringRule::from_name(value).ok_or_else(|| {
+        format!(
+            "Unknown scoring_rule '{value}' (expected stepped_decay, linear_decay, fixed_score, streak_bonus or position_race)"
+        )
+    })
+}
+
+fn parse_time_limit(value: &str) -> Result<u64, String> {
+    let seconds: u64 = value
+        .parse()
+        .map_err(|_| format!("time_limit must be a whole number of seconds, got '{value}'"))?;
+    match time_limit_violation(seconds) {
+        Some(message) => Err(message),
+        None => Ok(seconds),
+    }
+}
+
+/// Shared front-matter time limit rule for every quiz format.
+pub(crate) fn time_limit_violation(seconds: u64) -> Option<String> {
+    if (MIN_TIME_LIMIT_SEC..=MAX_TIME_LIMIT_SEC).contains(&seconds) {
+        None
+    } else {
+        Some(format!(
+            "time_limit must be between {MIN_TIME_LIMIT_SEC} and {MAX_TIME_LIMIT_SEC} seconds"
+        ))
+    }
+}
+
+fn parse_weight(value: &str) -> Result<f64, String> {
+    let weight: f64 = value
+        .parse()
+        .map_err(|_| format!("Weight must be a number, got '{value}'"))?;
+    match weight_violation(weight) {
+        Some(message) => Err(message),
+        None => Ok(weight),
+    }
+}
+
+pub(crate) const POLL_WEIGHT_ERROR: &str =
+    "Poll question cannot have a weight (polls award no points)";
+
+/// Shared question weight rule for every quiz format.
+pub(crate) fn weight_violation(weight: f64) -> Option<String> {
+    if weight > 0.0 && weight <= MAX_WEIGHT {
+        None
+    } else {
+        Some(format!(
+            "Weight must be greater than 0 and at most {MAX_WEIGHT}"
+        ))
+    }
+}
+
+fn finalize_question(
+    questions: &mut Vec<Question>,
+    errors: &mut Vec<ParseError>,
+    source_map: &mut TextSourceMap,
+    question: PendingQuestion,
+    default_time: u64,
+    limits: OptionLimits,
+) {
+    let PendingQuestion {
+        text,
+        kind,
+        options,
+        explanation,
+        media,
+        tags,
+        weight,
+        start_line,
+        option_lines,
+        explanation_line,
+        ..
+    } = question;
+
+    let correct_count = options.iter().filter(|(_, c)| *c).count();
+    let is_poll = kind == QuestionKind::Poll;
+    if is_poll {
+        if correct_count > 0 {
+            errors.push(ParseError::new(
+                start_line,
+                "Poll question cannot have a correct answer (use - for every option)",
+            ));
+        }
+    } else if correct_count == 0 {
+        errors.push(ParseError::new(
+            start_line,
+            "Question has no correct answer (no line starting with *)",
+        ));
+    } else if correct_count > 1 {
+        errors.push(ParseError::new(
+            start_line,
+            "Question has multiple correct answers (only one * allowed)",
+        ));
+    }
+    if let Some(message) = limits.violation(options.len()) {
+        errors.push(ParseError::new(start_line, message));
+    }
+    if is_poll && weight.is_some() {
+        errors.push(ParseError::new(start_line, POLL_WEIGHT_ERROR));
+    }
+
+    let answers_valid = if is_poll {
+        correct_count == 0
+    } else {
+        correct_count == 1
+    };
+    if answers_valid && limits.violation(options.len()).is_none() {
+        let correct_index = options.iter().position(|(_, c)| *c).unwrap_or(0);
+        questions.push(Question {
+            text,
+            options: options.into_iter().map(|(option, _)| option).collect(),
+            correct_index,
+            time_limit_sec: default_time,
+            kind,
+            explanation,
+            media,
+            tags,
+            weight,
+        });
+        source_map.questions.push(QuestionLines {
+            line: start_line,
+            options: option_lines,
+            explanation: explanation_line,
+        });
+    }
+}
+
+/// Trimmed, non-empty entries of a comma-separated tag list.
+pub(crate) fn split_tags(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// `tags` in first-seen order, without repeats.
+pub(crate) fn dedup_tags(tags: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for tag in tags {
+        if !unique.contains(&tag) {
+            unique.push(tag);
+        }
+    }
+    unique
+}
+
+/// Rejects media paths that could escape the bundle root.
+pub(crate) fn validate_media_path(path: &str) -> Result<(), String> {
+    if path.is_empty() {
+        return Err("Media path is empty".into());
+    }
+    if path.starts_with('/') || path.starts_with('\\') || path.contains(':') {
+        return Err(format!(
+            "Media path '{path}' must be relative to the bundle"
+        ));
+    }
+    if path.split(['/', '\\']).any(|segment| segment == "..") {
+        return Err(format!("Media path '{path}' must not contain '..'"));
+    }
+    Ok(())
+}
+
+/// Canonical form used as the asset key: forward slashes, no leading `./`.
+pub fn normalize_media_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
@@ -1,9 +1,33 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
 
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::session::MAX_TIME_LIMIT_SEC;
+
+/// Points for a correct answer under the default parameters of every rule.
 pub const MAX_SCORE: u32 = 1000;
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+/// Largest points multiplier a question may carry.
+pub const MAX_WEIGHT: f64 = 10.0;
+
+/// Largest `max_points` (or schedule entry, or penalty) a host may configure.
+pub const MAX_POINTS_LIMIT: u32 = 10_000;
+
+/// Largest per-answer streak increment a host may configure.
+pub const MAX_STREAK_INCREMENT: f64 = 10.0;
+
+/// How answers are scored.
+///
+/// The unit variants are the built-in rules with their default parameters; any
+/// other parameters make a `WithParams` rule. Serialized as the bare rule name
+/// (`"linear_decay"`) in the first case, and as `{"rule": "linear_decay",
+/// "max_points": 2000}` otherwise. Both forms are accepted when reading, with
+/// missing parameters defaulted. Custom rules are never read from quiz files;
+/// hosts pick them by name through a
+/// [`ScorerRegistry`](crate::services::scorer_registry::ScorerRegistry).
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ScoringRule {
     #[default]
     SteppedDecay,
@@ -11,6 +35,190 @@ pub enum ScoringRule {
     FixedScore,
     StreakBonus,
     PositionRace,
+    /// A built-in rule with parameters other than its defaults. Build one with
+    /// [`ScoringRule::with_params`], which keeps default parameters a unit variant.
+    WithParams(RuleParams),
+    /// A base rule with modifiers applied on top, in order.
+    Composite(CompositeRule),
+    /// A rule registered by the embedding application.
+    Custom(CustomRule),
+}
+
+/// A built-in rule together with its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleParams {
+    SteppedDecay(SteppedDecayParams),
+    LinearDecay(LinearDecayParams),
+    FixedScore(FixedScoreParams),
+    StreakBonus(StreakBonusParams),
+    PositionRace(PositionRaceParams),
+}
+
+/// Full points for the first `step_sec` seconds, then one step less per `step_sec`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SteppedDecayParams {
+    pub max_points: u32,
+    pub step_sec: u64,
+}
+
+/// Points drop evenly every second over the time limit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LinearDecayParams {
+    pub max_points: u32,
+}
+
+/// The same points for every correct answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FixedScoreParams {
+    pub max_points: u32,
+}
+
+/// `max_points` times `1 + streak × streak_increment`, capped at `streak_cap`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StreakBonusParams {
+    pub max_points: u32,
+    pub streak_increment: f64,
+    /// Highest multiplier a streak can reach; unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streak_cap: Option<f64>,
+}
+
+/// Points by arrival order among correct answers: `schedule[0]` for the first,
+/// `schedule[1]` for the second, and the last entry for everyone after.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PositionRaceParams {
+    pub schedule: Vec<u32>,
+}
+
+/// `{"rule": "composite", "base": "linear_decay", "modifiers": [{"modifier": "streak"}]}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompositeRule {
+    /// Any rule except another composite.
+    pub base: Box<ScoringRule>,
+    #[serde(default)]
+    pub modifiers: Vec<ScoringModifier>,
+}
+
+/// A registered [`Scorer`] with the parameters a host chose for it.
+#[derive(Clone)]
+pub struct CustomRule {
+    scorer: Arc<dyn Scorer>,
+    params: serde_json::Value,
+}
+
+/// Something layered on a composite rule's base points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "modifier", rename_all = "snake_case")]
+pub enum ScoringModifier {
+    /// Multiply the points by the streak multiplier, as `StreakBonus` does.
+    Streak(StreakModifier),
+    /// Add bonus points for the first correct answers.
+    PositionBonus(PositionBonusModifier),
+    /// Take points away for a wrong answer. Unanswered questions cost nothing.
+    WrongAnswerPenalty(WrongAnswerPenaltyModifier),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StreakModifier {
+    pub streak_increment: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streak_cap: Option<f64>,
+}
+
+/// `schedule[0]` extra points for the first correct answer, `schedule[1]` for the
+/// second, and none once the schedule runs out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PositionBonusModifier {
+    pub schedule: Vec<u32>,
+}
+
+/// Negative marking: `points` off for a wrong answer. Unanswered questions are
+/// never scored, so leaving one blank costs nothing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WrongAnswerPenaltyModifier {
+    pub points: u32,
+}
+
+impl Default for SteppedDecayParams {
+    fn default() -> Self {
+        Self {
+            max_points: MAX_SCORE,
+            step_sec: 5,
+        }
+    }
+}
+
+impl Default for LinearDecayParams {
+    fn default() -> Self {
+        Self {
+            max_points: MAX_SCORE,
+        }
+    }
+}
+
+impl Default for FixedScoreParams {
+    fn default() -> Self {
+        Self {
+            max_points: MAX_SCORE,
+        }
+    }
+}
+
+impl Default for StreakBonusParams {
+    fn default() -> Self {
+        Self {
+            max_points: MAX_SCORE,
+            streak_increment: 0.5,
+            streak_cap: None,
+        }
+    }
+}
+
+impl Default for PositionRaceParams {
+    fn default() -> Self {
+        Self {
+            schedule: vec![MAX_SCORE, MAX_SCORE * 3 / 4, MAX_SCORE / 2, MAX_SCORE / 4],
+        }
+    }
+}
+
+impl Default for StreakModifier {
+    fn default() -> Self {
+        let StreakBonusParams {
+            streak_increment,
+            streak_cap,
+            ..
+        } = StreakBonusParams::default();
+        Self {
+            streak_increment,
+            streak_cap,
+        }
+    }
+}
+
+impl Default for PositionBonusModifier {
+    fn default() -> Self {
+        Self {
+            schedule: vec![MAX_SCORE * 3 / 10, MAX_SCORE / 5, MAX_SCORE / 10],
+        }
+    }
+}
+
+impl Default for WrongAnswerPenaltyModifier {
+    fn default() -> Self {
+        Self {
+            points: MAX_SCORE / 4,
+        }
+    }
 }
 
 /// All inputs a scoring rule needs to compute an outcome.
@@ -22,124 +230,623 @@ pub struct ScoringContext {
     pub streak: u32,
     /// Number of players who answered correctly before this answer (used by PositionRace).
     pub correct_answer_count: u32,
+    /// The question's points multiplier; 1.0 for a normal question.
+    pub weight: f64,
 }
 
 /// The result returned by every scoring rule.
 pub struct ScoringOutcome {
-    pub points: u32,
-    /// 1-based arrival rank among correct responders. `Some` only for PositionRace + correct.
+    /// Net change to the player's score: negative when a penalty outweighs the award.
+    pub points: i32,
+    /// 1-based arrival rank among correct responders. `Some` only for correct
+    /// answers under PositionRace or a position bonus.
     pub position: Option<u32>,
-    /// Multiplier shown to the player. Always 1.0 except for StreakBonus.
+    /// Multiplier shown to the player. 1.0 unless a streak applies.
     pub streak_multiplier: f64,
+    /// Points taken away for a wrong answer, already deducted from `points`;
+    /// 0 unless a penalty applies.
+    pub penalty: u32,
+}
+
+impl ScoringOutcome {
+    /// `points` awarded, with no position, streak or penalty to report.
+    pub fn points(points: u32) -> Self {
+        Self {
+            points: points as i32,
+            position: None,
+            streak_multiplier: 1.0,
+            penalty: 0,
+        }
+    }
+}
+
+/// A scoring scheme supplied by an application that embeds the server.
+///
+/// Register one in a [`ScorerRegistry`](crate::services::scorer_registry::ScorerRegistry)
+/// and hosts can select it by name with `set_scoring_rule`. `params` is that
+/// message's payload without its `rule` key: an empty object when the host sent
+/// only the name.
+pub trait Scorer: Send + Sync {
+    /// The snake_case name hosts select the rule by.
+    fn name(&self) -> &str;
+
+    fn display_name(&self) -> &str;
+
+    /// JSON Schema describing `params`, listed by `GET /api/scoring-rules`.
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({ "type": "object", "properties": {} })
+    }
+
+    /// Reject parameters no host could mean, naming the first offending one.
+    fn validate(&self, _params: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// The outcome for an answer. The question's weight is applied afterwards.
+    fn score(&self, params: &serde_json::Value, ctx: &ScoringContext) -> ScoringOutcome;
+
+    /// Whether scores depend on the answer streak, so unanswered questions must break it.
+    fn uses_streak(&self) -> bool {
+        false
+    }
+}
+
+impl CustomRule {
+    pub fn new(scorer: Arc<dyn Scorer>, params: serde_json::Value) -> Self {
+        Self { scorer, params }
+    }
+
+    pub fn params(&self) -> &serde_json::Value {
+        &self.params
+    }
+
+    /// `params` with the rule's name under `rule`, as sent to clients.
+    fn to_json(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({ "rule": self.scorer.name() });
+        if let serde_json::Value::Object(params) = &self.params {
+            for (key, param) in params {
+                if key != "rule" {
+                    value[key] = param.clone();
+                }
+            }
+        }
+        value
+    }
+}
+
+impl PartialEq for CustomRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.scorer.name() == other.scorer.name() && self.params == other.params
+    }
+}
+
+impl fmt::Debug for CustomRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomRule")
+            .field("name", &self.scorer.name())
+            .field("params", &self.params)
+            .finish()
+    }
 }
 
 impl ScoringRule {
+    /// Every rule name that works without parameters, in the order hosts are offered them.
+    pub const NAMES: [&'static str; 5] = [
+        "stepped_decay",
+        "linear_decay",
+        "fixed_score",
+        "streak_bonus",
+        "position_race",
+    ];
+
+    /// The built-in rule `params` describes: a unit variant when they are its
+    /// defaults, `WithParams` otherwise.
+    pub fn with_params(params: RuleParams) -> Self {
+        let unit = match &params {
+            RuleParams::SteppedDecay(_) => ScoringRule::SteppedDecay,
+            RuleParams::LinearDecay(_) => ScoringRule::LinearDecay,
+            RuleParams::FixedScore(_) => ScoringRule::FixedScore,
+            RuleParams::StreakBonus(_) => ScoringRule::StreakBonus,
+            RuleParams::PositionRace(_) => ScoringRule::PositionRace,
+        };
+        if unit.params().as_ref() == Some(&params) {
+            unit
+        } else {
+            ScoringRule::WithParams(params)
+        }
+    }
+
+    /// The parameters of a built-in rule, defaults included; `None` for a
+    /// composite or custom rule.
+    pub fn params(&self) -> Option<RuleParams> {
+        Some(match self {
+            ScoringRule::SteppedDecay => RuleParams::SteppedDecay(SteppedDecayParams::default()),
+            ScoringRule::LinearDecay => RuleParams::LinearDecay(LinearDecayParams::default()),
+            ScoringRule::FixedScore => RuleParams::FixedScore(FixedScoreParams::default()),
+            ScoringRule::StreakBonus => RuleParams::StreakBonus(StreakBonusParams::default()),
+            ScoringRule::PositionRace => RuleParams::PositionRace(PositionRaceParams::default()),
+            ScoringRule::WithParams(params) => params.clone(),
+            ScoringRule::Composite(_) | ScoringRule::Custom(_) => return None,
+        })
+    }
+
+    /// `base` with `modifiers` applied on top.
+    pub fn composite(base: ScoringRule, modifiers: Vec<ScoringModifier>) -> Self {
+        ScoringRule::Composite(CompositeRule {
+            base: Box::new(base),
+            modifiers,
+        })
+    }
+
+    /// The rule called `name`, with default parameters.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "stepped_decay" => Some(ScoringRule::SteppedDecay),
+            "linear_decay" => Some(ScoringRule::LinearDecay),
+            "fixed_score" => Some(ScoringRule::FixedScore),
+            "streak_bonus" => Some(ScoringRule::StreakBonus),
+            "position_race" => Some(ScoringRule::PositionRace),
+            _ => None,
+        }
+    }
+
+    /// The snake_case name used on the wire and in quiz files.
+    pub fn name(&self) -> &str {
+        match self {
+            ScoringRule::SteppedDecay => "stepped_decay",
+            ScoringRule::LinearDecay => "linear_decay",
+            ScoringRule::FixedScore => "fixed_score",
+            ScoringRule::StreakBonus => "streak_bonus",
+            ScoringRule::PositionRace => "position_race",
+            ScoringRule::WithParams(params) => params.name(),
+            ScoringRule::Composite(_) => "composite",
+            ScoringRule::Custom(rule) => rule.scorer.name(),
+        }
+    }
+
     /// Compute the scoring outcome for an answer.
-    /// Each rule delegates to its own private function with the same signature.
+    /// Each rule delegates to its own private function with the same signature,
+    /// and the question's weight then scales whatever it awarded or took away.
     pub fn score(&self, ctx: &ScoringContext) -> ScoringOutcome {
+        let mut outcome = self.score_unweighted(ctx);
+        outcome.points = apply_weight(outcome.points, ctx.weight);
+        outcome.penalty = apply_weight(outcome.penalty as i32, ctx.weight) as u32;
+        outcome
+    }
+
+    pub(crate) fn score_unweighted(&self, ctx: &ScoringContext) -> ScoringOutcome {
         match self {
-            ScoringRule::SteppedDecay => score_stepped_decay(ctx),
-            ScoringRule::LinearDecay => score_linear_decay(ctx),
-            ScoringRule::FixedScore => score_fixed(ctx),
-            ScoringRule::StreakBonus => score_streak_bonus(ctx),
-            ScoringRule::PositionRace => score_position_race(ctx),
+            ScoringRule::SteppedDecay => score_stepped_decay(&SteppedDecayParams::default(), ctx),
+            ScoringRule::LinearDecay => score_linear_decay(&LinearDecayParams::default(), ctx),
+            ScoringRule::FixedScore => score_fixed(&FixedScoreParams::default(), ctx),
+            ScoringRule::StreakBonus => score_streak_bonus(&StreakBonusParams::default(), ctx),
+            ScoringRule::PositionRace => score_position_race(&PositionRaceParams::default(), ctx),
+            ScoringRule::WithParams(params) => params.score(ctx),
+            ScoringRule::Composite(rule) => score_composite(rule, ctx),
+            ScoringRule::Custom(rule) => rule.scorer.score(&rule.params, ctx),
         }
     }
 
-    /// Points awarded for a given arrival position under the PositionRace rule.
+    /// Whether scores depend on the answer streak, so unanswered questions must break it.
+    pub fn uses_streak(&self) -> bool {
+        match self {
+            ScoringRule::StreakBonus | ScoringRule::WithParams(RuleParams::StreakBonus(_)) => true,
+            ScoringRule::Composite(rule) => {
+                rule.base.uses_streak()
+                    || rule
+                        .modifiers
+                        .iter()
+                        .any(|m| matches!(m, ScoringModifier::Streak(_)))
+            }
+            ScoringRule::Custom(rule) => rule.scorer.uses_streak(),
+            _ => false,
+        }
+    }
+
+    /// Points awarded for a given arrival position under the default PositionRace schedule.
     /// 1st → MAX_SCORE, 2nd → ¾, 3rd → ½, 4th+ → ¼ (integer division, rounds down).
     pub fn position_points(pos: u32) -> u32 {
-        match pos {
-            1 => MAX_SCORE,
-            2 => MAX_SCORE * 3 / 4,
-            3 => MAX_SCORE / 2,
-            _ => MAX_SCORE / 4,
+        PositionRaceParams::default().points(pos)
+    }
+
+    /// Human-readable name; a composite lists its base and modifiers,
+    /// e.g. "Linear Decay + Streak".
+    pub fn display_name(&self) -> String {
+        match self {
+            ScoringRule::Composite(rule) => std::iter::once(rule.base.display_name())
+                .chain(rule.modifiers.iter().map(|m| m.display_name().to_string()))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            ScoringRule::Custom(rule) => rule.scorer.display_name().into(),
+            builtin => builtin
+                .params()
+                .map(|p| p.display_name())
+                .unwrap_or_default()
+                .into(),
+        }
+    }
+
+    /// Reject parameters no host could mean, naming the first offending one.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ScoringRule::WithParams(params) => params.validate()?,
+            ScoringRule::Composite(rule) => {
+                if matches!(*rule.base, ScoringRule::Composite(_)) {
+                    return Err("A composite rule's base cannot itself be composite".into());
+                }
+                rule.base.validate()?;
+                for (i, modifier) in rule.modifiers.iter().enumerate() {
+                    if rule.modifiers[..i]
+                        .iter()
+                        .any(|m| std::mem::discriminant(m) == std::mem::discriminant(modifier))
+                    {
+                        return Err(format!(
+                            "Modifier '{}' is listed more than once",
+                            modifier.name()
+                        ));
+                    }
+                    modifier.validate()?;
+                }
+            }
+            ScoringRule::Custom(rule) => rule.scorer.validate(&rule.params)?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl RuleParams {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuleParams::SteppedDecay(_) => "stepped_decay",
+            RuleParams::LinearDecay(_) => "linear_decay",
+            RuleParams::FixedScore(_) => "fixed_score",
+            RuleParams::StreakBonus(_) => "streak_bonus",
+            RuleParams::PositionRace(_) => "position_race",
         }
     }
 
     pub fn display_name(&self) -> &'static str {
         match self {
-            ScoringRule::SteppedDecay => "Stepped Decay",
-            ScoringRule::LinearDecay => "Linear Decay",
-            ScoringRule::FixedScore => "Fixed Score",
-            ScoringRule::StreakBonus => "Streak Bonus",
-            ScoringRule::PositionRace => "Position Race",
+            RuleParams::SteppedDecay(_) => "Stepped Decay",
+            RuleParams::LinearDecay(_) => "Linear Decay",
+            RuleParams::FixedScore(_) => "Fixed Score",
+            RuleParams::StreakBonus(_) => "Streak Bonus",
+            RuleParams::PositionRace(_) => "Position Race",
+        }
+    }
+
+    fn score(&self, ctx: &ScoringContext) -> ScoringOutcome {
+        match self {
+            RuleParams::SteppedDecay(params) => score_stepped_decay(params, ctx),
+            RuleParams::LinearDecay(params) => score_linear_decay(params, ctx),
+            RuleParams::FixedScore(params) => score_fixed(params, ctx),
+            RuleParams::StreakBonus(params) => score_streak_bonus(params, ctx),
+            RuleParams::PositionRace(params) => score_position_race(params, ctx),
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            RuleParams::SteppedDecay(params) => {
+                check_max_points(params.max_points)?;
+                if !(1..=MAX_TIME_LIMIT_SEC).contains(&params.step_sec) {
+                    return Err(format!(
+                        "step_sec must be between 1 and {MAX_TIME_LIMIT_SEC} seconds"
+                    ));
+                }
+            }
+            RuleParams::LinearDecay(LinearDecayParams { max_points })
+            | RuleParams::FixedScore(FixedScoreParams { max_points }) => {
+                check_max_points(*max_points)?;
+            }
+            RuleParams::StreakBonus(params) => {
+                check_max_points(params.max_points)?;
+                check_streak(params.streak_increment, params.streak_cap)?;
+            }
+            RuleParams::PositionRace(params) => check_schedule(&params.schedule)?,
         }
+        Ok(())
     }
 }
 
-fn score_stepped_decay(ctx: &ScoringContext) -> ScoringOutcome {
-    let points = if ctx.correct {
-        let num_steps = (ctx.time_limit_sec / 5).max(1);
-        let step_size = MAX_SCORE / num_steps as u32;
-        let steps_elapsed = ctx.time_taken_ms / 5000;
-        MAX_SCORE
-            .saturating_sub(steps_elapsed as u32 * step_size)
-            .max(1)
-    } else {
-        0
-    };
-    ScoringOutcome {
-        points,
-        position: None,
-        streak_multiplier: 1.0,
+impl ScoringModifier {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScoringModifier::Streak(_) => "streak",
+            ScoringModifier::PositionBonus(_) => "position_bonus",
+            ScoringModifier::WrongAnswerPenalty(_) => "wrong_answer_penalty",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ScoringModifier::Streak(_) => "Streak",
+            ScoringModifier::PositionBonus(_) => "Position Bonus",
+            ScoringModifier::WrongAnswerPenalty(_) => "Wrong-Answer Penalty",
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            ScoringModifier::Streak(m) => check_streak(m.streak_increment, m.streak_cap),
+            ScoringModifier::PositionBonus(m) => check_schedule(&m.schedule),
+            ScoringModifier::WrongAnswerPenalty(m) => {
+                if m.points > MAX_POINTS_LIMIT {
+                    Err(format!("Penalty points must be at most {MAX_POINTS_LIMIT}"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Layer this modifier onto `outcome`, the base rule's result for `ctx`.
+    fn apply(&self, outcome: &mut ScoringOutcome, ctx: &ScoringContext) {
+        match self {
+            ScoringModifier::Streak(m) => {
+                let multiplier = streak_multiplier(ctx.streak, m.streak_increment, m.streak_cap);
+                if ctx.correct {
+                    outcome.points = (outcome.points as f64 * multiplier) as i32;
+                }
+                outcome.streak_multiplier *= multiplier;
+            }
+            ScoringModifier::PositionBonus(m) => {
+                if ctx.correct {
+                    let pos = arrival_position(ctx);
+                    let bonus = m.schedule.get(pos as usize - 1).copied().unwrap_or(0);
+                    outcome.points += bonus as i32;
+                    outcome.position = Some(pos);
+                }
+            }
+            ScoringModifier::WrongAnswerPenalty(m) => {
+                if !ctx.correct {
+                    outcome.points -= m.points as i32;
+                    outcome.penalty += m.points;
+                }
+            }
+        }
     }
 }
 
-fn score_linear_decay(ctx: &ScoringContext) -> ScoringOutcome {
-    let points = if ctx.correct {
-        let step_size = (MAX_SCORE / ctx.time_limit_sec as u32).max(1);
-        let secs_elapsed = ctx.time_taken_ms / 1000;
-        MAX_SCORE
-            .saturating_sub(secs_elapsed as u32 * step_size)
-            .max(1)
+impl PositionRaceParams {
+    /// Points for the 1-based arrival position `pos`.
+    pub fn points(&self, pos: u32) -> u32 {
+        let index = (pos.max(1) as usize - 1).min(self.schedule.len().saturating_sub(1));
+        self.schedule.get(index).copied().unwrap_or(0)
+    }
+}
+
+fn check_max_points(max_points: u32) -> Result<(), String> {
+    if (1..=MAX_POINTS_LIMIT).contains(&max_points) {
+        Ok(())
     } else {
-        0
-    };
-    ScoringOutcome {
-        points,
-        position: None,
-        streak_multiplier: 1.0,
+        Err(format!(
+            "max_points must be between 1 and {MAX_POINTS_LIMIT}"
+        ))
     }
 }
 
-fn score_fixed(ctx: &ScoringContext) -> ScoringOutcome {
-    let points = if ctx.correct { MAX_SCORE } else { 0 };
-    ScoringOutcome {
-        points,
-        position: None,
-        streak_multiplier: 1.0,
+fn check_streak(increment: f64, cap: Option<f64>) -> Result<(), String> {
+    if !(0.0..=MAX_STREAK_INCREMENT).contains(&increment) {
+        return Err(format!(
+            "streak_increment must be between 0 and {MAX_STREAK_INCREMENT}"
+        ));
+    }
+    if let Some(cap) = cap
+        && !(cap.is_finite() && cap >= 1.0)
+    {
+        return Err("streak_cap must be a multiplier of at least 1".into());
     }
+    Ok(())
 }
 
-fn score_streak_bonus(ctx: &ScoringContext) -> ScoringOutcome {
-    let streak_multiplier = 1.0 + ctx.streak as f64 * 0.5;
+fn check_schedule(schedule: &[u32]) -> Result<(), String> {
+    if schedule.is_empty() {
+        return Err("schedule must list points for at least one position".into());
+    }
+    if schedule
+        .iter()
+        .any(|&points| check_max_points(points).is_err())
+    {
+        return Err(format!(
+            "schedule entries must be between 1 and {MAX_POINTS_LIMIT}"
+        ));
+    }
+    if schedule.windows(2).any(|w| w[1] > w[0]) {
+        return Err("schedule must not award later positions more points".into());
+    }
+    Ok(())
+}
+
+/// The object form of a rule, tagged by name.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+enum TaggedRule {
+    SteppedDecay(SteppedDecayParams),
+    LinearDecay(LinearDecayParams),
+    FixedScore(FixedScoreParams),
+    StreakBonus(StreakBonusParams),
+    PositionRace(PositionRaceParams),
+    Composite(CompositeRule),
+}
+
+impl From<TaggedRule> for ScoringRule {
+    fn from(rule: TaggedRule) -> Self {
+        match rule {
+            TaggedRule::SteppedDecay(p) => ScoringRule::with_params(RuleParams::SteppedDecay(p)),
+            TaggedRule::LinearDecay(p) => ScoringRule::with_params(RuleParams::LinearDecay(p)),
+            TaggedRule::FixedScore(p) => ScoringRule::with_params(RuleParams::FixedScore(p)),
+            TaggedRule::StreakBonus(p) => ScoringRule::with_params(RuleParams::StreakBonus(p)),
+            TaggedRule::PositionRace(p) => ScoringRule::with_params(RuleParams::PositionRace(p)),
+            TaggedRule::Composite(r) => ScoringRule::Composite(r),
+        }
+    }
+}
+
+impl TaggedRule {
+    /// The tagged form of a built-in rule; `None` for a custom one.
+    fn of(rule: &ScoringRule) -> Option<Self> {
+        if let ScoringRule::Composite(r) = rule {
+            return Some(TaggedRule::Composite(r.clone()));
+        }
+        Some(match rule.params()? {
+            RuleParams::SteppedDecay(p) => TaggedRule::SteppedDecay(p),
+            RuleParams::LinearDecay(p) => TaggedRule::LinearDecay(p),
+            RuleParams::FixedScore(p) => TaggedRule::FixedScore(p),
+            RuleParams::StreakBonus(p) => TaggedRule::StreakBonus(p),
+            RuleParams::PositionRace(p) => TaggedRule::PositionRace(p),
+        })
+    }
+}
+
+impl ScoringRule {
+    /// The object form with every parameter spelled out, as sent to clients.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            ScoringRule::Custom(rule) => rule.to_json(),
+            rule => serde_json::to_value(TaggedRule::of(rule)).unwrap_or_default(),
+        }
+    }
+}
+
+impl Serialize for ScoringRule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ScoringRule::Custom(rule) => rule.to_json().serialize(serializer),
+            rule if ScoringRule::from_name(rule.name()).as_ref() == Some(rule) => {
+                serializer.serialize_str(rule.name())
+            }
+            rule => TaggedRule::of(rule).serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ScoringRule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(name) => ScoringRule::from_name(&name).ok_or_else(|| {
+                D::Error::custom(format!(
+                    "unknown scoring rule '{name}', expected one of {}",
+                    ScoringRule::NAMES.join(", ")
+                ))
+            }),
+            value => TaggedRule::deserialize(value)
+                .map(ScoringRule::from)
+                .map_err(D::Error::custom),
+        }
+    }
+}
+
+// ── Building blocks ──────────────────────────────────────────────────────────
+//
+// Each rule, and each composite modifier, is assembled from these.
+
+/// Points for a correct answer under stepped decay: `max_points` for the first
+/// `step_sec` seconds, one step less per further `step_sec`, and never below 1.
+pub fn stepped_decay_points(
+    params: &SteppedDecayParams,
+    time_taken_ms: u64,
+    time_limit_sec: u64,
+) -> u32 {
+    let step_sec = params.step_sec.max(1);
+    let num_steps = (time_limit_sec / step_sec).max(1);
+    let step_size = params.max_points / num_steps as u32;
+    let steps_elapsed = time_taken_ms / (step_sec * 1000);
+    params
+        .max_points
+        .saturating_sub(steps_elapsed as u32 * step_size)
+        .max(1)
+}
+
+/// Points for a correct answer under linear decay: `max_points` less an even
+/// share per whole second taken, and never below 1.
+pub fn linear_decay_points(
+    params: &LinearDecayParams,
+    time_taken_ms: u64,
+    time_limit_sec: u64,
+) -> u32 {
+    let step_size = (params.max_points / time_limit_sec.max(1) as u32).max(1);
+    let secs_elapsed = time_taken_ms / 1000;
+    params
+        .max_points
+        .saturating_sub(secs_elapsed as u32 * step_size)
+        .max(1)
+}
+
+/// `1 + streak × increment`, capped at `cap` when there is one.
+pub fn streak_multiplier(streak: u32, increment: f64, cap: Option<f64>) -> f64 {
+    let uncapped = 1.0 + streak as f64 * increment;
+    cap.map_or(uncapped, |cap| uncapped.min(cap))
+}
+
+/// The 1-based arrival rank this correct answer takes.
+pub fn arrival_position(ctx: &ScoringContext) -> u32 {
+    ctx.correct_answer_count + 1
+}
+
+/// Scale `points` by `weight`, rounding to the nearest point. A nonzero amount
+/// never drops to 0 however small the weight.
+fn apply_weight(points: i32, weight: f64) -> i32 {
+    let weighted = (points as f64 * weight).round();
+    match points.signum() {
+        1 => weighted.max(1.0) as i32,
+        -1 => weighted.min(-1.0) as i32,
+        _ => 0,
+    }
+}
+
+fn score_stepped_decay(params: &SteppedDecayParams, ctx: &ScoringContext) -> ScoringOutcome {
+    ScoringOutcome::points(if ctx.correct {
+        stepped_decay_points(params, ctx.time_taken_ms, ctx.time_limit_sec)
+    } else {
+        0
+    })
+}
+
+fn score_linear_decay(params: &LinearDecayParams, ctx: &ScoringContext) -> ScoringOutcome {
+    ScoringOutcome::points(if ctx.correct {
+        linear_decay_points(params, ctx.time_taken_ms, ctx.time_limit_sec)
+    } else {
+        0
+    })
+}
+
+fn score_fixed(params: &FixedScoreParams, ctx: &ScoringContext) -> ScoringOutcome {
+    ScoringOutcome::points(if ctx.correct { params.max_points } else { 0 })
+}
+
+fn score_streak_bonus(params: &StreakBonusParams, ctx: &ScoringContext) -> ScoringOutcome {
+    let streak_multiplier =
+        streak_multiplier(ctx.streak, params.streak_increment, params.streak_cap);
     let points = if ctx.correct {
-        (MAX_SCORE as f64 * streak_multiplier) as u32
+        (params.max_points as f64 * streak_multiplier) as u32
     } else {
         0
     };
     ScoringOutcome {
-        points,
-        position: None,
         streak_multiplier,
+        ..ScoringOutcome::points(points)
     }
 }
 
-fn score_position_race(ctx: &ScoringContext) -> ScoringOutcome {
+fn score_position_race(params: &PositionRaceParams, ctx: &ScoringContext) -> ScoringOutcome {
     if !ctx.correct {
-        return ScoringOutcome {
-            points: 0,
-            position: None,
-            streak_multiplier: 1.0,
-        };
+        return ScoringOutcome::points(0);
     }
-    let pos = ctx.correct_answer_count + 1;
+    let pos = arrival_position(ctx);
     ScoringOutcome {
-        points: ScoringRule::position_points(pos),
         position: Some(pos),
-        streak_multiplier: 1.0,
+        ..ScoringOutcome::points(params.points(pos))
+    }
+}
+
+fn score_composite(rule: &CompositeRule, ctx: &ScoringContext) -> ScoringOutcome {
+    let mut outcome = rule.base.score_unweighted(ctx);
+    for modifier in &rule.modifiers {
+        modifier.apply(&mut outcome, ctx);
     }
+    outcome
 }
 
 #[cfg(test)]
@@ -154,6 +861,7 @@ mod tests {
             time_limit_sec: 20,
             streak: 0,
             correct_answer_count: 0,
+            weight: 1.0,
         }
     }
 
@@ -166,7 +874,7 @@ mod tests {
     #[case(3, 2500, 2.5)]
     fn streak_bonus_multiplier(
         #[case] streak: u32,
-        #[case] expected_pts: u32,
+        #[case] expected_pts: i32,
         #[case] expected_mult: f64,
     ) {
         let ctx = ScoringContext {
@@ -181,7 +889,7 @@ mod tests {
     #[rstest]
     #[case(true, 1000)]
     #[case(false, 0)]
-    fn streak_bonus_correct_vs_wrong(#[case] correct: bool, #[case] expected: u32) {
+    fn streak_bonus_correct_vs_wrong(#[case] correct: bool, #[case] expected: i32) {
         assert_eq!(
             ScoringRule::StreakBonus.score(&ctx(correct)).points,
             expected
@@ -217,7 +925,7 @@ mod tests {
     #[case(1, 750)] // second correct → pos 2
     #[case(2, 500)] // third correct → pos 3
     #[case(3, 250)] // fourth+ correct → pos 4
-    fn position_race_score_by_count(#[case] prior_count: u32, #[case] expected_pts: u32) {
+    fn position_race_score_by_count(#[case] prior_count: u32, #[case] expected_pts: i32) {
         let ctx = ScoringContext {
             correct_answer_count: prior_count,
             ..ctx(true)
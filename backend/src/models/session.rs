@@ -1,21 +1,51 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
 
+use super::asset::QuizAssets;
+use super::draw::QuestionDraw;
+use super::elimination::EliminationSettings;
+use super::leaderboard::{
+    AskedQuestion, LeaderboardEntry, TieBreaker, compute_leaderboard_with,
+    compute_survival_leaderboard,
+};
 use super::player::Player;
-use super::quiz::Quiz;
+use super::quiz::{Question, Quiz};
 use super::scoring_rule::ScoringRule;
+use super::shuffle::ShuffleSettings;
+use super::team::TeamSettings;
+
+/// Bounds for the per-question time limit, whether set by the host or by quiz front-matter.
+pub const MIN_TIME_LIMIT_SEC: u64 = 10;
+pub const MAX_TIME_LIMIT_SEC: u64 = 60;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SessionStatus {
     Lobby,
     Active,
+    /// The host disconnected mid-game; `tiebreak` is set when it was during a tiebreak.
     Paused,
+    /// Players tied for first are answering sudden-death tiebreaker questions.
+    Tiebreak,
     Finished,
 }
 
+/// Progress of a sudden-death tiebreak.
+#[derive(Debug, Default)]
+pub struct Tiebreak {
+    /// IDs of the players still tied for first.
+    pub contenders: Vec<String>,
+    /// Index into `quiz.tiebreakers` of the question being asked, once one has been.
+    pub question: Option<usize>,
+    /// Whether the current question still takes answers.
+    pub open: bool,
+    /// Whether each contender who has answered the current question got it right.
+    pub answers: HashMap<String, bool>,
+}
+
 #[derive(Debug)]
 pub struct GameSession {
     pub join_code: String,
@@ -31,10 +61,39 @@ pub struct GameSession {
     /// Counts correct answers received for the current question under PositionRace rule.
     /// Reset to 0 at the start of each new question.
     pub correct_answer_count: u32,
+    /// Media files from the uploaded bundle, served under the session's asset URL.
+    pub assets: Arc<QuizAssets>,
+    pub shuffle: ShuffleSettings,
+    /// Quiz question index for each position in play order, fixed when the game starts.
+    pub question_order: Vec<usize>,
+    /// Set when `quiz` holds questions drawn from a larger bank.
+    pub draw: Option<QuestionDraw>,
+    /// How leaderboards order players with equal scores.
+    pub tie_breaker: TieBreaker,
+    /// Set while `status` is `Tiebreak`.
+    pub tiebreak: Option<Tiebreak>,
+    /// Standings sent with the last `question_ended`, for rank movement in the next.
+    pub previous_leaderboard: Vec<LeaderboardEntry>,
+    /// Set up in the lobby; a team game when it names any teams.
+    pub teams: TeamSettings,
+    /// Set in the lobby; players are knocked out once they run out of lives.
+    pub elimination: EliminationSettings,
 }
 
 impl GameSession {
+    /// Creates a lobby session. The quiz front-matter, when present, overrides the
+    /// default scoring rule and `default_time_limit_sec`, and turns on shuffling.
     pub fn new(join_code: String, quiz: Quiz, default_time_limit_sec: u64) -> Self {
+        let scoring_rule = quiz.metadata.scoring_rule.clone().unwrap_or_default();
+        let time_limit_sec = quiz
+            .metadata
+            .time_limit_sec
+            .unwrap_or(default_time_limit_sec);
+        let shuffle = ShuffleSettings::new(
+            quiz.metadata.shuffle_questions,
+            quiz.metadata.shuffle_options,
+        );
+        let question_order = (0..quiz.questions.len()).collect();
         Self {
             join_code,
             quiz,
@@ -44,9 +103,18 @@ impl GameSession {
             status: SessionStatus::Lobby,
             question_started: None,
             created_at: Instant::now(),
-            scoring_rule: ScoringRule::default(),
-            time_limit_sec: default_time_limit_sec,
+            scoring_rule,
+            time_limit_sec,
             correct_answer_count: 0,
+            assets: Arc::default(),
+            shuffle,
+            question_order,
+            draw: None,
+            tie_breaker: TieBreaker::default(),
+            tiebreak: None,
+            previous_leaderboard: Vec::new(),
+            teams: TeamSettings::default(),
+            elimination: EliminationSettings::default(),
         }
     }
 
@@ -57,6 +125,42 @@ impl GameSession {
             .count()
     }
 
+    /// Players not eliminated; everyone outside an elimination game.
+    pub fn players_in_game(&self) -> impl Iterator<Item = &Player> {
+        self.players.values().filter(|p| !p.is_eliminated())
+    }
+
+    /// Whether an elimination game is down to its last player. Only eliminations
+    /// get it there, so a game that starts with one player still plays its questions.
+    pub fn last_player_standing(&self) -> bool {
+        self.elimination.enabled
+            && self.players.values().any(Player::is_eliminated)
+            && self.players_in_game().count() <= 1
+    }
+
+    /// Current standings, survivors first in an elimination game.
+    pub fn leaderboard(&self, mark_winner: bool) -> Vec<LeaderboardEntry> {
+        let players: Vec<&Player> = self.players.values().collect();
+        let asked = self.asked_questions();
+        if self.elimination.enabled {
+            compute_survival_leaderboard(&players, mark_winner, self.tie_breaker, &asked)
+        } else {
+            compute_leaderboard_with(&players, mark_winner, self.tie_breaker, &asked)
+        }
+    }
+
+    /// The scored questions asked so far, up to and including the current one.
+    fn asked_questions(&self) -> Vec<AskedQuestion> {
+        let asked = (self.current_question + 1).max(0) as usize;
+        (0..asked.min(self.quiz.questions.len()))
+            .filter(|&position| !self.question_at(position).is_poll())
+            .map(|position| AskedQuestion {
+                position,
+                time_limit_ms: self.time_limit_at(position) * 1000,
+            })
+            .collect()
+    }
+
     pub fn total_player_count(&self) -> usize {
         self.players.len()
     }
@@ -68,4 +172,53 @@ impl GameSession {
     pub fn total_questions(&self) -> usize {
         self.quiz.questions.len()
     }
+
+    /// Index into `quiz.questions` of the question played at `position`.
+    pub fn quiz_question_index(&self, position: usize) -> usize {
+        self.question_order
+            .get(position)
+            .copied()
+            .unwrap_or(position)
+    }
+
+    /// The question played at `position`.
+    pub fn question_at(&self, position: usize) -> &Question {
+        &self.quiz.questions[self.quiz_question_index(position)]
+    }
+
+    /// Index into `quiz.rounds` of the round played at `position`. Shuffling keeps
+    /// questions within their round, so positions and quiz indices share rounds.
+    pub fn round_at(&self, position: usize) -> Option<usize> {
+        self.quiz.round_of(position)
+    }
+
+    /// The scoring rule for the question at `position`: its round's, if the round
+    /// sets one, otherwise the session's.
+    pub fn scoring_rule_at(&self, position: usize) -> &ScoringRule {
+        self.round_at(position)
+            .and_then(|r| self.quiz.rounds[r].scoring_rule.as_ref())
+            .unwrap_or(&self.scoring_rule)
+    }
+
+    /// The time limit for the question at `position`, chosen like `scoring_rule_at`.
+    pub fn time_limit_at(&self, position: usize) -> u64 {
+        self.round_at(position)
+            .and_then(|r| self.quiz.rounds[r].time_limit_sec)
+            .unwrap_or(self.time_limit_sec)
+    }
+
+    /// Quiz option index for each option slot `player_id` sees at `position`. The
+    /// order follows the player's display name, which the finished game reports
+    /// alongside the seed, not their per-connection ID.
+    pub fn option_order(&self, player_id: &str, position: usize) -> Vec<usize> {
+        let display_name = self
+            .players
+            .get(player_id)
+            .map_or("", |p| p.display_name.as_str());
+        self.shuffle.option_order(
+            display_name,
+            self.quiz_question_index(position),
+            self.question_at(position).options.len(),
+        )
+    }
 }
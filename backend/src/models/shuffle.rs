@@ -0,0 +1,101 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+/// How a session reorders its quiz. Question order is shared by the whole session;
+/// option order differs per player. Everything derives from `seed` and the players'
+/// display names, so replaying a game with the same seed and names reproduces every
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleSettings {
+    pub questions: bool,
+    pub options: bool,
+    pub seed: u64,
+}
+
+impl ShuffleSettings {
+    /// Shuffling as requested, with a fresh random seed.
+    pub fn new(questions: bool, options: bool) -> Self {
+        Self {
+            questions,
+            options,
+            seed: random_seed(),
+        }
+    }
+
+    /// Quiz question index for each position in the session, in play order.
+    pub fn question_order(&self, question_count: usize) -> Vec<usize> {
+        self.question_order_in_rounds(std::slice::from_ref(&(0..question_count)))
+    }
+
+    /// `question_order` for a quiz split into rounds: questions only move within
+    /// their own round, so rounds keep their place in play order.
+    pub fn question_order_in_rounds(&self, rounds: &[Range<usize>]) -> Vec<usize> {
+        if !self.questions {
+            return rounds.iter().flat_map(Range::clone).collect();
+        }
+        rounds
+            .iter()
+            .enumerate()
+            .flat_map(|(i, range)| {
+                let seed = self.seed.wrapping_add(i as u64);
+                permutation(range.len(), seed)
+                    .into_iter()
+                    .map(|offset| range.start + offset)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Quiz option index for each option slot shown to the player named
+    /// `display_name` for the quiz question `question`.
+    pub fn option_order(
+        &self,
+        display_name: &str,
+        question: usize,
+        option_count: usize,
+    ) -> Vec<usize> {
+        if !self.options {
+            return (0..option_count).collect();
+        }
+        let mut seed = self.seed ^ fnv1a(display_name.as_bytes());
+        seed = splitmix64(&mut seed) ^ question as u64;
+        permutation(option_count, seed)
+    }
+}
+
+/// Largest seed a browser can hold exactly in a JavaScript number.
+pub const MAX_SEED: u64 = (1 << 53) - 1;
+
+/// A seed for a new session; not cryptographic, only needs to differ between games.
+pub fn random_seed() -> u64 {
+    let bytes = uuid::Uuid::new_v4().into_bytes();
+    u64::from_le_bytes(bytes[..8].try_into().unwrap_or_default()) & MAX_SEED
+}
+
+/// A uniformly shuffled `0..len`, fully determined by `seed` (Fisher–Yates driven
+/// by SplitMix64, so the result is stable across platforms and releases).
+pub fn permutation(len: usize, seed: u64) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    let mut state = seed;
+    for i in (1..len).rev() {
+        let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    order
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Stable string hash, used to give each player their own option order.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
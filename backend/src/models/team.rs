@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use super::shuffle::permutation;
+
+/// Most teams one session can have.
+pub const MAX_TEAMS: usize = 16;
+/// Longest team name, in characters.
+pub const MAX_TEAM_NAME_CHARS: usize = 30;
+
+/// How a team's score is built from its members' points. Each question is
+/// aggregated on its own, and the team scores the total over all questions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamAggregation {
+    /// Total of the members' points, so bigger teams have an edge.
+    #[default]
+    Sum,
+    /// Mean of the members' points, rounded toward zero, so team size does not matter.
+    Average,
+    /// The best member's points on each question.
+    Best,
+}
+
+impl TeamAggregation {
+    /// The team's points for one question, given what each member scored on it;
+    /// 0 for a team without members.
+    pub fn aggregate(self, points: &[i32]) -> i64 {
+        if points.is_empty() {
+            return 0;
+        }
+        match self {
+            TeamAggregation::Sum => points.iter().map(|&p| i64::from(p)).sum(),
+            TeamAggregation::Average => {
+                let total: i64 = points.iter().map(|&p| i64::from(p)).sum();
+                total / points.len() as i64
+            }
+            TeamAggregation::Best => points.iter().copied().max().map_or(0, i64::from),
+        }
+    }
+}
+
+/// The teams of a session. With no `names`, everyone plays alone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamSettings {
+    pub names: Vec<String>,
+    pub aggregation: TeamAggregation,
+}
+
+impl TeamSettings {
+    pub fn is_team_game(&self) -> bool {
+        !self.names.is_empty()
+    }
+
+    /// The existing team called `name`, ignoring case and surrounding spaces.
+    pub fn find(&self, name: &str) -> Option<&str> {
+        let name = name.trim();
+        self.names
+            .iter()
+            .find(|n| n.eq_ignore_ascii_case(name))
+            .map(String::as_str)
+    }
+
+    /// The team called `name`, added first when there is none yet. Returns the
+    /// team's name as stored.
+    pub fn join(&mut self, name: &str) -> Result<String, String> {
+        if let Some(existing) = self.find(name) {
+            return Ok(existing.to_string());
+        }
+        let name = validate_team_name(name)?;
+        if self.names.len() >= MAX_TEAMS {
+            return Err(format!("A game can have at most {MAX_TEAMS} teams"));
+        }
+        self.names.push(name.clone());
+        Ok(name)
+    }
+}
+
+/// `name` trimmed, or why it cannot be a team name.
+pub fn validate_team_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Team name is empty".into());
+    }
+    if name.chars().count() > MAX_TEAM_NAME_CHARS {
+        return Err(format!(
+            "Team name '{name}' is longer than {MAX_TEAM_NAME_CHARS} characters"
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// A team index for each of `player_count` players, in a random order fixed by
+/// `seed`, so that team sizes differ by at most one.
+pub fn balance_teams(player_count: usize, team_count: usize, seed: u64) -> Vec<usize> {
+    let mut teams = vec![0; player_count];
+    if team_count == 0 {
+        return teams;
+    }
+    for (slot, player) in permutation(player_count, seed).into_iter().enumerate() {
+        teams[player] = slot % team_count;
+    }
+    teams
+}
@@ -5,12 +5,27 @@ use serde_json::json;
 use tokio::sync::{RwLock, broadcast};
 use tokio::time::{Duration, sleep};
 
-use crate::models::leaderboard::compute_leaderboard;
+use crate::models::asset::asset_url;
+use crate::models::elimination::EliminationSettings;
+use crate::models::leaderboard::{
+    LeaderboardEntry, RankMovement, TieBreaker, compute_team_leaderboard, rank_leaderboard,
+    rank_movement,
+};
 use crate::models::player::{Answer, Player};
-use crate::models::scoring_rule::{ScoringContext, ScoringRule};
-use crate::models::session::{GameSession, SessionStatus};
+use crate::models::scoring_rule::ScoringContext;
+use crate::models::session::{
+    GameSession, MAX_TIME_LIMIT_SEC, MIN_TIME_LIMIT_SEC, SessionStatus, Tiebreak,
+};
+use crate::models::shuffle::{MAX_SEED, ShuffleSettings, random_seed};
+use crate::models::team::{
+    MAX_TEAMS, TeamAggregation, TeamSettings, balance_teams, validate_team_name,
+};
+use crate::services::scorer_registry::ScorerRegistry;
 use crate::services::session_manager::SessionManager;
 
+/// How long the `round_started` screen shows before the round's first question.
+const ROUND_INTRO_SEC: u64 = 3;
+
 /// Message that can be sent through the broadcast channel.
 #[derive(Debug, Clone)]
 pub enum GameEvent {
@@ -23,21 +38,45 @@ pub enum GameEvent {
 }
 
 /// Updates the session's scoring rule if still in Lobby and broadcasts the change.
+/// `payload` names the rule and may override its parameters, e.g.
+/// `{"rule": "linear_decay", "max_points": 2000}`; parameters left out keep their
+/// defaults. Any rule in `scorers` may be named, custom ones included. The
+/// broadcast spells out every parameter plus the rule's `display_name`.
+/// Returns an error event to the host if the rule is unknown or a parameter is
+/// out of range.
 /// Silently ignored when the session is Active, Paused, or Finished.
 pub fn handle_set_scoring_rule(
     session: &mut GameSession,
-    rule: ScoringRule,
+    scorers: &ScorerRegistry,
+    payload: &serde_json::Value,
     tx: &broadcast::Sender<GameEvent>,
 ) {
     if session.status != SessionStatus::Lobby {
         return;
     }
+    let rule = match scorers.resolve(payload) {
+        Ok(rule) => rule,
+        Err(message) => {
+            let _ = tx.send(GameEvent::HostOnly(
+                json!({
+                    "type": "error",
+                    "payload": {
+                        "code": "invalid_scoring_rule",
+                        "message": message
+                    }
+                })
+                .to_string(),
+            ));
+            return;
+        }
+    };
+    let mut rule_value = rule.to_json();
+    rule_value["display_name"] = json!(rule.display_name());
     session.scoring_rule = rule;
-    let rule_value = serde_json::to_value(&session.scoring_rule).unwrap_or_default();
     let _ = tx.send(GameEvent::BroadcastAll(
         json!({
             "type": "scoring_rule_set",
-            "payload": { "rule": rule_value }
+            "payload": rule_value
         })
         .to_string(),
     ));
@@ -45,7 +84,8 @@ pub fn handle_set_scoring_rule(
 
 /// Updates the session's time limit if still in Lobby and broadcasts the change.
 /// Silently ignored when the session is Active, Paused, or Finished.
-/// Returns an error event to the host if `seconds` is out of the valid range [10, 60].
+/// Returns an error event to the host if `seconds` is out of the valid range
+/// [`MIN_TIME_LIMIT_SEC`, `MAX_TIME_LIMIT_SEC`].
 pub fn handle_set_time_limit(
     session: &mut GameSession,
     seconds: u64,
@@ -54,13 +94,15 @@ pub fn handle_set_time_limit(
     if session.status != SessionStatus::Lobby {
         return;
     }
-    if !(10..=60).contains(&seconds) {
+    if !(MIN_TIME_LIMIT_SEC..=MAX_TIME_LIMIT_SEC).contains(&seconds) {
         let _ = tx.send(GameEvent::HostOnly(
             json!({
                 "type": "error",
                 "payload": {
                     "code": "invalid_time_limit",
-                    "message": "Time limit must be between 10 and 60 seconds"
+                    "message": format!(
+                        "Time limit must be between {MIN_TIME_LIMIT_SEC} and {MAX_TIME_LIMIT_SEC} seconds"
+                    ),
                 }
             })
             .to_string(),
@@ -77,6 +119,223 @@ pub fn handle_set_time_limit(
     ));
 }
 
+/// Updates how leaderboards break ties if still in Lobby and broadcasts the change.
+/// `payload` is `{"tie_breaker": "answer_time"}`; see [`TieBreaker`] for the names.
+/// Silently ignored when the session is Active, Paused, or Finished.
+/// Returns an error event to the host if the tie-breaker is unknown.
+pub fn handle_set_tie_breaker(
+    session: &mut GameSession,
+    payload: &serde_json::Value,
+    tx: &broadcast::Sender<GameEvent>,
+) {
+    if session.status != SessionStatus::Lobby {
+        return;
+    }
+    let tie_breaker = match serde_json::from_value::<TieBreaker>(payload["tie_breaker"].clone()) {
+        Ok(tie_breaker) => tie_breaker,
+        Err(e) => {
+            let _ = tx.send(GameEvent::HostOnly(
+                json!({
+                    "type": "error",
+                    "payload": {
+                        "code": "invalid_tie_breaker",
+                        "message": e.to_string()
+                    }
+                })
+                .to_string(),
+            ));
+            return;
+        }
+    };
+    session.tie_breaker = tie_breaker;
+    let _ = tx.send(GameEvent::BroadcastAll(
+        json!({
+            "type": "tie_breaker_set",
+            "payload": { "tie_breaker": tie_breaker }
+        })
+        .to_string(),
+    ));
+}
+
+/// Updates the session's elimination settings if still in Lobby and broadcasts them.
+/// `payload` is `{"enabled": true, "lives": 2, "max_answer_sec": 8}`; `lives` defaults
+/// to 1 and `max_answer_sec` to none. Returns an error event to the host for
+/// settings out of range.
+pub fn handle_set_elimination(
+    session: &mut GameSession,
+    payload: &serde_json::Value,
+    tx: &broadcast::Sender<GameEvent>,
+) {
+    if session.status != SessionStatus::Lobby {
+        return;
+    }
+    let elimination = serde_json::from_value::<EliminationSettings>(payload.clone())
+        .map_err(|e| e.to_string())
+        .and_then(|settings| settings.validate().map(|()| settings));
+    match elimination {
+        Ok(elimination) => session.elimination = elimination,
+        Err(message) => {
+            send_host_error(tx, "invalid_elimination", &message);
+            return;
+        }
+    }
+    let _ = tx.send(GameEvent::BroadcastAll(
+        json!({
+            "type": "elimination_set",
+            "payload": session.elimination,
+        })
+        .to_string(),
+    ));
+}
+
+/// Replaces the session's teams if still in Lobby and broadcasts them. `payload` is
+/// `{"names": ["Red", "Blue"], "aggregation": "average"}`; an empty list ends team
+/// play, and `aggregation` is kept when left out. Players whose team is gone are
+/// left without one. Returns an error event to the host for invalid teams.
+pub fn handle_set_teams(
+    session: &mut GameSession,
+    payload: &serde_json::Value,
+    tx: &broadcast::Sender<GameEvent>,
+) {
+    if session.status != SessionStatus::Lobby {
+        return;
+    }
+    let teams = match parse_teams(payload, session.teams.aggregation) {
+        Ok(teams) => teams,
+        Err(message) => {
+            send_host_error(tx, "invalid_teams", &message);
+            return;
+        }
+    };
+    for player in session.players.values_mut() {
+        player.team = player
+            .team
+            .as_deref()
+            .and_then(|team| teams.find(team))
+            .map(str::to_string);
+    }
+    session.teams = teams;
+    let _ = tx.send(GameEvent::BroadcastAll(teams_message(session)));
+}
+
+/// The teams in a `set_teams` payload, keeping `current` when it names no aggregation.
+fn parse_teams(
+    payload: &serde_json::Value,
+    current: TeamAggregation,
+) -> Result<TeamSettings, String> {
+    let aggregation = match payload.get("aggregation") {
+        None => current,
+        Some(value) => serde_json::from_value(value.clone()).map_err(|e| e.to_string())?,
+    };
+    let names: Vec<String> =
+        serde_json::from_value(payload["names"].clone()).map_err(|e| e.to_string())?;
+    if names.len() > MAX_TEAMS {
+        return Err(format!("A game can have at most {MAX_TEAMS} teams"));
+    }
+    let mut teams = TeamSettings {
+        names: Vec::new(),
+        aggregation,
+    };
+    for name in names {
+        let name = validate_team_name(&name)?;
+        if teams.find(&name).is_some() {
+            return Err(format!("Team '{name}' is listed twice"));
+        }
+        teams.names.push(name);
+    }
+    Ok(teams)
+}
+
+/// Deals every player into the session's teams at random, keeping team sizes
+/// within one of each other, if still in Lobby. Needs at least two teams.
+pub fn handle_auto_balance_teams(session: &mut GameSession, tx: &broadcast::Sender<GameEvent>) {
+    if session.status != SessionStatus::Lobby {
+        return;
+    }
+    if session.teams.names.len() < 2 {
+        send_host_error(
+            tx,
+            "invalid_teams",
+            "Set up at least two teams before balancing them",
+        );
+        return;
+    }
+    let mut player_ids: Vec<String> = session.players.keys().cloned().collect();
+    player_ids.sort();
+    let assignment = balance_teams(player_ids.len(), session.teams.names.len(), random_seed());
+    for (player_id, team) in player_ids.iter().zip(assignment) {
+        let name = session.teams.names[team].clone();
+        if let Some(player) = session.players.get_mut(player_id) {
+            player.team = Some(name);
+        }
+    }
+    let _ = tx.send(GameEvent::BroadcastAll(teams_message(session)));
+}
+
+/// The `teams_updated` message: each team with its members, and the aggregation.
+pub(crate) fn teams_message(session: &GameSession) -> String {
+    let mut players: Vec<&Player> = session.players.values().collect();
+    players.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+    let teams: Vec<_> = session
+        .teams
+        .names
+        .iter()
+        .map(|name| {
+            let members: Vec<_> = players
+                .iter()
+                .filter(|p| p.team.as_ref() == Some(name))
+                .map(|p| json!({ "player_id": p.id, "display_name": p.display_name }))
+                .collect();
+            json!({ "name": name, "members": members })
+        })
+        .collect();
+    json!({
+        "type": "teams_updated",
+        "payload": {
+            "teams": teams,
+            "aggregation": session.teams.aggregation,
+        }
+    })
+    .to_string()
+}
+
+fn send_host_error(tx: &broadcast::Sender<GameEvent>, code: &str, message: &str) {
+    let _ = tx.send(GameEvent::HostOnly(
+        json!({
+            "type": "error",
+            "payload": { "code": code, "message": message }
+        })
+        .to_string(),
+    ));
+}
+
+/// Updates the session's shuffle settings if still in Lobby and tells the host.
+/// A new seed is drawn unless the host supplies one to replay an earlier game.
+/// Silently ignored when the session is Active, Paused, or Finished.
+pub fn handle_set_shuffle(
+    session: &mut GameSession,
+    questions: bool,
+    options: bool,
+    seed: Option<u64>,
+    tx: &broadcast::Sender<GameEvent>,
+) {
+    if session.status != SessionStatus::Lobby {
+        return;
+    }
+    session.shuffle = ShuffleSettings {
+        questions,
+        options,
+        seed: seed.map_or_else(random_seed, |seed| seed & MAX_SEED),
+    };
+    let _ = tx.send(GameEvent::HostOnly(
+        json!({
+            "type": "shuffle_set",
+            "payload": session.shuffle,
+        })
+        .to_string(),
+    ));
+}
+
 pub async fn start_game(
     session: Arc<RwLock<GameSession>>,
     tx: broadcast::Sender<GameEvent>,
@@ -85,9 +344,13 @@ pub async fn start_game(
     {
         let mut s = session.write().await;
         s.status = SessionStatus::Active;
+        s.question_order = s.shuffle.question_order_in_rounds(&s.quiz.round_ranges());
     }
 
-    let total_questions = session.read().await.total_questions();
+    let (total_questions, total_rounds) = {
+        let s = session.read().await;
+        (s.total_questions(), s.quiz.rounds.len())
+    };
 
     let _ = tx.send(GameEvent::BroadcastAll(
         json!({
@@ -95,6 +358,7 @@ pub async fn start_game(
             "payload": {
                 "countdown_sec": 3,
                 "total_questions": total_questions,
+                "total_rounds": total_rounds,
             }
         })
         .to_string(),
@@ -121,25 +385,48 @@ pub(crate) async fn do_advance_question(
     tx: broadcast::Sender<GameEvent>,
     session_manager: SessionManager,
 ) {
+    let round_started = {
+        let s = session.read().await;
+        if s.last_player_standing() {
+            None
+        } else {
+            round_started_message(&s, (s.current_question + 1) as usize)
+        }
+    };
+    if let Some(message) = round_started {
+        let _ = tx.send(GameEvent::BroadcastAll(message));
+        sleep(Duration::from_secs(ROUND_INTRO_SEC)).await;
+    }
+
     let question_index = {
         let mut s = session.write().await;
         s.current_question += 1;
         s.correct_answer_count = 0;
         let idx = s.current_question as usize;
 
-        if idx >= s.quiz.questions.len() {
-            broadcast_game_finished(&s, &tx);
-            s.status = SessionStatus::Finished;
-            session_manager.remove_session(&s.join_code);
+        if idx >= s.quiz.questions.len() || s.last_player_standing() {
+            if start_tiebreak(&mut s, &tx) {
+                send_next_tiebreak_question(session.clone(), tx.clone(), session_manager.clone());
+            } else {
+                finish_game(&mut s, &tx, &session_manager);
+            }
             return;
         }
 
         s.question_started = Some(Instant::now());
-        let q = &s.quiz.questions[idx];
-        let options: Vec<String> = q.options.iter().map(|o| o.text.clone()).collect();
-
-        let scoring_rule_value = serde_json::to_value(&s.scoring_rule).unwrap_or_default();
-        let _ = tx.send(GameEvent::BroadcastAll(
+        let q = s.question_at(idx);
+        let media_url = q.media.as_ref().map(|m| asset_url(&s.join_code, m));
+        send_in_option_order(&s, idx, &tx, |order| {
+            let options: Vec<&str> = order.iter().map(|&o| q.options[o].text.as_str()).collect();
+            let option_media_urls: Vec<Option<String>> = order
+                .iter()
+                .map(|&o| {
+                    q.options[o]
+                        .media
+                        .as_ref()
+                        .map(|m| asset_url(&s.join_code, m))
+                })
+                .collect();
             json!({
                 "type": "question",
                 "payload": {
@@ -147,22 +434,27 @@ pub(crate) async fn do_advance_question(
                     "total_questions": s.quiz.questions.len(),
                     "text": q.text,
                     "options": options,
-                    "time_limit_sec": s.time_limit_sec,
-                    "scoring_rule": scoring_rule_value,
+                    "media_url": media_url,
+                    "option_media_urls": option_media_urls,
+                    "time_limit_sec": s.time_limit_at(idx),
+                    "scoring_rule": s.scoring_rule_at(idx).name(),
+                    "scoring_rule_display_name": s.scoring_rule_at(idx).display_name(),
+                    "poll": q.is_poll(),
+                    "round_index": s.round_at(idx),
+                    "weight": q.weight(),
                 }
             })
-            .to_string(),
-        ));
+        });
 
         idx
     };
 
-    // Start question timer using session's time_limit_sec
+    // Start question timer using the session's (or the round's) time limit
     let timer_session = session.clone();
     let timer_tx = tx.clone();
     let timer_sm = session_manager.clone();
     tokio::spawn(async move {
-        let time_limit = timer_session.read().await.time_limit_sec;
+        let time_limit = timer_session.read().await.time_limit_at(question_index);
         sleep(Duration::from_secs(time_limit)).await;
 
         let current = timer_session.read().await.current_question;
@@ -200,6 +492,18 @@ pub async fn handle_answer(
             None => return,
         };
 
+        if player.is_eliminated() {
+            let _ = tx.send(GameEvent::PlayerOnly {
+                player_id: player_id.to_string(),
+                message: json!({
+                    "type": "error",
+                    "payload": { "code": "eliminated", "message": "You have been eliminated and can only watch" }
+                })
+                .to_string(),
+            });
+            return;
+        }
+
         if player.has_answered(question_index) {
             let _ = tx.send(GameEvent::PlayerOnly {
                 player_id: player_id.to_string(),
@@ -214,66 +518,111 @@ pub async fn handle_answer(
 
         let pre_answer_streak = player.correct_streak;
 
-        let (correct, correct_index, time_taken_ms) = {
-            let question = &s.quiz.questions[question_index];
-            let correct = selected_index == question.correct_index;
+        // Players answer with the slot they saw; scoring and storage use the quiz's
+        // option index, and the reply points at the correct slot in the player's order.
+        let (is_poll, correct, correct_index, time_taken_ms, selected_index) = {
+            let order = s.option_order(player_id, question_index);
+            let selected_index = order.get(selected_index).copied().unwrap_or(selected_index);
+            let question = s.question_at(question_index);
+            let correct = !question.is_poll() && selected_index == question.correct_index;
+            let correct_slot = order
+                .iter()
+                .position(|&o| o == question.correct_index)
+                .unwrap_or(question.correct_index);
             let time_taken_ms = s
                 .question_started
                 .map(|started| started.elapsed().as_millis() as u64)
                 .unwrap_or(0);
-            (correct, question.correct_index, time_taken_ms)
+            (
+                question.is_poll(),
+                correct,
+                correct_slot,
+                time_taken_ms,
+                selected_index,
+            )
         };
 
-        let outcome = s.scoring_rule.score(&ScoringContext {
-            correct,
-            time_taken_ms,
-            time_limit_sec: s.time_limit_sec,
-            streak: pre_answer_streak,
-            correct_answer_count: s.correct_answer_count,
-        });
-        if outcome.position.is_some() {
-            s.correct_answer_count += 1;
-        }
-        let points = outcome.points;
-        let position_opt = outcome.position;
-        let streak_multiplier = outcome.streak_multiplier;
+        if is_poll {
+            // Polls bypass scoring entirely: no points, streak and correct_count untouched.
+            let player = s.players.get_mut(player_id).unwrap();
+            player.answers.push(Answer {
+                question_index,
+                selected_index,
+                time_taken_ms,
+                points_awarded: 0,
+            });
 
-        let player = s.players.get_mut(player_id).unwrap();
-        player.answers.push(Answer {
-            question_index,
-            selected_index,
-            time_taken_ms,
-            points_awarded: points,
-        });
-        if correct {
-            player.correct_count += 1;
-            player.correct_streak += 1;
+            let _ = tx.send(GameEvent::PlayerOnly {
+                player_id: player_id.to_string(),
+                message: json!({
+                    "type": "answer_result",
+                    "payload": {
+                        "poll": true,
+                        "correct": false,
+                        "points_awarded": 0,
+                        "correct_index": null,
+                        "streak_multiplier": 1.0,
+                        "position": null,
+                        "penalty": 0,
+                    }
+                })
+                .to_string(),
+            });
         } else {
-            player.correct_streak = 0;
-        }
-        player.score += points;
+            let outcome = s.scoring_rule_at(question_index).score(&ScoringContext {
+                correct,
+                time_taken_ms,
+                time_limit_sec: s.time_limit_at(question_index),
+                streak: pre_answer_streak,
+                correct_answer_count: s.correct_answer_count,
+                weight: s.question_at(question_index).weight(),
+            });
+            if outcome.position.is_some() {
+                s.correct_answer_count += 1;
+            }
+            let points = outcome.points;
+            let position_opt = outcome.position;
+            let streak_multiplier = outcome.streak_multiplier;
+
+            let player = s.players.get_mut(player_id).unwrap();
+            player.answers.push(Answer {
+                question_index,
+                selected_index,
+                time_taken_ms,
+                points_awarded: points,
+            });
+            if correct {
+                player.correct_count += 1;
+                player.correct_streak += 1;
+            } else {
+                player.correct_streak = 0;
+            }
+            // A penalty can take the score below zero.
+            player.score += points;
 
-        let _ = tx.send(GameEvent::PlayerOnly {
-            player_id: player_id.to_string(),
-            message: json!({
-                "type": "answer_result",
-                "payload": {
-                    "correct": correct,
-                    "points_awarded": points,
-                    "correct_index": correct_index,
-                    "streak_multiplier": streak_multiplier,
-                    "position": position_opt,
-                }
-            })
-            .to_string(),
-        });
+            let _ = tx.send(GameEvent::PlayerOnly {
+                player_id: player_id.to_string(),
+                message: json!({
+                    "type": "answer_result",
+                    "payload": {
+                        "correct": correct,
+                        "points_awarded": points,
+                        "correct_index": correct_index,
+                        "streak_multiplier": streak_multiplier,
+                        "position": position_opt,
+                        "penalty": outcome.penalty,
+                    }
+                })
+                .to_string(),
+            });
+        }
 
+        // Eliminated players only watch, so they are not waited for.
         let answered_count = s
-            .players
-            .values()
+            .players_in_game()
             .filter(|p| p.has_answered(question_index))
             .count();
-        let total_players = s.players.len();
+        let total_players = s.players_in_game().count();
 
         let _ = tx.send(GameEvent::HostOnly(
             json!({
@@ -300,50 +649,112 @@ pub(crate) async fn do_end_question(
     question_index: usize,
     session_manager: SessionManager,
 ) {
-    {
-        let s = session.read().await;
+    let leaderboard = {
+        let mut s = session.write().await;
 
-        if s.current_question as usize != question_index {
+        // A tiebreak keeps `current_question` past the last question, so only a
+        // session still in (or paused during) its questions has one to end.
+        if !matches!(s.status, SessionStatus::Active | SessionStatus::Paused)
+            || s.tiebreak.is_some()
+            || s.current_question as usize != question_index
+        {
             return;
         }
 
-        let question = &s.quiz.questions[question_index];
-        let correct_index = question.correct_index;
-        let correct_text = question.options[correct_index].text.clone();
+        let elimination = eliminate_players(&mut s, question_index);
+        let question = s.question_at(question_index);
 
         let player_refs: Vec<&Player> = s.players.values().collect();
-        let leaderboard = compute_leaderboard(&player_refs, false);
+        let leaderboard = s.leaderboard(false);
+        let movements: Vec<RankMovement> = leaderboard
+            .iter()
+            .map(|e| rank_movement(e, &s.previous_leaderboard))
+            .collect();
 
         let leaderboard_json: Vec<_> = leaderboard
             .iter()
-            .map(|e| {
+            .zip(&movements)
+            .map(|(e, movement)| {
                 json!({
                     "rank": e.rank,
                     "display_name": e.display_name,
                     "avatar": e.avatar,
                     "score": e.score,
                     "correct_count": e.correct_count,
+                    "points_gained": movement.points_gained,
+                    "previous_rank": movement.previous_rank,
+                    "rank_change": movement.rank_change,
+                    "eliminated": e.eliminated,
                 })
             })
             .collect();
 
-        let _ = tx.send(GameEvent::BroadcastAll(
+        let team_leaderboard = compute_team_leaderboard(&player_refs, &s.teams, false);
+        let votes = vote_counts(&s, question_index);
+        send_in_option_order(&s, question_index, &tx, |order| {
+            let payload = if question.is_poll() {
+                let votes: Vec<u32> = order.iter().map(|&o| votes[o]).collect();
+                json!({
+                    "poll": true,
+                    "correct_index": null,
+                    "correct_text": null,
+                    "votes": votes,
+                    "explanation": question.explanation,
+                    "leaderboard": leaderboard_json,
+                    "team_leaderboard": team_leaderboard,
+                })
+            } else {
+                let correct_index = question.correct_index;
+                json!({
+                    "correct_index": order.iter().position(|&o| o == correct_index),
+                    "correct_text": question.options[correct_index].text,
+                    "explanation": question.explanation,
+                    "leaderboard": leaderboard_json,
+                    "team_leaderboard": team_leaderboard,
+                })
+            };
             json!({
                 "type": "question_ended",
-                "payload": {
-                    "correct_index": correct_index,
-                    "correct_text": correct_text,
-                    "leaderboard": leaderboard_json,
-                }
+                "payload": payload,
             })
-            .to_string(),
-        ));
-    }
+        });
+        if let Some(message) = elimination {
+            let _ = tx.send(GameEvent::BroadcastAll(message));
+        }
+
+        if let Some(round) = s.round_at(question_index)
+            && s.quiz.round_ranges()[round].end == question_index + 1
+        {
+            let _ = tx.send(GameEvent::BroadcastAll(
+                json!({
+                    "type": "round_ended",
+                    "payload": {
+                        "round_index": round,
+                        "total_rounds": s.quiz.rounds.len(),
+                        "title": s.quiz.rounds[round].title,
+                        "leaderboard": round_leaderboard(&s, round),
+                    }
+                })
+                .to_string(),
+            ));
+        }
+
+        let _ = tx.send(GameEvent::HostOnly(answer_distribution_message(
+            &s,
+            question_index,
+        )));
+        send_standings(&s, &leaderboard, &movements, &tx);
+        leaderboard
+    };
 
-    // Reset streak for unanswered players when StreakBonus rule is active
+    // Reset streak for unanswered players when the rule scores streaks.
+    // Polls never affect streaks, so skipping one is not a break either.
     {
         let mut s = session.write().await;
-        if s.scoring_rule == ScoringRule::StreakBonus {
+        s.previous_leaderboard = leaderboard;
+        if s.scoring_rule_at(question_index).uses_streak()
+            && !s.question_at(question_index).is_poll()
+        {
             for player in s.players.values_mut() {
                 if !player.has_answered(question_index) {
                     player.correct_streak = 0;
@@ -357,54 +768,626 @@ pub(crate) async fn do_end_question(
     send_next_question(session, tx, session_manager);
 }
 
-fn broadcast_game_finished(session: &GameSession, tx: &broadcast::Sender<GameEvent>) {
-    let player_refs: Vec<&Player> = session.players.values().collect();
-    let leaderboard = compute_leaderboard(&player_refs, true);
-
-    let leaderboard_json: Vec<_> = leaderboard
-        .iter()
-        .map(|e| {
-            json!({
-                "rank": e.rank,
-                "display_name": e.display_name,
-                "avatar": e.avatar,
-                "score": e.score,
-                "correct_count": e.correct_count,
-                "is_winner": e.is_winner,
+/// Sends each player their own `your_standing`, whether or not clients show them in
+/// the leaderboard's top places.
+fn send_standings(
+    session: &GameSession,
+    leaderboard: &[LeaderboardEntry],
+    movements: &[RankMovement],
+    tx: &broadcast::Sender<GameEvent>,
+) {
+    for player in session.players.values() {
+        let Some((entry, movement)) = leaderboard
+            .iter()
+            .zip(movements)
+            .find(|(e, _)| e.display_name == player.display_name)
+        else {
+            continue;
+        };
+        let _ = tx.send(GameEvent::PlayerOnly {
+            player_id: player.id.clone(),
+            message: json!({
+                "type": "your_standing",
+                "payload": {
+                    "rank": entry.rank,
+                    "total_players": leaderboard.len(),
+                    "score": entry.score,
+                    "points_gained": movement.points_gained,
+                    "previous_rank": movement.previous_rank,
+                    "rank_change": movement.rank_change,
+                    "lives_left": session.elimination.enabled.then(|| lives_left(session, player)),
+                    "eliminated": player.is_eliminated(),
+                }
             })
-        })
+            .to_string(),
+        });
+    }
+}
+
+/// In an elimination game, takes a life from each player still in who missed the
+/// question at `position`, and eliminates those left without one. When that would
+/// eliminate everyone still in, nobody loses a life. Returns the `players_eliminated`
+/// message to broadcast, or `None` outside an elimination game and for polls.
+/// Calling it again for the same question changes nothing.
+fn eliminate_players(session: &mut GameSession, position: usize) -> Option<String> {
+    let settings = session.elimination;
+    let question = session.question_at(position);
+    if !settings.enabled || question.is_poll() {
+        return None;
+    }
+    let mut missed: Vec<String> = session
+        .players
+        .values()
+        .filter(|p| p.in_game_at(position) && !p.lives_lost_at.contains(&position))
+        .filter(|p| settings.costs_life(question, p.answer_to(position)))
+        .map(|p| p.id.clone())
         .collect();
+    let out_of_lives = |p: &Player| p.lives_lost_at.len() as u32 + 1 >= settings.lives;
+    let still_in = session.players_in_game().count();
+    let would_eliminate = missed
+        .iter()
+        .filter(|id| out_of_lives(&session.players[id.as_str()]))
+        .count();
+    if still_in > 0 && would_eliminate == still_in {
+        missed.clear();
+    }
 
-    let _ = tx.send(GameEvent::BroadcastAll(
+    let mut eliminated = Vec::new();
+    let mut lost_life = Vec::new();
+    missed.sort_by(|a, b| {
+        session.players[a.as_str()]
+            .display_name
+            .cmp(&session.players[b.as_str()].display_name)
+    });
+    for id in missed {
+        let Some(player) = session.players.get_mut(&id) else {
+            continue;
+        };
+        player.lives_lost_at.push(position);
+        if player.lives_lost_at.len() as u32 >= settings.lives {
+            player.eliminated_at = Some(position);
+            eliminated
+                .push(json!({ "display_name": player.display_name, "avatar": player.avatar }));
+        } else {
+            lost_life.push(json!({
+                "display_name": player.display_name,
+                "avatar": player.avatar,
+                "lives_left": settings.lives - player.lives_lost_at.len() as u32,
+            }));
+        }
+    }
+    Some(
         json!({
-            "type": "game_finished",
+            "type": "players_eliminated",
             "payload": {
-                "leaderboard": leaderboard_json,
-                "total_questions": session.quiz.questions.len(),
+                "question_index": position,
+                "eliminated": eliminated,
+                "lost_life": lost_life,
+                "remaining": session.players_in_game().count(),
             }
         })
         .to_string(),
-    ));
+    )
 }
 
-#[cfg(test)]
-mod tests {
-    use std::time::Duration;
-
-    use tokio::sync::broadcast;
-
-    use super::*;
-    use crate::config::AppConfig;
-    use crate::models::player::Player;
-    use crate::models::quiz::{Question, Quiz, QuizOption};
-    use crate::models::scoring_rule::ScoringRule;
-    use crate::models::session::{GameSession, SessionStatus};
-    use crate::services::session_manager::SessionManager;
+/// Lives `player` has left in an elimination game.
+fn lives_left(session: &GameSession, player: &Player) -> u32 {
+    session
+        .elimination
+        .lives
+        .saturating_sub(player.lives_lost_at.len() as u32)
+}
 
-    fn make_session_manager() -> SessionManager {
-        SessionManager::new(AppConfig::from_env())
+/// Sends a message about the question at `position` with its options in the order
+/// each recipient sees them: quiz order for the host, and for everyone when options
+/// are not shuffled; each player's own order otherwise. `build` receives the quiz
+/// option index for each slot.
+fn send_in_option_order(
+    session: &GameSession,
+    position: usize,
+    tx: &broadcast::Sender<GameEvent>,
+    build: impl Fn(&[usize]) -> serde_json::Value,
+) {
+    let quiz_order: Vec<usize> = (0..session.question_at(position).options.len()).collect();
+    if !session.shuffle.options {
+        let _ = tx.send(GameEvent::BroadcastAll(build(&quiz_order).to_string()));
+        return;
     }
-
+    let _ = tx.send(GameEvent::HostOnly(build(&quiz_order).to_string()));
+    for player_id in session.players.keys() {
+        let order = session.option_order(player_id, position);
+        let _ = tx.send(GameEvent::PlayerOnly {
+            player_id: player_id.clone(),
+            message: build(&order).to_string(),
+        });
+    }
+}
+
+/// The `round_started` message when `position` is the first question of a round.
+fn round_started_message(session: &GameSession, position: usize) -> Option<String> {
+    let round = session.round_at(position)?;
+    let range = session.quiz.round_ranges()[round].clone();
+    if range.start != position {
+        return None;
+    }
+    Some(
+        json!({
+            "type": "round_started",
+            "payload": {
+                "round_index": round,
+                "total_rounds": session.quiz.rounds.len(),
+                "title": session.quiz.rounds[round].title,
+                "question_count": range.len(),
+                "first_question_index": position,
+                "scoring_rule": session.scoring_rule_at(position).name(),
+                "scoring_rule_display_name": session.scoring_rule_at(position).display_name(),
+                "time_limit_sec": session.time_limit_at(position),
+                "countdown_sec": ROUND_INTRO_SEC,
+            }
+        })
+        .to_string(),
+    )
+}
+
+/// Standings counting only the questions of `round`.
+fn round_leaderboard(session: &GameSession, round: usize) -> Vec<LeaderboardEntry> {
+    let range = session.quiz.round_ranges()[round].clone();
+    let entries = session
+        .players
+        .values()
+        .map(|player| {
+            let (score, correct_count) = player
+                .answers
+                .iter()
+                .filter(|a| range.contains(&a.question_index))
+                .fold((0, 0), |(score, correct), answer| {
+                    let question = session.question_at(answer.question_index);
+                    let is_correct =
+                        !question.is_poll() && answer.selected_index == question.correct_index;
+                    (
+                        score + answer.points_awarded,
+                        correct + u32::from(is_correct),
+                    )
+                });
+            LeaderboardEntry {
+                rank: 0,
+                display_name: player.display_name.clone(),
+                avatar: player.avatar.clone(),
+                score,
+                correct_count,
+                is_winner: false,
+                eliminated: player.is_eliminated(),
+            }
+        })
+        .collect();
+    rank_leaderboard(entries, false)
+}
+
+/// Number of players who picked each option of the question at `question_index`,
+/// in quiz option order.
+fn vote_counts(session: &GameSession, question_index: usize) -> Vec<u32> {
+    let mut votes = vec![0u32; session.question_at(question_index).options.len()];
+    for answer in session
+        .players
+        .values()
+        .flat_map(|p| p.answers.iter())
+        .filter(|a| a.question_index == question_index)
+    {
+        if let Some(count) = votes.get_mut(answer.selected_index) {
+            *count += 1;
+        }
+    }
+    votes
+}
+
+/// The host's breakdown of how players answered the question at `question_index`:
+/// picks per option in quiz order, the share of all players who answered correctly,
+/// response times of those who answered, and how many did not answer.
+fn answer_distribution_message(session: &GameSession, question_index: usize) -> String {
+    let question = session.question_at(question_index);
+    let in_game: Vec<&Player> = session
+        .players
+        .values()
+        .filter(|p| p.in_game_at(question_index))
+        .collect();
+    let answers: Vec<&Answer> = in_game
+        .iter()
+        .filter_map(|p| p.answer_to(question_index))
+        .collect();
+    let mut times: Vec<u64> = answers.iter().map(|a| a.time_taken_ms).collect();
+    times.sort_unstable();
+    let total = in_game.len();
+    let correct_count = (!question.is_poll()).then(|| {
+        answers
+            .iter()
+            .filter(|a| a.selected_index == question.correct_index)
+            .count()
+    });
+    // Percentages to one decimal place
+    let correct_percentage = correct_count
+        .filter(|_| total > 0)
+        .map(|correct| (correct * 1000 / total) as f64 / 10.0);
+    let average_time_ms =
+        (!times.is_empty()).then(|| times.iter().sum::<u64>() / times.len() as u64);
+
+    json!({
+        "type": "answer_distribution",
+        "payload": {
+            "question_index": question_index,
+            "poll": question.is_poll(),
+            "picks": vote_counts(session, question_index),
+            "correct_index": (!question.is_poll()).then_some(question.correct_index),
+            "correct_count": correct_count,
+            "correct_percentage": correct_percentage,
+            "answered": answers.len(),
+            "no_answer": total - answers.len(),
+            "average_time_ms": average_time_ms,
+            "median_time_ms": median(&times),
+        }
+    })
+    .to_string()
+}
+
+/// Median of sorted values; the mean of the middle two for an even count.
+fn median(sorted: &[u64]) -> Option<u64> {
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid]),
+        _ => Some((sorted[mid - 1] + sorted[mid]) / 2),
+    }
+}
+
+fn broadcast_game_finished(session: &GameSession, tx: &broadcast::Sender<GameEvent>) {
+    let player_refs: Vec<&Player> = session.players.values().collect();
+    let leaderboard = session.leaderboard(true);
+
+    let leaderboard_json: Vec<_> = leaderboard
+        .iter()
+        .map(|e| {
+            json!({
+                "rank": e.rank,
+                "display_name": e.display_name,
+                "avatar": e.avatar,
+                "score": e.score,
+                "correct_count": e.correct_count,
+                "is_winner": e.is_winner,
+                "eliminated": e.eliminated,
+            })
+        })
+        .collect();
+
+    let _ = tx.send(GameEvent::BroadcastAll(
+        json!({
+            "type": "game_finished",
+            "payload": {
+                "leaderboard": leaderboard_json,
+                "team_leaderboard": compute_team_leaderboard(&player_refs, &session.teams, true),
+                "team_aggregation": session.teams.aggregation,
+                "total_questions": session.quiz.questions.len(),
+                "shuffle": session.shuffle,
+                "draw": session.draw,
+                "tie_breaker": session.tie_breaker,
+                "elimination": session.elimination,
+            }
+        })
+        .to_string(),
+    ));
+}
+
+fn finish_game(
+    session: &mut GameSession,
+    tx: &broadcast::Sender<GameEvent>,
+    session_manager: &SessionManager,
+) {
+    broadcast_game_finished(session, tx);
+    session.status = SessionStatus::Finished;
+    session.tiebreak = None;
+    session_manager.remove_session(&session.join_code);
+}
+
+/// Moves the players tied for first into a sudden-death tiebreak, when the session
+/// breaks ties that way and the quiz holds tiebreaker questions. Returns whether
+/// the tiebreak started.
+fn start_tiebreak(session: &mut GameSession, tx: &broadcast::Sender<GameEvent>) -> bool {
+    if session.tie_breaker != TieBreaker::SuddenDeath || session.quiz.tiebreakers.is_empty() {
+        return false;
+    }
+    let Some(top) = session.players_in_game().map(|p| p.score).max() else {
+        return false;
+    };
+    let mut contenders: Vec<&Player> = session
+        .players_in_game()
+        .filter(|p| p.score == top)
+        .collect();
+    if contenders.len() < 2 {
+        return false;
+    }
+    contenders.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+    let contenders: Vec<String> = contenders.into_iter().map(|p| p.id.clone()).collect();
+
+    // A game paused for a host reconnect stays paused; the reconnect resumes it
+    // into the tiebreak.
+    if session.status != SessionStatus::Paused {
+        session.status = SessionStatus::Tiebreak;
+    }
+    session.tiebreak = Some(Tiebreak {
+        contenders,
+        ..Default::default()
+    });
+    let contenders = tiebreak_contenders(session);
+    send_to_contenders(session, tx, |contender| {
+        json!({
+            "type": "tiebreak_started",
+            "payload": {
+                "contenders": contenders,
+                "score": top,
+                "total_tiebreakers": session.quiz.tiebreakers.len(),
+                "contender": contender,
+            }
+        })
+    });
+    true
+}
+
+/// Spawns a task to ask the next tiebreaker question, or to end the game once the
+/// tie is broken or the questions run out.
+fn send_next_tiebreak_question(
+    session: Arc<RwLock<GameSession>>,
+    tx: broadcast::Sender<GameEvent>,
+    session_manager: SessionManager,
+) {
+    tokio::spawn(async move {
+        do_tiebreak_question(session, tx, session_manager).await;
+    });
+}
+
+pub(crate) async fn do_tiebreak_question(
+    session: Arc<RwLock<GameSession>>,
+    tx: broadcast::Sender<GameEvent>,
+    session_manager: SessionManager,
+) {
+    let (tiebreak_index, time_limit) = {
+        let mut s = session.write().await;
+        if !matches!(s.status, SessionStatus::Tiebreak | SessionStatus::Paused) {
+            return;
+        }
+        let total = s.quiz.tiebreakers.len();
+        let Some(tiebreak) = s.tiebreak.as_mut() else {
+            return;
+        };
+        let idx = tiebreak.question.map_or(0, |i| i + 1);
+        if tiebreak.contenders.len() < 2 || idx >= total {
+            finish_game(&mut s, &tx, &session_manager);
+            return;
+        }
+        tiebreak.question = Some(idx);
+        tiebreak.open = true;
+        tiebreak.answers.clear();
+
+        let q = &s.quiz.tiebreakers[idx];
+        let media_url = q.media.as_ref().map(|m| asset_url(&s.join_code, m));
+        let option_media_urls: Vec<Option<String>> = q
+            .options
+            .iter()
+            .map(|o| o.media.as_ref().map(|m| asset_url(&s.join_code, m)))
+            .collect();
+        let options: Vec<&str> = q.options.iter().map(|o| o.text.as_str()).collect();
+        let contenders = tiebreak_contenders(&s);
+        send_to_contenders(&s, &tx, |contender| {
+            json!({
+                "type": "tiebreak_question",
+                "payload": {
+                    "tiebreak_index": idx,
+                    "total_tiebreakers": total,
+                    "text": q.text,
+                    "options": options,
+                    "media_url": media_url,
+                    "option_media_urls": option_media_urls,
+                    "time_limit_sec": s.time_limit_sec,
+                    "contenders": contenders,
+                    "contender": contender,
+                }
+            })
+        });
+        (idx, s.time_limit_sec)
+    };
+
+    let timer_session = session.clone();
+    let timer_tx = tx.clone();
+    tokio::spawn(async move {
+        sleep(Duration::from_secs(time_limit)).await;
+        end_tiebreak_question(timer_session, timer_tx, tiebreak_index, session_manager).await;
+    });
+}
+
+pub async fn handle_tiebreak_answer(
+    session: &Arc<RwLock<GameSession>>,
+    tx: &broadcast::Sender<GameEvent>,
+    player_id: &str,
+    tiebreak_index: usize,
+    selected_index: usize,
+    session_manager: SessionManager,
+) {
+    let all_answered = {
+        let mut s = session.write().await;
+        let correct_index = s
+            .quiz
+            .tiebreakers
+            .get(tiebreak_index)
+            .map(|q| q.correct_index);
+        let error = |code: &str, message: &str| GameEvent::PlayerOnly {
+            player_id: player_id.to_string(),
+            message: json!({
+                "type": "error",
+                "payload": { "code": code, "message": message }
+            })
+            .to_string(),
+        };
+        let Some(tiebreak) = s
+            .tiebreak
+            .as_mut()
+            .filter(|t| t.open && t.question == Some(tiebreak_index))
+        else {
+            let _ = tx.send(error(
+                "wrong_question",
+                "Not the current tiebreaker question",
+            ));
+            return;
+        };
+        if !tiebreak.contenders.iter().any(|id| id == player_id) {
+            let _ = tx.send(error(
+                "not_a_contender",
+                "Only players tied for first answer tiebreaker questions",
+            ));
+            return;
+        }
+        if tiebreak.answers.contains_key(player_id) {
+            let _ = tx.send(error(
+                "already_answered",
+                "You have already submitted an answer for this question",
+            ));
+            return;
+        }
+        tiebreak
+            .answers
+            .insert(player_id.to_string(), correct_index == Some(selected_index));
+
+        let _ = tx.send(GameEvent::HostOnly(
+            json!({
+                "type": "answer_count",
+                "payload": {
+                    "answered": tiebreak.answers.len(),
+                    "total": tiebreak.contenders.len(),
+                }
+            })
+            .to_string(),
+        ));
+        tiebreak.answers.len() == tiebreak.contenders.len()
+    };
+
+    if all_answered {
+        end_tiebreak_question(session.clone(), tx.clone(), tiebreak_index, session_manager).await;
+    }
+}
+
+/// Closes a tiebreaker question. Contenders who answered correctly stay in; when
+/// nobody did, all of them do.
+pub(crate) async fn end_tiebreak_question(
+    session: Arc<RwLock<GameSession>>,
+    tx: broadcast::Sender<GameEvent>,
+    tiebreak_index: usize,
+    session_manager: SessionManager,
+) {
+    {
+        let mut s = session.write().await;
+        let Some(tiebreak) = s
+            .tiebreak
+            .as_mut()
+            .filter(|t| t.open && t.question == Some(tiebreak_index))
+        else {
+            return;
+        };
+        tiebreak.open = false;
+        let (survivors, eliminated): (Vec<String>, Vec<String>) = tiebreak
+            .contenders
+            .iter()
+            .cloned()
+            .partition(|id| tiebreak.answers.get(id) == Some(&true));
+        let (survivors, eliminated) = if survivors.is_empty() {
+            (eliminated, Vec::new())
+        } else {
+            (survivors, eliminated)
+        };
+        tiebreak.contenders = survivors;
+        let survivors = tiebreak.contenders.clone();
+        for id in &survivors {
+            if let Some(player) = s.players.get_mut(id) {
+                player.tiebreak_survived += 1;
+            }
+        }
+
+        let question = &s.quiz.tiebreakers[tiebreak_index];
+        let _ = tx.send(GameEvent::BroadcastAll(
+            json!({
+                "type": "tiebreak_result",
+                "payload": {
+                    "tiebreak_index": tiebreak_index,
+                    "correct_index": question.correct_index,
+                    "correct_text": question.options[question.correct_index].text,
+                    "explanation": question.explanation,
+                    "survivors": contender_entries(&s, &survivors),
+                    "eliminated": contender_entries(&s, &eliminated),
+                }
+            })
+            .to_string(),
+        ));
+    }
+
+    sleep(Duration::from_millis(500)).await;
+
+    send_next_tiebreak_question(session, tx, session_manager);
+}
+
+/// The players still in the tiebreak, for messages.
+fn tiebreak_contenders(session: &GameSession) -> Vec<serde_json::Value> {
+    session
+        .tiebreak
+        .as_ref()
+        .map(|t| contender_entries(session, &t.contenders))
+        .unwrap_or_default()
+}
+
+fn contender_entries(session: &GameSession, player_ids: &[String]) -> Vec<serde_json::Value> {
+    player_ids
+        .iter()
+        .filter_map(|id| session.players.get(id))
+        .map(|p| json!({ "display_name": p.display_name, "avatar": p.avatar }))
+        .collect()
+}
+
+/// Sends `build(false)` to the host and each player `build(is_contender)`, so
+/// players know whether to answer or watch.
+fn send_to_contenders(
+    session: &GameSession,
+    tx: &broadcast::Sender<GameEvent>,
+    build: impl Fn(bool) -> serde_json::Value,
+) {
+    let contenders = session
+        .tiebreak
+        .as_ref()
+        .map(|t| t.contenders.as_slice())
+        .unwrap_or_default();
+    let _ = tx.send(GameEvent::HostOnly(build(false).to_string()));
+    for player_id in session.players.keys() {
+        let _ = tx.send(GameEvent::PlayerOnly {
+            player_id: player_id.clone(),
+            message: build(contenders.contains(player_id)).to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::models::leaderboard::compute_leaderboard_with;
+    use crate::models::player::Player;
+    use crate::models::quiz::{Question, QuestionKind, Quiz, QuizOption, Round};
+    use crate::models::scoring_rule::{
+        PositionRaceParams, RuleParams, ScoringModifier, ScoringRule, WrongAnswerPenaltyModifier,
+    };
+    use crate::models::session::{GameSession, SessionStatus};
+    use crate::services::session_manager::SessionManager;
+
+    fn make_session_manager() -> SessionManager {
+        SessionManager::new(AppConfig::from_env())
+    }
+
     fn make_quiz(q_time_limit_sec: u64) -> Quiz {
         Quiz {
             title: "Test".to_string(),
@@ -413,14 +1396,18 @@ mod tests {
                 options: vec![
                     QuizOption {
                         text: "1".to_string(),
+                        ..Default::default()
                     },
                     QuizOption {
                         text: "2".to_string(),
+                        ..Default::default()
                     },
                 ],
                 correct_index: 1,
                 time_limit_sec: q_time_limit_sec,
+                ..Default::default()
             }],
+            ..Default::default()
         }
     }
 
@@ -469,6 +1456,37 @@ mod tests {
         );
     }
 
+    // ── Media attachments ────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn question_broadcast_includes_session_scoped_media_urls() {
+        let mut quiz = make_quiz(20);
+        quiz.questions[0].media = Some("images/sum.png".to_string());
+        quiz.questions[0].options[1].media = Some("audio/two.mp3".to_string());
+        let session = Arc::new(RwLock::new(GameSession::new(
+            "TSTCDE".to_string(),
+            quiz,
+            20,
+        )));
+        session.write().await.status = SessionStatus::Active;
+
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+        do_advance_question(session.clone(), tx.clone(), make_session_manager()).await;
+
+        let Ok(GameEvent::BroadcastAll(msg)) = rx.try_recv() else {
+            panic!("expected question broadcast");
+        };
+        let parsed: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(
+            parsed["payload"]["media_url"],
+            "/api/sessions/TSTCDE/assets/images/sum.png"
+        );
+        assert_eq!(
+            parsed["payload"]["option_media_urls"],
+            json!([null, "/api/sessions/TSTCDE/assets/audio/two.mp3"])
+        );
+    }
+
     // ── T003: scoring uses session.time_limit_sec ────────────────────────────
 
     #[tokio::test]
@@ -611,33 +1629,158 @@ mod tests {
         assert!(rx.try_recv().is_err(), "should not broadcast when active");
     }
 
-    // ── T008: handle_answer streak scoring ───────────────────────────────────
+    // ── Tie-breakers ─────────────────────────────────────────────────────────
 
-    fn make_session_with_rule(rule: ScoringRule, time_limit: u64) -> Arc<RwLock<GameSession>> {
-        let quiz = make_quiz(time_limit);
-        let mut session_data = GameSession::new("TSTCDE".to_string(), quiz, time_limit);
-        session_data.scoring_rule = rule;
-        Arc::new(RwLock::new(session_data))
-    }
+    #[test]
+    fn set_tie_breaker_updates_session_and_broadcasts() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(4);
 
-    #[tokio::test]
-    async fn streak_bonus_first_correct_answer_awards_1000_pts() {
-        let session = make_session_with_rule(ScoringRule::StreakBonus, 20);
-        let player_id = "player-1";
-        {
-            let mut s = session.write().await;
-            s.status = SessionStatus::Active;
-            s.current_question = 0;
-            s.players.insert(
-                player_id.to_string(),
-                Player::new(player_id.to_string(), "Alice".to_string(), "🙂".to_string()),
-            );
-            // Add a second player so do_end_question is not triggered
-            s.players.insert(
-                "player-2".to_string(),
-                Player::new("player-2".to_string(), "Bob".to_string(), "🙂".to_string()),
-            );
-        }
+        handle_set_tie_breaker(&mut session, &json!({"tie_breaker": "answer_time"}), &tx);
+
+        assert_eq!(session.tie_breaker, TieBreaker::AnswerTime);
+        let Ok(GameEvent::BroadcastAll(msg)) = rx.try_recv() else {
+            panic!("expected BroadcastAll");
+        };
+        assert_eq!(payload_of(&msg)["tie_breaker"], "answer_time");
+    }
+
+    #[test]
+    fn set_tie_breaker_rejects_unknown_names() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(4);
+
+        handle_set_tie_breaker(&mut session, &json!({"tie_breaker": "coin_toss"}), &tx);
+
+        assert_eq!(session.tie_breaker, TieBreaker::None);
+        let Ok(GameEvent::HostOnly(msg)) = rx.try_recv() else {
+            panic!("expected HostOnly error");
+        };
+        let parsed: serde_json::Value = serde_json::from_str(&msg).unwrap();
+        assert_eq!(parsed["payload"]["code"], "invalid_tie_breaker");
+    }
+
+    #[test]
+    fn game_finished_breaks_ties_and_reports_the_tie_breaker() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        session.tie_breaker = TieBreaker::AnswerTime;
+        session.current_question = 0;
+        for (id, time_taken_ms) in [("slow", 9000), ("fast", 2000)] {
+            let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+            player.score = 1000;
+            player.answers.push(Answer {
+                question_index: 0,
+                selected_index: 1,
+                time_taken_ms,
+                points_awarded: 1000,
+            });
+            session.players.insert(id.to_string(), player);
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(4);
+
+        broadcast_game_finished(&session, &tx);
+
+        let Ok(GameEvent::BroadcastAll(msg)) = rx.try_recv() else {
+            panic!("expected BroadcastAll");
+        };
+        let payload = payload_of(&msg);
+        assert_eq!(payload["tie_breaker"], "answer_time");
+        let podium: Vec<_> = payload["leaderboard"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| {
+                (
+                    e["display_name"].as_str().unwrap(),
+                    e["rank"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(podium, [("fast", 1), ("slow", 2)]);
+    }
+
+    // ── set_scoring_rule parameters ─────────────────────────────────────────
+
+    #[test]
+    fn set_scoring_rule_accepts_parameters() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(4);
+
+        handle_set_scoring_rule(
+            &mut session,
+            &ScorerRegistry::default(),
+            &json!({"rule": "position_race", "schedule": [500, 300]}),
+            &tx,
+        );
+
+        assert_eq!(
+            session.scoring_rule,
+            ScoringRule::with_params(RuleParams::PositionRace(PositionRaceParams {
+                schedule: vec![500, 300],
+            }))
+        );
+        let Ok(GameEvent::BroadcastAll(msg)) = rx.try_recv() else {
+            panic!("expected BroadcastAll");
+        };
+        let payload = payload_of(&msg);
+        assert_eq!(payload["rule"], "position_race");
+        assert_eq!(payload["schedule"], json!([500, 300]));
+        assert_eq!(payload["display_name"], "Position Race");
+    }
+
+    #[test]
+    fn set_scoring_rule_rejects_invalid_parameters() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(4);
+
+        for payload in [
+            json!({"rule": "linear_decay", "max_points": 0}),
+            json!({"rule": "linear_decay", "step_sec": 3}),
+            json!({"rule": "fastest_finger"}),
+        ] {
+            handle_set_scoring_rule(&mut session, &ScorerRegistry::default(), &payload, &tx);
+            let Ok(GameEvent::HostOnly(msg)) = rx.try_recv() else {
+                panic!("expected HostOnly error for {payload}");
+            };
+            let parsed: serde_json::Value = serde_json::from_str(&msg).unwrap();
+            assert_eq!(parsed["type"], "error");
+            assert_eq!(parsed["payload"]["code"], "invalid_scoring_rule");
+        }
+        assert_eq!(session.scoring_rule, ScoringRule::default());
+    }
+
+    // ── T008: handle_answer streak scoring ───────────────────────────────────
+
+    fn make_session_with_rule(rule: ScoringRule, time_limit: u64) -> Arc<RwLock<GameSession>> {
+        let quiz = make_quiz(time_limit);
+        let mut session_data = GameSession::new("TSTCDE".to_string(), quiz, time_limit);
+        session_data.scoring_rule = rule;
+        Arc::new(RwLock::new(session_data))
+    }
+
+    #[tokio::test]
+    async fn streak_bonus_first_correct_answer_awards_1000_pts() {
+        let session = make_session_with_rule(ScoringRule::StreakBonus, 20);
+        let player_id = "player-1";
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            s.players.insert(
+                player_id.to_string(),
+                Player::new(player_id.to_string(), "Alice".to_string(), "🙂".to_string()),
+            );
+            // Add a second player so do_end_question is not triggered
+            s.players.insert(
+                "player-2".to_string(),
+                Player::new("player-2".to_string(), "Bob".to_string(), "🙂".to_string()),
+            );
+        }
 
         let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
         handle_answer(&session, &tx, player_id, 0, 1, make_session_manager()).await; // correct index is 1
@@ -1018,6 +2161,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::single_match, clippy::collapsible_if)]
     async fn position_race_second_correct_answer_awards_750_pts() {
         let session = make_session_with_rule(ScoringRule::PositionRace, 20);
         let player1_id = "player-1";
@@ -1131,9 +2275,148 @@ mod tests {
         );
     }
 
+    // ── Poll questions ───────────────────────────────────────────────────────
+
+    fn make_poll_session(rule: ScoringRule) -> Arc<RwLock<GameSession>> {
+        let mut quiz = make_quiz(20);
+        quiz.questions[0].kind = QuestionKind::Poll;
+        quiz.questions[0].correct_index = 0;
+        let mut session_data = GameSession::new("TSTCDE".to_string(), quiz, 20);
+        session_data.scoring_rule = rule;
+        Arc::new(RwLock::new(session_data))
+    }
+
+    #[tokio::test]
+    async fn poll_answer_awards_no_points_and_keeps_streak() {
+        let session = make_poll_session(ScoringRule::StreakBonus);
+        let player_id = "player-1";
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            let mut player =
+                Player::new(player_id.to_string(), "Alice".to_string(), "🙂".to_string());
+            player.correct_streak = 2;
+            s.players.insert(player_id.to_string(), player);
+            s.players.insert(
+                "player-2".to_string(),
+                Player::new("player-2".to_string(), "Bob".to_string(), "🙂".to_string()),
+            );
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+        handle_answer(&session, &tx, player_id, 0, 0, make_session_manager()).await;
+
+        let mut result = None;
+        while let Ok(event) = rx.try_recv() {
+            if let GameEvent::PlayerOnly { message, .. } = event {
+                let parsed: serde_json::Value = serde_json::from_str(&message).unwrap();
+                if parsed["type"] == "answer_result" {
+                    result = Some(parsed);
+                }
+            }
+        }
+
+        let result = result.expect("no answer_result received");
+        assert_eq!(result["payload"]["poll"], true);
+        assert_eq!(result["payload"]["points_awarded"], 0);
+        assert!(result["payload"]["correct_index"].is_null());
+
+        let s = session.read().await;
+        let player = &s.players[player_id];
+        assert_eq!(player.score, 0);
+        assert_eq!(player.correct_count, 0, "polls never count as correct");
+        assert_eq!(
+            player.correct_streak, 2,
+            "polls must leave the streak untouched"
+        );
+        assert!(player.has_answered(0));
+    }
+
+    #[tokio::test]
+    async fn poll_question_ended_carries_vote_distribution() {
+        let session = make_poll_session(ScoringRule::StreakBonus);
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            for (id, name, choice) in [("p1", "Alice", 1), ("p2", "Bob", 1), ("p3", "Cara", 0)] {
+                let mut player = Player::new(id.to_string(), name.to_string(), "🙂".to_string());
+                player.answers.push(Answer {
+                    question_index: 0,
+                    selected_index: choice,
+                    time_taken_ms: 1000,
+                    points_awarded: 0,
+                });
+                s.players.insert(id.to_string(), player);
+            }
+            let mut idle = Player::new("p4".to_string(), "Dan".to_string(), "🙂".to_string());
+            idle.correct_streak = 3;
+            s.players.insert("p4".to_string(), idle);
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        let mut ended = None;
+        while let Ok(event) = rx.try_recv() {
+            if let GameEvent::BroadcastAll(m) = event {
+                let parsed: serde_json::Value = serde_json::from_str(&m).unwrap();
+                if parsed["type"] == "question_ended" {
+                    ended = Some(parsed);
+                }
+            }
+        }
+
+        let ended = ended.expect("no question_ended received");
+        assert_eq!(ended["payload"]["poll"], true);
+        assert_eq!(ended["payload"]["votes"], json!([1, 2]));
+        assert!(ended["payload"]["correct_index"].is_null());
+        assert!(ended["payload"]["correct_text"].is_null());
+
+        let s = session.read().await;
+        assert_eq!(
+            s.players["p4"].correct_streak, 3,
+            "skipping a poll must not reset the streak"
+        );
+    }
+
+    // ── Explanations ─────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn question_ended_includes_explanation() {
+        let mut quiz = make_quiz(20);
+        quiz.questions[0].explanation = Some("Because 1+1=2.".to_string());
+        let session = Arc::new(RwLock::new(GameSession::new(
+            "TSTCDE".to_string(),
+            quiz,
+            20,
+        )));
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        let mut ended = None;
+        while let Ok(event) = rx.try_recv() {
+            if let GameEvent::BroadcastAll(m) = event {
+                let parsed: serde_json::Value = serde_json::from_str(&m).unwrap();
+                if parsed["type"] == "question_ended" {
+                    ended = Some(parsed);
+                }
+            }
+        }
+
+        let ended = ended.expect("no question_ended received");
+        assert_eq!(ended["payload"]["correct_text"], "2");
+        assert_eq!(ended["payload"]["explanation"], "Because 1+1=2.");
+    }
+
     // ── T017: do_end_question idempotency ────────────────────────────────────
 
     #[tokio::test]
+    #[allow(clippy::while_let_loop)]
     async fn end_question_is_idempotent() {
         let session = make_session(20, 20);
         {
@@ -1189,4 +2472,1124 @@ mod tests {
         );
         assert_eq!(extra_ended, 0, "second call should be a no-op");
     }
+
+    // ── Question weight ──────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn weighted_question_is_announced_and_scored() {
+        let session = make_session_with_rule(ScoringRule::FixedScore, 20);
+        {
+            let mut s = session.write().await;
+            s.quiz.questions[0].weight = Some(2.0);
+            s.status = SessionStatus::Active;
+            s.current_question = -1;
+            for (id, name) in [("player-1", "Alice"), ("player-2", "Bob")] {
+                s.players.insert(
+                    id.to_string(),
+                    Player::new(id.to_string(), name.to_string(), "🙂".to_string()),
+                );
+            }
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_advance_question(session.clone(), tx.clone(), make_session_manager()).await;
+        let Some(GameEvent::BroadcastAll(message)) = drain(&mut rx).into_iter().next() else {
+            panic!("expected question broadcast");
+        };
+        assert_eq!(payload_of(&message)["weight"], 2.0);
+
+        handle_answer(&session, &tx, "player-1", 0, 1, make_session_manager()).await;
+        let result = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::PlayerOnly { message, .. } => Some(payload_of(&message)),
+                _ => None,
+            })
+            .expect("answer_result");
+        assert_eq!(result["points_awarded"], 2000);
+        assert_eq!(session.read().await.players["player-1"].score, 2000);
+    }
+
+    // ── Composite rules ──────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn do_end_question_resets_streak_for_composite_with_streak_modifier() {
+        let rule = ScoringRule::composite(
+            ScoringRule::LinearDecay,
+            vec![ScoringModifier::Streak(Default::default())],
+        );
+        let session = make_session_with_rule(rule, 20);
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            let mut player = Player::new(
+                "player-1".to_string(),
+                "Alice".to_string(),
+                "🙂".to_string(),
+            );
+            player.correct_streak = 2;
+            s.players.insert("player-1".to_string(), player);
+        }
+
+        let (tx, _rx) = broadcast::channel::<GameEvent>(16);
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        assert_eq!(session.read().await.players["player-1"].correct_streak, 0);
+    }
+
+    #[tokio::test]
+    async fn wrong_answer_penalty_can_take_the_score_below_zero() {
+        let rule = ScoringRule::composite(
+            ScoringRule::FixedScore,
+            vec![ScoringModifier::WrongAnswerPenalty(
+                WrongAnswerPenaltyModifier { points: 250 },
+            )],
+        );
+        let session = make_session_with_rule(rule, 20);
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            for (id, score) in [("player-1", 1000), ("player-2", 100), ("player-3", 0)] {
+                let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+                player.score = score;
+                s.players.insert(id.to_string(), player);
+            }
+        }
+
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+        // Correct is index 1; player-3 stays unanswered so the question stays open
+        handle_answer(&session, &tx, "player-1", 0, 0, make_session_manager()).await;
+        handle_answer(&session, &tx, "player-2", 0, 0, make_session_manager()).await;
+
+        let result = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::PlayerOnly { player_id, message } if player_id == "player-2" => {
+                    Some(payload_of(&message))
+                }
+                _ => None,
+            })
+            .expect("answer_result");
+        assert_eq!(result["points_awarded"], -250);
+        assert_eq!(result["penalty"], 250);
+
+        let s = session.read().await;
+        assert_eq!(s.players["player-1"].score, 750);
+        assert_eq!(s.players["player-2"].score, -150);
+        assert_eq!(s.players["player-2"].answers[0].points_awarded, -250);
+        // Leaving a question unanswered costs nothing
+        assert_eq!(s.players["player-3"].score, 0);
+    }
+
+    // ── Shuffling ────────────────────────────────────────────────────────────
+
+    fn make_shuffled_session(questions: bool, options: bool) -> Arc<RwLock<GameSession>> {
+        let question = |text: &str| Question {
+            text: text.to_string(),
+            options: ["A", "B", "C", "D"]
+                .iter()
+                .map(|t| QuizOption {
+                    text: t.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            correct_index: 2,
+            time_limit_sec: 20,
+            ..Default::default()
+        };
+        let quiz = Quiz {
+            title: "Shuffled".to_string(),
+            questions: vec![question("Q1"), question("Q2"), question("Q3")],
+            ..Default::default()
+        };
+        let mut session = GameSession::new("TSTCDE".to_string(), quiz, 20);
+        session.shuffle = ShuffleSettings {
+            questions,
+            options,
+            seed: 42,
+        };
+        session.players.insert(
+            "player-1".to_string(),
+            Player::new(
+                "player-1".to_string(),
+                "Alice".to_string(),
+                "🙂".to_string(),
+            ),
+        );
+        Arc::new(RwLock::new(session))
+    }
+
+    fn drain(rx: &mut broadcast::Receiver<GameEvent>) -> Vec<GameEvent> {
+        std::iter::from_fn(|| rx.try_recv().ok()).collect()
+    }
+
+    fn payload_of(message: &str) -> serde_json::Value {
+        serde_json::from_str::<serde_json::Value>(message).unwrap()["payload"].clone()
+    }
+
+    #[tokio::test]
+    async fn shuffled_options_are_sent_per_player() {
+        let session = make_shuffled_session(false, true);
+        session.write().await.status = SessionStatus::Active;
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_advance_question(session.clone(), tx.clone(), make_session_manager()).await;
+
+        let order = session.read().await.option_order("player-1", 0);
+        let expected: Vec<&str> = order.iter().map(|&o| ["A", "B", "C", "D"][o]).collect();
+        let events = drain(&mut rx);
+        assert_eq!(events.len(), 2, "{events:?}");
+        let GameEvent::HostOnly(host) = &events[0] else {
+            panic!("expected host question, got {:?}", events[0]);
+        };
+        assert_eq!(payload_of(host)["options"], json!(["A", "B", "C", "D"]));
+        let GameEvent::PlayerOnly { player_id, message } = &events[1] else {
+            panic!("expected player question, got {:?}", events[1]);
+        };
+        assert_eq!(player_id, "player-1");
+        assert_eq!(payload_of(message)["options"], json!(expected));
+    }
+
+    #[tokio::test]
+    async fn answer_in_shuffled_slot_maps_to_quiz_option() {
+        let session = make_shuffled_session(false, true);
+        let correct_slot = {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            s.question_started = Some(Instant::now());
+            s.option_order("player-1", 0)
+                .iter()
+                .position(|&o| o == 2)
+                .unwrap()
+        };
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        handle_answer(
+            &session,
+            &tx,
+            "player-1",
+            0,
+            correct_slot,
+            make_session_manager(),
+        )
+        .await;
+
+        let result = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::PlayerOnly { message, .. } => Some(payload_of(&message)),
+                _ => None,
+            })
+            .expect("answer_result");
+        assert_eq!(result["correct"], true);
+        assert_eq!(result["correct_index"], correct_slot);
+        let s = session.read().await;
+        assert_eq!(s.players["player-1"].answers[0].selected_index, 2);
+    }
+
+    #[tokio::test]
+    async fn shuffled_question_order_is_fixed_at_start() {
+        let session = make_shuffled_session(true, false);
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.question_order = s.shuffle.question_order(3);
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_advance_question(session.clone(), tx.clone(), make_session_manager()).await;
+
+        let first = session.read().await.question_order[0];
+        let Some(GameEvent::BroadcastAll(message)) = drain(&mut rx).into_iter().next() else {
+            panic!("expected question broadcast");
+        };
+        let payload = payload_of(&message);
+        assert_eq!(payload["question_index"], 0);
+        assert_eq!(payload["text"], format!("Q{}", first + 1));
+    }
+
+    #[test]
+    fn set_shuffle_keeps_supplied_seed_and_is_lobby_only() {
+        let session = make_shuffled_session(false, false);
+        let mut s = session.try_write().unwrap();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        handle_set_shuffle(&mut s, true, true, Some(7), &tx);
+        assert_eq!(
+            s.shuffle,
+            ShuffleSettings {
+                questions: true,
+                options: true,
+                seed: 7
+            }
+        );
+        let Ok(GameEvent::HostOnly(message)) = rx.try_recv() else {
+            panic!("expected shuffle_set to the host");
+        };
+        assert_eq!(
+            payload_of(&message),
+            json!({ "questions": true, "options": true, "seed": 7 })
+        );
+
+        s.status = SessionStatus::Active;
+        handle_set_shuffle(&mut s, false, false, Some(9), &tx);
+        assert_eq!(s.shuffle.seed, 7);
+        assert!(rx.try_recv().is_err());
+    }
+
+    // ── Rounds ───────────────────────────────────────────────────────────────
+
+    /// Three questions: a two-question "Lightning" round with its own rule and time
+    /// limit, then a one-question "Finale" on the session's settings.
+    fn make_round_session() -> Arc<RwLock<GameSession>> {
+        let session = make_shuffled_session(false, false);
+        {
+            let mut s = session.try_write().unwrap();
+            s.quiz.rounds = vec![
+                Round {
+                    title: "Lightning".to_string(),
+                    question_count: 2,
+                    scoring_rule: Some(ScoringRule::PositionRace),
+                    time_limit_sec: Some(10),
+                },
+                Round {
+                    title: "Finale".to_string(),
+                    question_count: 1,
+                    ..Default::default()
+                },
+            ];
+            s.players.insert(
+                "player-2".to_string(),
+                Player::new("player-2".to_string(), "Bob".to_string(), "🙂".to_string()),
+            );
+        }
+        session
+    }
+
+    #[test]
+    fn round_started_is_sent_at_the_first_question_of_each_round() {
+        let session = make_round_session();
+        let s = session.try_read().unwrap();
+
+        let lightning = payload_of(&round_started_message(&s, 0).expect("round 0"));
+        assert_eq!(lightning["round_index"], 0);
+        assert_eq!(lightning["total_rounds"], 2);
+        assert_eq!(lightning["title"], "Lightning");
+        assert_eq!(lightning["question_count"], 2);
+        assert_eq!(lightning["scoring_rule"], "position_race");
+        assert_eq!(lightning["time_limit_sec"], 10);
+        assert!(round_started_message(&s, 1).is_none());
+        let finale = payload_of(&round_started_message(&s, 2).expect("round 1"));
+        assert_eq!(finale["scoring_rule"], "stepped_decay");
+        assert_eq!(finale["time_limit_sec"], 20);
+
+        let plain = make_session(20, 20);
+        assert!(round_started_message(&plain.try_read().unwrap(), 0).is_none());
+    }
+
+    #[tokio::test]
+    async fn round_settings_apply_to_question_and_scoring() {
+        let session = make_round_session();
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_advance_question(session.clone(), tx.clone(), make_session_manager()).await;
+        let Some(GameEvent::BroadcastAll(message)) = drain(&mut rx).into_iter().next() else {
+            panic!("expected question broadcast");
+        };
+        let question = payload_of(&message);
+        assert_eq!(question["question_index"], 1);
+        assert_eq!(question["round_index"], 0);
+        assert_eq!(question["time_limit_sec"], 10);
+        assert_eq!(question["scoring_rule"], "position_race");
+
+        handle_answer(&session, &tx, "player-1", 1, 2, make_session_manager()).await;
+        let result = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::PlayerOnly { message, .. } => Some(payload_of(&message)),
+                _ => None,
+            })
+            .expect("answer_result");
+        assert_eq!(result["points_awarded"], 1000);
+        assert_eq!(result["position"], 1);
+    }
+
+    #[tokio::test]
+    async fn round_ended_carries_round_leaderboard() {
+        let session = make_round_session();
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 1;
+            let answer = |question_index, selected_index, points_awarded| Answer {
+                question_index,
+                selected_index,
+                time_taken_ms: 1000,
+                points_awarded,
+            };
+            let alice = s.players.get_mut("player-1").unwrap();
+            alice.answers = vec![answer(0, 2, 500), answer(1, 0, 0)];
+            alice.score = 2000; // includes points from outside the round
+            let bob = s.players.get_mut("player-2").unwrap();
+            bob.answers = vec![answer(1, 2, 700)];
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_end_question(session.clone(), tx, 1, make_session_manager()).await;
+
+        let ended = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::BroadcastAll(m) if m.contains("round_ended") => Some(payload_of(&m)),
+                _ => None,
+            })
+            .expect("round_ended");
+        assert_eq!(ended["round_index"], 0);
+        assert_eq!(ended["title"], "Lightning");
+        let leaderboard = ended["leaderboard"].as_array().unwrap();
+        assert_eq!(leaderboard[0]["display_name"], "Bob");
+        assert_eq!(leaderboard[0]["score"], 700);
+        assert_eq!(leaderboard[1]["display_name"], "Alice");
+        assert_eq!(leaderboard[1]["score"], 500);
+        assert_eq!(leaderboard[1]["correct_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn round_ended_waits_for_the_last_question_of_the_round() {
+        let session = make_round_session();
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        let types: Vec<serde_json::Value> = drain(&mut rx)
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::BroadcastAll(m) => {
+                    Some(serde_json::from_str::<serde_json::Value>(m).unwrap()["type"].clone())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(types, [json!("question_ended")]);
+    }
+
+    #[tokio::test]
+    async fn question_ended_reports_rank_movement_and_personal_standings() {
+        let session = make_session(20, 20);
+        {
+            let mut s = session.write().await;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+            for (id, score) in [("al", 500), ("bo", 200)] {
+                let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+                player.score = score;
+                s.players.insert(id.to_string(), player);
+            }
+            let refs: Vec<&Player> = s.players.values().collect();
+            s.previous_leaderboard = compute_leaderboard_with(&refs, false, TieBreaker::None, &[]);
+            s.players.get_mut("bo").unwrap().score = 900;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        let mut standings = std::collections::HashMap::new();
+        for event in drain(&mut rx) {
+            match event {
+                GameEvent::BroadcastAll(m) => {
+                    let leaderboard = payload_of(&m)["leaderboard"].clone();
+                    assert_eq!(leaderboard[0]["display_name"], "bo");
+                    assert_eq!(leaderboard[0]["points_gained"], 700);
+                    assert_eq!(leaderboard[0]["previous_rank"], 2);
+                    assert_eq!(leaderboard[0]["rank_change"], 1);
+                    assert_eq!(leaderboard[1]["points_gained"], 0);
+                    assert_eq!(leaderboard[1]["rank_change"], -1);
+                }
+                GameEvent::PlayerOnly { player_id, message } => {
+                    standings.insert(player_id, payload_of(&message));
+                }
+                GameEvent::HostOnly(_) => {}
+            }
+        }
+        assert_eq!(standings["al"]["rank"], 2);
+        assert_eq!(standings["al"]["total_players"], 2);
+        assert_eq!(standings["al"]["rank_change"], -1);
+        assert_eq!(standings["bo"]["points_gained"], 700);
+        let s = session.read().await;
+        assert_eq!(s.previous_leaderboard[0].display_name, "bo");
+    }
+
+    #[test]
+    fn answer_distribution_summarises_picks_and_response_times() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        for (id, answer) in [
+            ("al", Some((1, 1000))),
+            ("bo", Some((0, 4000))),
+            ("cy", Some((1, 2000))),
+            ("di", None),
+        ] {
+            let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+            if let Some((selected_index, time_taken_ms)) = answer {
+                player.answers.push(Answer {
+                    question_index: 0,
+                    selected_index,
+                    time_taken_ms,
+                    points_awarded: 0,
+                });
+            }
+            session.players.insert(id.to_string(), player);
+        }
+
+        let payload = payload_of(&answer_distribution_message(&session, 0));
+
+        assert_eq!(payload["picks"], json!([1, 2]));
+        assert_eq!(payload["correct_index"], 1);
+        assert_eq!(payload["correct_count"], 2);
+        assert_eq!(payload["correct_percentage"], 50.0);
+        assert_eq!(payload["answered"], 3);
+        assert_eq!(payload["no_answer"], 1);
+        assert_eq!(payload["average_time_ms"], 2333);
+        assert_eq!(payload["median_time_ms"], 2000);
+    }
+
+    #[test]
+    fn answer_distribution_counts_only_players_still_in_the_game() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        let second = session.quiz.questions[0].clone();
+        session.quiz.questions.push(second);
+        for (id, eliminated_at) in [("al", None), ("bo", Some(0)), ("cy", Some(0))] {
+            let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+            player.eliminated_at = eliminated_at;
+            player.answers.push(Answer {
+                question_index: 1,
+                selected_index: 0,
+                time_taken_ms: 1000,
+                points_awarded: 0,
+            });
+            session.players.insert(id.to_string(), player);
+        }
+
+        let payload = payload_of(&answer_distribution_message(&session, 1));
+
+        assert_eq!(payload["answered"], 1);
+        assert_eq!(payload["no_answer"], 0);
+    }
+
+    #[test]
+    fn answer_distribution_of_a_poll_has_no_correct_share() {
+        let session_arc = make_session(20, 20);
+        let mut session = session_arc.blocking_write();
+        session.quiz.questions[0].kind = QuestionKind::Poll;
+        session.players.insert(
+            "al".to_string(),
+            Player::new("al".to_string(), "al".to_string(), "🙂".to_string()),
+        );
+
+        let payload = payload_of(&answer_distribution_message(&session, 0));
+
+        assert_eq!(payload["poll"], true);
+        assert_eq!(payload["correct_percentage"], serde_json::Value::Null);
+        assert_eq!(payload["median_time_ms"], serde_json::Value::Null);
+        assert_eq!(payload["no_answer"], 1);
+        assert_eq!(median(&[1000, 3000]), Some(2000));
+    }
+
+    // ── sudden-death tiebreak ───────────────────────────────────────────────
+
+    fn make_tiebreak_session(scores: &[(&str, i32)]) -> Arc<RwLock<GameSession>> {
+        let mut quiz = make_quiz(20);
+        quiz.tiebreakers = vec![quiz.questions[0].clone(); 2];
+        let mut session = GameSession::new("TSTCDE".to_string(), quiz, 20);
+        session.tie_breaker = TieBreaker::SuddenDeath;
+        session.status = SessionStatus::Active;
+        session.current_question = 0;
+        for &(id, score) in scores {
+            let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+            player.score = score;
+            session.players.insert(id.to_string(), player);
+        }
+        Arc::new(RwLock::new(session))
+    }
+
+    fn names(entries: &serde_json::Value) -> Vec<&str> {
+        entries
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["display_name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tied_leaders_enter_sudden_death() {
+        let session_arc = make_tiebreak_session(&[("bo", 900), ("al", 900), ("cy", 400)]);
+        let mut session = session_arc.blocking_write();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(8);
+
+        assert!(start_tiebreak(&mut session, &tx));
+
+        assert_eq!(session.status, SessionStatus::Tiebreak);
+        assert_eq!(session.tiebreak.as_ref().unwrap().contenders, ["al", "bo"]);
+        for event in drain(&mut rx) {
+            let (message, contender) = match event {
+                GameEvent::HostOnly(message) => (message, false),
+                GameEvent::PlayerOnly { player_id, message } => (message, player_id != "cy"),
+                GameEvent::BroadcastAll(_) => panic!("expected per-recipient messages"),
+            };
+            let payload = payload_of(&message);
+            assert_eq!(names(&payload["contenders"]), ["al", "bo"]);
+            assert_eq!(payload["score"], 900);
+            assert_eq!(payload["contender"], contender);
+        }
+    }
+
+    #[test]
+    fn sudden_death_needs_a_tie_and_tiebreaker_questions() {
+        let (tx, _rx) = broadcast::channel::<GameEvent>(8);
+
+        let sole_leader = make_tiebreak_session(&[("al", 900), ("bo", 400)]);
+        assert!(!start_tiebreak(&mut sole_leader.blocking_write(), &tx));
+
+        let other_rule = make_tiebreak_session(&[("al", 900), ("bo", 900)]);
+        other_rule.blocking_write().tie_breaker = TieBreaker::CorrectCount;
+        assert!(!start_tiebreak(&mut other_rule.blocking_write(), &tx));
+
+        let no_questions = make_tiebreak_session(&[("al", 900), ("bo", 900)]);
+        no_questions.blocking_write().quiz.tiebreakers.clear();
+        let mut session = no_questions.blocking_write();
+        assert!(!start_tiebreak(&mut session, &tx));
+        assert_eq!(session.status, SessionStatus::Active);
+    }
+
+    #[test]
+    fn tiebreak_started_while_paused_stays_paused() {
+        let session_arc = make_tiebreak_session(&[("al", 900), ("bo", 900)]);
+        let mut session = session_arc.blocking_write();
+        session.status = SessionStatus::Paused;
+        let (tx, _rx) = broadcast::channel::<GameEvent>(8);
+
+        assert!(start_tiebreak(&mut session, &tx));
+
+        assert_eq!(session.status, SessionStatus::Paused);
+        assert!(session.tiebreak.is_some());
+    }
+
+    #[tokio::test]
+    async fn only_contenders_answer_tiebreaker_questions() {
+        let session = make_tiebreak_session(&[("al", 900), ("bo", 900), ("cy", 400)]);
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+        start_tiebreak(&mut *session.write().await, &tx);
+        do_tiebreak_question(session.clone(), tx.clone(), make_session_manager()).await;
+        drain(&mut rx);
+
+        handle_tiebreak_answer(&session, &tx, "cy", 0, 1, make_session_manager()).await;
+
+        let Ok(GameEvent::PlayerOnly { player_id, message }) = rx.try_recv() else {
+            panic!("expected PlayerOnly");
+        };
+        assert_eq!(player_id, "cy");
+        assert_eq!(payload_of(&message)["code"], "not_a_contender");
+        assert!(
+            session
+                .read()
+                .await
+                .tiebreak
+                .as_ref()
+                .unwrap()
+                .answers
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn ending_a_regular_question_during_a_tiebreak_is_ignored() {
+        let session = make_tiebreak_session(&[("al", 900), ("bo", 900)]);
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+        let past_last = {
+            let mut s = session.write().await;
+            s.current_question = s.quiz.questions.len() as i32;
+            start_tiebreak(&mut s, &tx);
+            s.current_question as usize
+        };
+        do_tiebreak_question(session.clone(), tx.clone(), make_session_manager()).await;
+        drain(&mut rx);
+
+        do_end_question(
+            session.clone(),
+            tx.clone(),
+            past_last,
+            make_session_manager(),
+        )
+        .await;
+
+        assert!(drain(&mut rx).is_empty());
+        let s = session.read().await;
+        assert_eq!(s.status, SessionStatus::Tiebreak);
+        assert!(s.tiebreak.as_ref().unwrap().open);
+    }
+
+    #[tokio::test]
+    async fn sudden_death_ends_when_one_contender_survives() {
+        let session = make_tiebreak_session(&[("al", 900), ("bo", 900), ("cy", 400)]);
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+        start_tiebreak(&mut *session.write().await, &tx);
+        do_tiebreak_question(session.clone(), tx.clone(), make_session_manager()).await;
+
+        handle_tiebreak_answer(&session, &tx, "al", 0, 0, make_session_manager()).await;
+        handle_tiebreak_answer(&session, &tx, "bo", 0, 1, make_session_manager()).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(session.read().await.status, SessionStatus::Finished);
+        let broadcasts: Vec<serde_json::Value> = drain(&mut rx)
+            .into_iter()
+            .filter_map(|e| match e {
+                GameEvent::BroadcastAll(m) => serde_json::from_str(&m).ok(),
+                _ => None,
+            })
+            .collect();
+        let result = &broadcasts[0];
+        assert_eq!(result["type"], "tiebreak_result");
+        assert_eq!(names(&result["payload"]["survivors"]), ["bo"]);
+        assert_eq!(names(&result["payload"]["eliminated"]), ["al"]);
+        let finished = &broadcasts[1];
+        assert_eq!(finished["type"], "game_finished");
+        let winners: Vec<_> = finished["payload"]["leaderboard"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|e| e["is_winner"] == true)
+            .map(|e| e["display_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(winners, ["bo"]);
+    }
+
+    #[tokio::test]
+    async fn everyone_survives_when_no_contender_is_correct() {
+        let session = make_tiebreak_session(&[("al", 900), ("bo", 900)]);
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+        start_tiebreak(&mut *session.write().await, &tx);
+        do_tiebreak_question(session.clone(), tx.clone(), make_session_manager()).await;
+
+        end_tiebreak_question(session.clone(), tx.clone(), 0, make_session_manager()).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let s = session.read().await;
+        assert_eq!(s.status, SessionStatus::Tiebreak);
+        let tiebreak = s.tiebreak.as_ref().unwrap();
+        assert_eq!(tiebreak.contenders, ["al", "bo"]);
+        assert_eq!(tiebreak.question, Some(1));
+        let asked: Vec<u64> = drain(&mut rx)
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::HostOnly(m) => {
+                    Some(serde_json::from_str::<serde_json::Value>(m).unwrap())
+                }
+                _ => None,
+            })
+            .filter(|m| m["type"] == "tiebreak_question")
+            .map(|m| m["payload"]["tiebreak_index"].as_u64().unwrap())
+            .collect();
+        assert_eq!(asked, [0, 1]);
+    }
+
+    // ── Teams ────────────────────────────────────────────────────────────────
+
+    fn make_team_session(players: &[(&str, i32, Option<&str>)]) -> Arc<RwLock<GameSession>> {
+        let session = make_session(20, 20);
+        {
+            let mut s = session.try_write().unwrap();
+            for &(id, score, team) in players {
+                let mut player = Player::new(id.to_string(), id.to_string(), "🙂".to_string());
+                player.score = score;
+                player.answers.push(Answer {
+                    question_index: 0,
+                    selected_index: 0,
+                    time_taken_ms: 0,
+                    points_awarded: score,
+                });
+                player.team = team.map(str::to_string);
+                s.players.insert(id.to_string(), player);
+            }
+        }
+        session
+    }
+
+    #[test]
+    fn set_teams_replaces_teams_and_drops_removed_ones() {
+        let session = make_team_session(&[("al", 0, Some("Red")), ("bo", 0, Some("Blue"))]);
+        let mut s = session.try_write().unwrap();
+        s.teams.names = vec!["Red".to_string(), "Blue".to_string()];
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        handle_set_teams(
+            &mut s,
+            &json!({ "names": ["red", "Green"], "aggregation": "best" }),
+            &tx,
+        );
+
+        assert_eq!(s.teams.names, ["red", "Green"]);
+        assert_eq!(s.teams.aggregation, TeamAggregation::Best);
+        assert_eq!(s.players["al"].team.as_deref(), Some("red"));
+        assert_eq!(s.players["bo"].team, None);
+        let Ok(GameEvent::BroadcastAll(message)) = rx.try_recv() else {
+            panic!("expected teams_updated broadcast");
+        };
+        let payload = payload_of(&message);
+        assert_eq!(payload["aggregation"], "best");
+        assert_eq!(payload["teams"][0]["name"], "red");
+        assert_eq!(payload["teams"][0]["members"][0]["display_name"], "al");
+        assert_eq!(payload["teams"][1]["members"], json!([]));
+    }
+
+    #[test]
+    fn set_teams_rejects_invalid_teams_and_is_lobby_only() {
+        let session = make_team_session(&[]);
+        let mut s = session.try_write().unwrap();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        for payload in [
+            json!({ "names": ["Red", " red "] }),
+            json!({ "names": [""] }),
+            json!({ "names": ["Red"], "aggregation": "median" }),
+            json!({}),
+        ] {
+            handle_set_teams(&mut s, &payload, &tx);
+            let Ok(GameEvent::HostOnly(message)) = rx.try_recv() else {
+                panic!("expected an error for {payload}");
+            };
+            assert_eq!(payload_of(&message)["code"], "invalid_teams");
+        }
+        assert!(!s.teams.is_team_game());
+
+        s.status = SessionStatus::Active;
+        handle_set_teams(&mut s, &json!({ "names": ["Red"] }), &tx);
+        assert!(!s.teams.is_team_game());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn auto_balance_deals_players_evenly_into_teams() {
+        let ids = ["a", "b", "c", "d", "e"];
+        let session = make_team_session(&ids.map(|id| (id, 0, None)));
+        let mut s = session.try_write().unwrap();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        handle_auto_balance_teams(&mut s, &tx);
+        let Ok(GameEvent::HostOnly(message)) = rx.try_recv() else {
+            panic!("expected an error without teams");
+        };
+        assert_eq!(payload_of(&message)["code"], "invalid_teams");
+
+        s.teams.names = vec!["Red".to_string(), "Blue".to_string()];
+        handle_auto_balance_teams(&mut s, &tx);
+        let red = s
+            .players
+            .values()
+            .filter(|p| p.team.as_deref() == Some("Red"))
+            .count();
+        assert!(s.players.values().all(|p| p.team.is_some()));
+        assert!(red == 2 || red == 3, "{red} players in Red");
+        assert!(matches!(rx.try_recv(), Ok(GameEvent::BroadcastAll(_))));
+    }
+
+    #[tokio::test]
+    async fn team_leaderboard_is_sent_after_each_question_and_at_the_end() {
+        let session = make_team_session(&[
+            ("al", 500, Some("Red")),
+            ("bo", 300, Some("Red")),
+            ("cy", 700, Some("Blue")),
+        ]);
+        {
+            let mut s = session.write().await;
+            s.teams.names = vec!["Red".to_string(), "Blue".to_string()];
+            s.teams.aggregation = TeamAggregation::Average;
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+
+        do_end_question(session.clone(), tx.clone(), 0, make_session_manager()).await;
+        broadcast_game_finished(&*session.read().await, &tx);
+
+        let broadcasts: Vec<serde_json::Value> = drain(&mut rx)
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::BroadcastAll(m) => Some(serde_json::from_str(m).unwrap()),
+                _ => None,
+            })
+            .collect();
+        let ended = broadcasts
+            .iter()
+            .find(|m| m["type"] == "question_ended")
+            .expect("question_ended");
+        let teams = &ended["payload"]["team_leaderboard"];
+        assert_eq!(teams[0]["team"], "Blue");
+        assert_eq!(teams[0]["score"], 700);
+        assert_eq!(teams[1]["team"], "Red");
+        assert_eq!(teams[1]["score"], 400);
+        assert_eq!(teams[1]["member_count"], 2);
+
+        let finished = broadcasts
+            .iter()
+            .find(|m| m["type"] == "game_finished")
+            .expect("game_finished");
+        assert_eq!(finished["payload"]["team_aggregation"], "average");
+        assert_eq!(
+            finished["payload"]["team_leaderboard"][0]["is_winner"],
+            true
+        );
+    }
+
+    // ── Elimination ──────────────────────────────────────────────────────────
+
+    /// The three-question shuffled-session quiz (option "C" correct) as an active
+    /// elimination game at its first question, with Alice, Bob and Cy playing.
+    fn make_elimination_session(
+        lives: u32,
+        max_answer_sec: Option<u64>,
+    ) -> Arc<RwLock<GameSession>> {
+        let session = make_shuffled_session(false, false);
+        {
+            let mut s = session.try_write().unwrap();
+            for (id, name) in [("player-2", "Bob"), ("player-3", "Cy")] {
+                s.players.insert(
+                    id.to_string(),
+                    Player::new(id.to_string(), name.to_string(), "🙂".to_string()),
+                );
+            }
+            s.elimination = EliminationSettings {
+                enabled: true,
+                lives,
+                max_answer_sec,
+            };
+            s.status = SessionStatus::Active;
+            s.current_question = 0;
+        }
+        session
+    }
+
+    fn give_answer(session: &mut GameSession, player_id: &str, selected_index: usize, ms: u64) {
+        let position = session.current_question as usize;
+        session
+            .players
+            .get_mut(player_id)
+            .unwrap()
+            .answers
+            .push(Answer {
+                question_index: position,
+                selected_index,
+                time_taken_ms: ms,
+                points_awarded: 0,
+            });
+    }
+
+    fn broadcast_of_type(events: &[GameEvent], kind: &str) -> serde_json::Value {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                GameEvent::BroadcastAll(m) => serde_json::from_str::<serde_json::Value>(m).ok(),
+                _ => None,
+            })
+            .find(|m| m["type"] == kind)
+            .unwrap_or_else(|| panic!("expected a {kind} broadcast"))
+    }
+
+    #[test]
+    fn set_elimination_validates_and_is_lobby_only() {
+        let session = make_session(20, 20);
+        let mut s = session.try_write().unwrap();
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(16);
+
+        handle_set_elimination(&mut s, &json!({ "enabled": true, "lives": 2 }), &tx);
+        assert_eq!(
+            s.elimination,
+            EliminationSettings {
+                enabled: true,
+                lives: 2,
+                max_answer_sec: None
+            }
+        );
+        let Ok(GameEvent::BroadcastAll(message)) = rx.try_recv() else {
+            panic!("expected elimination_set broadcast");
+        };
+        assert_eq!(
+            payload_of(&message),
+            json!({ "enabled": true, "lives": 2, "max_answer_sec": null })
+        );
+
+        handle_set_elimination(&mut s, &json!({ "enabled": true, "lives": 0 }), &tx);
+        let Ok(GameEvent::HostOnly(message)) = rx.try_recv() else {
+            panic!("expected an error for zero lives");
+        };
+        assert_eq!(payload_of(&message)["code"], "invalid_elimination");
+        assert_eq!(s.elimination.lives, 2);
+
+        s.status = SessionStatus::Active;
+        handle_set_elimination(&mut s, &json!({ "enabled": false }), &tx);
+        assert!(s.elimination.enabled);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn missing_a_question_eliminates_the_player() {
+        let session = make_elimination_session(1, None);
+        {
+            let mut s = session.write().await;
+            give_answer(&mut s, "player-1", 2, 1_000);
+            give_answer(&mut s, "player-2", 0, 1_000);
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+
+        do_end_question(session.clone(), tx, 0, make_session_manager()).await;
+
+        let events = drain(&mut rx);
+        let eliminated = broadcast_of_type(&events, "players_eliminated")["payload"].clone();
+        let names: Vec<_> = eliminated["eliminated"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["display_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["Bob", "Cy"]);
+        assert_eq!(eliminated["remaining"], 1);
+        let leaderboard =
+            broadcast_of_type(&events, "question_ended")["payload"]["leaderboard"].clone();
+        assert_eq!(leaderboard[0]["display_name"], "Alice");
+        assert_eq!(leaderboard[0]["eliminated"], false);
+        assert_eq!(leaderboard[1]["eliminated"], true);
+        let standing = events
+            .iter()
+            .find_map(|e| match e {
+                GameEvent::PlayerOnly { player_id, message } if player_id == "player-2" => {
+                    Some(payload_of(message))
+                }
+                _ => None,
+            })
+            .expect("Bob's standing");
+        assert_eq!(standing["eliminated"], true);
+        assert_eq!(standing["lives_left"], 0);
+
+        let s = session.read().await;
+        assert_eq!(s.players["player-2"].eliminated_at, Some(0));
+        assert!(s.last_player_standing());
+    }
+
+    #[tokio::test]
+    async fn players_with_lives_left_stay_in() {
+        let session = make_elimination_session(2, Some(5));
+        {
+            let mut s = session.write().await;
+            give_answer(&mut s, "player-1", 2, 1_000);
+            give_answer(&mut s, "player-2", 0, 1_000);
+            give_answer(&mut s, "player-3", 2, 9_000);
+        }
+
+        let message = eliminate_players(&mut *session.write().await, 0).expect("message");
+        let payload = payload_of(&message);
+        assert_eq!(payload["eliminated"], json!([]));
+        assert_eq!(payload["lost_life"][0]["display_name"], "Bob");
+        assert_eq!(payload["lost_life"][0]["lives_left"], 1);
+        assert_eq!(payload["lost_life"][1]["display_name"], "Cy");
+        assert_eq!(payload["remaining"], 3);
+
+        // Ending the same question again costs nothing more
+        let mut s = session.write().await;
+        eliminate_players(&mut s, 0);
+        assert_eq!(s.players["player-2"].lives_lost_at, [0]);
+        assert!(!s.players["player-2"].is_eliminated());
+    }
+
+    #[tokio::test]
+    async fn nobody_is_eliminated_when_everyone_still_in_misses() {
+        let session = make_elimination_session(1, None);
+        let mut s = session.write().await;
+        give_answer(&mut s, "player-1", 0, 1_000);
+
+        let payload = payload_of(&eliminate_players(&mut s, 0).expect("message"));
+
+        assert_eq!(payload["eliminated"], json!([]));
+        assert_eq!(payload["remaining"], 3);
+        assert!(s.players.values().all(|p| p.lives_lost_at.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn eliminated_players_cannot_answer_and_are_not_waited_for() {
+        let session = make_elimination_session(1, None);
+        {
+            let mut s = session.write().await;
+            s.current_question = 1;
+            s.players.get_mut("player-3").unwrap().eliminated_at = Some(0);
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+
+        handle_answer(&session, &tx, "player-3", 1, 2, make_session_manager()).await;
+        let Ok(GameEvent::PlayerOnly { message, .. }) = rx.try_recv() else {
+            panic!("expected an error for the eliminated player");
+        };
+        assert_eq!(payload_of(&message)["code"], "eliminated");
+
+        handle_answer(&session, &tx, "player-1", 1, 2, make_session_manager()).await;
+        let count = drain(&mut rx)
+            .into_iter()
+            .find_map(|e| match e {
+                GameEvent::HostOnly(m) => Some(payload_of(&m)),
+                _ => None,
+            })
+            .expect("answer_count");
+        assert_eq!(count, json!({ "answered": 1, "total": 2 }));
+    }
+
+    #[tokio::test]
+    async fn single_player_elimination_game_still_asks_questions() {
+        let session = make_elimination_session(1, None);
+        {
+            let mut s = session.write().await;
+            s.players.retain(|id, _| id == "player-1");
+            s.current_question = -1;
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+
+        do_advance_question(session.clone(), tx, make_session_manager()).await;
+
+        let question = broadcast_of_type(&drain(&mut rx), "question");
+        assert_eq!(question["payload"]["question_index"], 0);
+        let s = session.read().await;
+        assert_eq!(s.status, SessionStatus::Active);
+        assert!(!s.last_player_standing());
+    }
+
+    #[tokio::test]
+    async fn game_ends_when_one_player_is_left() {
+        let session = make_elimination_session(1, None);
+        {
+            let mut s = session.write().await;
+            for id in ["player-2", "player-3"] {
+                s.players.get_mut(id).unwrap().eliminated_at = Some(0);
+            }
+        }
+        let (tx, mut rx) = broadcast::channel::<GameEvent>(32);
+
+        do_advance_question(session.clone(), tx, make_session_manager()).await;
+
+        let finished = broadcast_of_type(&drain(&mut rx), "game_finished")["payload"].clone();
+        assert_eq!(finished["leaderboard"][0]["display_name"], "Alice");
+        assert_eq!(finished["leaderboard"][0]["is_winner"], true);
+        assert_eq!(finished["elimination"]["enabled"], true);
+        assert_eq!(session.read().await.status, SessionStatus::Finished);
+    }
 }
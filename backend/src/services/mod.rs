@@ -1,2 +1,9 @@
 pub mod game_engine;
+pub mod quiz_bundle;
+pub mod quiz_csv;
+pub mod quiz_export;
+pub mod quiz_import;
+pub mod quiz_library;
+pub mod quiz_lint;
+pub mod scorer_registry;
 pub mod session_manager;
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::io::{Cursor, Read};
+
+use crate::errors::{AppError, ParseError};
+use crate::models::asset::{QuizAsset, QuizAssets, content_type_for};
+use crate::models::quiz::{Quiz, normalize_media_path};
+use crate::services::quiz_import::QuizFormat;
+
+/// Local file header signature that every zip archive starts with.
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+const ONE_QUIZ_FILE: &str =
+    "Bundle must contain exactly one quiz file (.txt, .json, .yaml, .yml or .csv)";
+
+/// The contents of an uploaded zip bundle: the quiz document plus its media files.
+#[derive(Debug)]
+pub struct QuizBundle {
+    pub quiz_file_name: String,
+    pub quiz_text: String,
+    pub assets: QuizAssets,
+}
+
+pub fn is_zip(bytes: &[u8]) -> bool {
+    bytes.starts_with(ZIP_MAGIC)
+}
+
+/// Extract a bundle containing exactly one quiz document (`.txt`, `.json`, `.yaml`,
+/// `.yml` or `.csv`) and any number of image/audio assets. `max_bytes` bounds the total
+/// extracted size.
+pub fn read_bundle(bytes: &[u8], max_bytes: usize) -> Result<QuizBundle, AppError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| AppError::InvalidUpload(format!("Invalid zip bundle: {e}")))?;
+
+    let mut quiz_file: Option<(String, String)> = None;
+    let mut assets = QuizAssets::new();
+    let mut total_bytes = 0usize;
+
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| AppError::InvalidUpload(format!("Invalid zip bundle: {e}")))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let Some(path) = entry.enclosed_name() else {
+            return Err(AppError::InvalidUpload(format!(
+                "Bundle entry '{}' has an unsafe path",
+                entry.name()
+            )));
+        };
+        let path = normalize_media_path(&path.to_string_lossy());
+        if is_metadata_entry(&path) {
+            continue;
+        }
+
+        let remaining = max_bytes.saturating_sub(total_bytes);
+        if entry.size() as usize > remaining {
+            return Err(AppError::UploadTooLarge(max_bytes));
+        }
+        let mut data = Vec::with_capacity(entry.size() as usize);
+        (&mut entry)
+            .take(remaining as u64 + 1)
+            .read_to_end(&mut data)
+            .map_err(|e| AppError::InvalidUpload(format!("Invalid zip bundle: {e}")))?;
+        if data.len() > remaining {
+            return Err(AppError::UploadTooLarge(max_bytes));
+        }
+        total_bytes += data.len();
+
+        if QuizFormat::is_quiz_file_name(&path) {
+            if quiz_file.is_some() {
+                return Err(AppError::InvalidUpload(ONE_QUIZ_FILE.into()));
+            }
+            let text = String::from_utf8(data).map_err(|_| {
+                AppError::InvalidUpload(format!("Quiz file '{path}' is not valid UTF-8"))
+            })?;
+            quiz_file = Some((path, text));
+            continue;
+        }
+
+        let content_type =
+            content_type_for(&path).ok_or_else(|| AppError::UnsupportedMediaType(path.clone()))?;
+        assets.insert(path, QuizAsset { content_type, data });
+    }
+
+    let (quiz_file_name, quiz_text) =
+        quiz_file.ok_or_else(|| AppError::InvalidUpload(ONE_QUIZ_FILE.into()))?;
+
+    Ok(QuizBundle {
+        quiz_file_name,
+        quiz_text,
+        assets,
+    })
+}
+
+/// Report every media reference in `quiz` that has no matching asset.
+/// Text-format references are located by their `@` line in `content`; references
+/// from structured documents fall back to their document path.
+pub fn check_media_references(quiz: &Quiz, assets: &QuizAssets, content: &str) -> Vec<ParseError> {
+    let referenced = quiz.questions.iter().enumerate().flat_map(|(qi, q)| {
+        let question_ref = q
+            .media
+            .as_ref()
+            .map(|m| (m, format!("questions[{qi}].media")));
+        let option_refs = q.options.iter().enumerate().filter_map(move |(oi, o)| {
+            o.media
+                .as_ref()
+                .map(|m| (m, format!("questions[{qi}].options[{oi}].media")))
+        });
+        question_ref.into_iter().chain(option_refs)
+    });
+
+    let mut seen = HashSet::new();
+    let mut errors: Vec<ParseError> = Vec::new();
+    for (media, doc_path) in referenced {
+        if assets.contains_key(media) || !seen.insert(media) {
+            continue;
+        }
+        let message = format!("Media file '{media}' not found in the uploaded bundle");
+        errors.push(match media_line(content, media) {
+            Some(line) => ParseError::new(line, message),
+            None => ParseError::at_path(doc_path, message),
+        });
+    }
+    errors.sort_by_key(|e| e.line);
+    errors
+}
+
+fn media_line(content: &str, path: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| {
+            line.trim()
+                .strip_prefix('@')
+                .is_some_and(|rest| normalize_media_path(rest.trim()) == path)
+        })
+        .map(|i| i + 1)
+}
+
+/// Files added by archivers (e.g. macOS `__MACOSX/`, `.DS_Store`) that are not part
+/// of the quiz.
+fn is_metadata_entry(path: &str) -> bool {
+    path.starts_with("__MACOSX/")
+        || path
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name.starts_with('.'))
+}
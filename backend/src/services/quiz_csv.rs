@@ -0,0 +1,389 @@
+use crate::errors::ParseError;
+use crate::models::quiz::{
+    OptionLimits, ParseSettings, Question, QuestionKind, Quiz, QuizOption, dedup_tags, split_tags,
+    time_limit_violation, weight_violation,
+};
+
+/// What a CSV header maps to. Headers are matched case-insensitively, with `_`
+/// and `-` treated as spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Question,
+    /// Zero-based option slot, from `Option A`/`Option 1` onwards.
+    Option(usize),
+    Correct,
+    TimeLimit,
+    Explanation,
+    Tags,
+    Weight,
+}
+
+impl Column {
+    fn from_header(header: &str) -> Option<Self> {
+        let normalized = header
+            .to_ascii_lowercase()
+            .replace(['_', '-'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        match normalized.as_str() {
+            "question" | "question text" => Some(Column::Question),
+            "correct" | "answer" | "correct answer" => Some(Column::Correct),
+            "time limit" | "time limit sec" => Some(Column::TimeLimit),
+            "explanation" => Some(Column::Explanation),
+            "tags" => Some(Column::Tags),
+            "weight" => Some(Column::Weight),
+            other => other
+                .strip_prefix("option ")
+                .and_then(option_slot)
+                .map(Column::Option),
+        }
+    }
+}
+
+/// Column positions resolved from the header row.
+struct ColumnMap {
+    headers: Vec<String>,
+    question: usize,
+    options: Vec<usize>,
+    correct: usize,
+    time_limit: Option<usize>,
+    explanation: Option<usize>,
+    tags: Option<usize>,
+    weight: Option<usize>,
+}
+
+/// Parse a CSV question bank with a header row into a quiz, along with the
+/// spreadsheet row of each question. CSV carries no title, so the caller supplies one.
+pub fn parse_csv_quiz(
+    content: &str,
+    title: &str,
+    settings: &ParseSettings,
+) -> Result<(Quiz, Vec<usize>), Vec<ParseError>> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let headers = match reader.headers() {
+        Ok(headers) => headers.iter().map(str::to_string).collect::<Vec<_>>(),
+        Err(e) => return Err(vec![csv_error(&e, 1)]),
+    };
+    let columns = map_columns(headers)?;
+
+    let mut questions = Vec::new();
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 2;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                errors.push(csv_error(&e, row));
+                continue;
+            }
+        };
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        let line = record.position().map_or(0, |p| p.line() as usize);
+        if let Some(question) = parse_row(
+            &columns,
+            &record,
+            line,
+            row,
+            settings.default_time_limit,
+            settings.option_limits,
+            &mut errors,
+        ) {
+            questions.push(question);
+            rows.push(row);
+        }
+    }
+
+    if questions.is_empty() && errors.is_empty() {
+        errors.push(ParseError::new(0, "Quiz has no valid questions"));
+    }
+
+    if errors.is_empty() {
+        let quiz = Quiz {
+            title: title.to_string(),
+            questions,
+            ..Default::default()
+        };
+        Ok((quiz, rows))
+    } else {
+        Err(errors)
+    }
+}
+
+fn map_columns(headers: Vec<String>) -> Result<ColumnMap, Vec<ParseError>> {
+    let mut errors = Vec::new();
+    let mut found: Vec<(Column, usize)> = Vec::new();
+
+    for (index, header) in headers.iter().enumerate() {
+        match Column::from_header(header) {
+            Some(column) if found.iter().any(|(c, _)| *c == column) => {
+                errors.push(ParseError::at_cell(
+                    1,
+                    1,
+                    header.as_str(),
+                    format!("Duplicate column '{header}'"),
+                ));
+            }
+            Some(column) => found.push((column, index)),
+            None => errors.push(ParseError::at_cell(
+                1,
+                1,
+                header.as_str(),
+                format!(
+                    "Unknown column '{header}' (expected question, option A.., correct, time_limit, explanation, tags or weight)"
+                ),
+            )),
+        }
+    }
+
+    let position = |wanted: Column| {
+        found
+            .iter()
+            .find(|(c, _)| *c == wanted)
+            .map(|&(_, index)| index)
+    };
+
+    let mut options = Vec::new();
+    while let Some(index) = position(Column::Option(options.len())) {
+        options.push(index);
+    }
+    let highest_slot = found
+        .iter()
+        .filter_map(|(c, _)| match c {
+            Column::Option(slot) => Some(*slot),
+            _ => None,
+        })
+        .max();
+    if let Some(highest) = highest_slot
+        && highest >= options.len()
+    {
+        errors.push(ParseError::at_row(
+            1,
+            1,
+            format!(
+                "Missing column 'Option {}' (option columns must be consecutive from Option A)",
+                option_letter(options.len())
+            ),
+        ));
+    }
+
+    let question = position(Column::Question);
+    let correct = position(Column::Correct);
+    if question.is_none() {
+        errors.push(ParseError::at_row(
+            1,
+            1,
+            "Missing required column 'question'",
+        ));
+    }
+    if correct.is_none() {
+        errors.push(ParseError::at_row(
+            1,
+            1,
+            "Missing required column 'correct'",
+        ));
+    }
+    if highest_slot.is_none() {
+        errors.push(ParseError::at_row(
+            1,
+            1,
+            "Missing option columns (expected 'Option A', 'Option B', ...)",
+        ));
+    }
+
+    match (question, correct) {
+        (Some(question), Some(correct)) if errors.is_empty() => Ok(ColumnMap {
+            time_limit: position(Column::TimeLimit),
+            explanation: position(Column::Explanation),
+            tags: position(Column::Tags),
+            weight: position(Column::Weight),
+            headers,
+            question,
+            options,
+            correct,
+        }),
+        _ => Err(errors),
+    }
+}
+
+fn parse_row(
+    columns: &ColumnMap,
+    record: &csv::StringRecord,
+    line: usize,
+    row: usize,
+    default_time_limit: u64,
+    limits: OptionLimits,
+    errors: &mut Vec<ParseError>,
+) -> Option<Question> {
+    let error_count = errors.len();
+    let cell = |index: usize| record.get(index).unwrap_or("");
+    let header = |index: usize| columns.headers[index].as_str();
+
+    let text = cell(columns.question);
+    if text.is_empty() {
+        errors.push(ParseError::at_cell(
+            line,
+            row,
+            header(columns.question),
+            "Question text is empty",
+        ));
+    }
+
+    let cells: Vec<&str> = columns.options.iter().map(|&i| cell(i)).collect();
+    let filled = cells
+        .iter()
+        .rposition(|c| !c.is_empty())
+        .map_or(0, |i| i + 1);
+    for (slot, value) in cells[..filled].iter().enumerate() {
+        if value.is_empty() {
+            errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(columns.options[slot]),
+                format!(
+                    "Option {} is empty but a later option is filled",
+                    option_letter(slot)
+                ),
+            ));
+        }
+    }
+    if let Some(message) = limits.violation(filled) {
+        errors.push(ParseError::at_row(line, row, message));
+    }
+
+    let correct = cell(columns.correct);
+    let correct_index = match parse_correct(correct) {
+        _ if correct.is_empty() => {
+            errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(columns.correct),
+                "Correct answer is missing",
+            ));
+            0
+        }
+        Some(index) if index < filled => index,
+        Some(_) => {
+            errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(columns.correct),
+                format!("Correct answer '{correct}' does not refer to one of the {filled} options"),
+            ));
+            0
+        }
+        None => {
+            errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(columns.correct),
+                format!("Correct answer '{correct}' must be an option letter (A, B, ...) or number (1, 2, ...)"),
+            ));
+            0
+        }
+    };
+
+    let mut time_limit_sec = default_time_limit;
+    if let Some(index) = columns.time_limit
+        && !cell(index).is_empty()
+    {
+        match cell(index).parse::<u64>() {
+            Ok(seconds) => match time_limit_violation(seconds) {
+                Some(message) => {
+                    errors.push(ParseError::at_cell(line, row, header(index), message))
+                }
+                None => time_limit_sec = seconds,
+            },
+            Err(_) => errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(index),
+                format!(
+                    "Time limit '{}' is not a whole number of seconds",
+                    cell(index)
+                ),
+            )),
+        }
+    }
+
+    let explanation = columns
+        .explanation
+        .map(cell)
+        .filter(|e| !e.is_empty())
+        .map(str::to_string);
+    let tags = dedup_tags(columns.tags.map(cell).map(split_tags).unwrap_or_default());
+
+    let mut weight = None;
+    if let Some(index) = columns.weight
+        && !cell(index).is_empty()
+    {
+        match cell(index).parse::<f64>() {
+            Ok(value) => match weight_violation(value) {
+                Some(message) => {
+                    errors.push(ParseError::at_cell(line, row, header(index), message))
+                }
+                None => weight = Some(value),
+            },
+            Err(_) => errors.push(ParseError::at_cell(
+                line,
+                row,
+                header(index),
+                format!("Weight '{}' is not a number", cell(index)),
+            )),
+        }
+    }
+
+    if errors.len() > error_count {
+        return None;
+    }
+    Some(Question {
+        text: text.to_string(),
+        options: cells[..filled]
+            .iter()
+            .map(|text| QuizOption {
+                text: text.to_string(),
+                media: None,
+            })
+            .collect(),
+        correct_index,
+        time_limit_sec,
+        kind: QuestionKind::Standard,
+        explanation,
+        media: None,
+        tags,
+        weight,
+    })
+}
+
+/// `A`/`b` or a 1-based number, as zero-based option index.
+fn parse_correct(value: &str) -> Option<usize> {
+    option_slot(&value.to_ascii_lowercase())
+}
+
+/// Zero-based slot for a lowercase option letter or 1-based option number.
+fn option_slot(label: &str) -> Option<usize> {
+    match label.as_bytes() {
+        [letter @ b'a'..=b'z'] => Some((letter - b'a') as usize),
+        _ => label.parse::<usize>().ok().and_then(|n| n.checked_sub(1)),
+    }
+}
+
+fn option_letter(slot: usize) -> String {
+    match u8::try_from(slot) {
+        Ok(slot @ 0..26) => char::from(b'A' + slot).to_string(),
+        _ => (slot + 1).to_string(),
+    }
+}
+
+fn csv_error(error: &csv::Error, row: usize) -> ParseError {
+    let line = error.position().map_or(0, |p| p.line() as usize);
+    ParseError::at_row(line, row, format!("Invalid CSV: {error}"))
+}
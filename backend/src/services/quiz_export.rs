@@ -0,0 +1,178 @@
+use std::fmt::Write;
+
+use crate::models::quiz::{Question, Quiz, QuizMetadata, Round, needs_escape};
+use crate::models::scoring_rule::ScoringRule;
+
+/// Serialize a quiz to the line-based text format read by `parse_quiz`.
+///
+/// Per-question time limits are not part of the text format; every question
+/// re-parses with the front-matter `time_limit` (or the server default). Trailing
+/// whitespace on each line of text is not preserved. Scoring rules are written by
+/// name only, so custom rule parameters fall back to their defaults and a
+/// composite rule to its base rule.
+pub fn export_quiz(quiz: &Quiz) -> String {
+    let mut out = String::new();
+
+    write_front_matter(&mut out, &quiz.metadata);
+    let _ = writeln!(out, "# {}", escape(&single_line(&quiz.title)));
+
+    let round_starts: Vec<usize> = quiz.round_ranges().iter().map(|r| r.start).collect();
+    for (qi, question) in quiz.questions.iter().enumerate() {
+        out.push('\n');
+        if let Some(ri) = round_starts.iter().position(|&start| start == qi)
+            && let Some(round) = quiz.rounds.get(ri)
+        {
+            write_round_header(&mut out, round);
+        }
+        write_question(&mut out, question);
+    }
+    if !quiz.tiebreakers.is_empty() {
+        out.push_str("\n== Tiebreaker\n");
+        for question in &quiz.tiebreakers {
+            out.push('\n');
+            write_question(&mut out, question);
+        }
+    }
+
+    out
+}
+
+fn write_front_matter(out: &mut String, metadata: &QuizMetadata) {
+    let mut fields: Vec<(&str, String)> = Vec::new();
+    if let Some(author) = &metadata.author {
+        fields.push(("author", single_line(author)));
+    }
+    if let Some(description) = &metadata.description {
+        fields.push(("description", single_line(description)));
+    }
+    if let Some(rule) = &metadata.scoring_rule {
+        fields.push(("scoring_rule", rule_name(rule).to_string()));
+    }
+    if let Some(seconds) = metadata.time_limit_sec {
+        fields.push(("time_limit", seconds.to_string()));
+    }
+    if metadata.shuffle_questions {
+        fields.push(("shuffle_questions", "true".into()));
+    }
+    if metadata.shuffle_options {
+        fields.push(("shuffle_options", "true".into()));
+    }
+    if !metadata.tags.is_empty() {
+        let tags: Vec<String> = metadata
+            .tags
+            .iter()
+            .map(|t| single_line(&t.replace(',', " ")))
+            .collect();
+        fields.push(("tags", tags.join(", ")));
+    }
+    if let Some(count) = metadata.min_options {
+        fields.push(("min_options", count.to_string()));
+    }
+    if let Some(count) = metadata.max_options {
+        fields.push(("max_options", count.to_string()));
+    }
+
+    if fields.is_empty() {
+        return;
+    }
+    out.push_str("---\n");
+    for (key, value) in fields {
+        let _ = writeln!(out, "{key}: {value}");
+    }
+    out.push_str("---\n\n");
+}
+
+/// Write a `=` line. A title ending in `]` gets an empty settings group so the
+/// parser does not read its tail as settings.
+fn write_round_header(out: &mut String, round: &Round) {
+    let title = escape(&single_line(&round.title));
+    let mut settings = Vec::new();
+    if let Some(seconds) = round.time_limit_sec {
+        settings.push(format!("time_limit: {seconds}"));
+    }
+    if let Some(rule) = &round.scoring_rule {
+        settings.push(format!("scoring_rule: {}", rule_name(rule)));
+    }
+    if settings.is_empty() && !title.ends_with(']') {
+        let _ = writeln!(out, "= {title}\n");
+    } else {
+        let _ = writeln!(out, "= {title} [{}]\n", settings.join(", "));
+    }
+}
+
+/// The name the text format can carry: a composite rule is written as its base.
+fn rule_name(rule: &ScoringRule) -> &str {
+    match rule {
+        ScoringRule::Composite(composite) => composite.base.name(),
+        rule => rule.name(),
+    }
+}
+
+fn write_question(out: &mut String, question: &Question) {
+    let marker = if question.is_poll() { "~" } else { "?" };
+    write_text(out, marker, &question.text);
+    if let Some(media) = &question.media {
+        let _ = writeln!(out, "@ {media}");
+    }
+    if !question.tags.is_empty() {
+        let tags: Vec<String> = question
+            .tags
+            .iter()
+            .map(|t| single_line(&t.replace(',', " ")))
+            .collect();
+        let _ = writeln!(out, "% {}", tags.join(", "));
+    }
+    if let Some(weight) = question.weight {
+        let _ = writeln!(out, "! {weight}");
+    }
+
+    for (i, option) in question.options.iter().enumerate() {
+        let marker = if !question.is_poll() && i == question.correct_index {
+            '*'
+        } else {
+            '-'
+        };
+        write_text(out, &marker.to_string(), &option.text);
+        if let Some(media) = &option.media {
+            let _ = writeln!(out, "@ {media}");
+        }
+    }
+
+    if let Some(explanation) = &question.explanation {
+        write_text(out, ">", explanation);
+    }
+}
+
+/// Write a directive line, with any further lines of `text` as `|` continuations.
+fn write_text(out: &mut String, marker: &str, text: &str) {
+    let mut lines = text.lines().map(str::trim_end);
+    let _ = writeln!(out, "{marker} {}", escape(lines.next().unwrap_or_default()));
+    for line in lines {
+        if line.is_empty() {
+            out.push_str("|\n");
+        } else {
+            let _ = writeln!(out, "| {line}");
+        }
+    }
+}
+
+/// Protect leading whitespace, which the parser would otherwise trim. The space
+/// written after every marker already keeps text that starts with a marker
+/// character from being read as part of the marker.
+fn escape(text: &str) -> String {
+    if needs_escape(text) {
+        format!("\\{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Titles and front-matter values are single-line, so line breaks inside them are
+/// folded into spaces.
+fn single_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,369 @@
+use crate::errors::{ParseError, ParseWarning};
+use crate::models::quiz::{
+    NO_CORRECT_INDEX, OptionLimits, POLL_WEIGHT_ERROR, ParseSettings, Question, Quiz, dedup_tags,
+    normalize_media_path, option_limit_violation, option_limits_conflict,
+    parse_quiz_with_source_map, round_layout_violation, time_limit_violation, validate_media_path,
+    weight_violation,
+};
+use crate::models::scoring_rule::ScoringRule;
+use crate::services::quiz_csv::parse_csv_quiz;
+use crate::services::quiz_lint::{SourceMap, content_warnings};
+
+/// Quiz document formats accepted by `POST /api/quiz`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizFormat {
+    /// The line-based `#`/`?`/`-`/`*` format.
+    Text,
+    Json,
+    Yaml,
+    /// A question bank with one question per row; see `quiz_csv`.
+    Csv,
+}
+
+impl QuizFormat {
+    /// Parse an explicit format name as sent in the upload's `format` field.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "text" | "txt" => Some(QuizFormat::Text),
+            "json" => Some(QuizFormat::Json),
+            "yaml" | "yml" => Some(QuizFormat::Yaml),
+            "csv" => Some(QuizFormat::Csv),
+            _ => None,
+        }
+    }
+
+    /// Guess the format from the file extension, falling back to sniffing the content.
+    pub fn detect(file_name: Option<&str>, content: &str) -> Self {
+        if let Some(format) = file_name
+            .and_then(|name| name.rsplit_once('.'))
+            .and_then(|(_, ext)| QuizFormat::from_name(ext))
+        {
+            return format;
+        }
+        if content.trim_start().starts_with('{') {
+            QuizFormat::Json
+        } else {
+            QuizFormat::Text
+        }
+    }
+
+    /// Whether `file_name` has an extension this format recognises.
+    pub fn is_quiz_file_name(file_name: &str) -> bool {
+        file_name
+            .rsplit_once('.')
+            .is_some_and(|(_, ext)| QuizFormat::from_name(ext).is_some())
+    }
+}
+
+/// Parse a quiz document in the given format. Structured formats go through the
+/// same validation rules as the text format, with errors located by document path.
+/// `file_name` supplies the title for CSV, which has nowhere to put one.
+pub fn parse_quiz_document(
+    format: QuizFormat,
+    content: &str,
+    file_name: Option<&str>,
+    default_time_limit: u64,
+) -> Result<Quiz, Vec<ParseError>> {
+    let settings = ParseSettings::with_time_limit(default_time_limit);
+    lint_quiz_document(format, content, file_name, &settings).quiz
+}
+
+/// The outcome of parsing a quiz document, with any non-fatal warnings.
+#[derive(Debug)]
+pub struct QuizReport {
+    pub quiz: Result<Quiz, Vec<ParseError>>,
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// `parse_quiz_document` with explicit server settings, plus parser and content
+/// warnings located in the source.
+pub fn lint_quiz_document(
+    format: QuizFormat,
+    content: &str,
+    file_name: Option<&str>,
+    settings: &ParseSettings,
+) -> QuizReport {
+    match format {
+        QuizFormat::Text => {
+            let parsed = parse_quiz_with_source_map(content, settings);
+            let source = SourceMap::Text {
+                content,
+                lines: &parsed.source_map,
+            };
+            report(parsed.result, parsed.warnings, &source)
+        }
+        QuizFormat::Csv => {
+            let title = title_from_file_name(file_name);
+            match parse_csv_quiz(content, &title, settings) {
+                Ok((quiz, rows)) => report(Ok(quiz), Vec::new(), &SourceMap::Csv { rows: &rows }),
+                Err(errors) => report(Err(errors), Vec::new(), &SourceMap::Csv { rows: &[] }),
+            }
+        }
+        QuizFormat::Json => {
+            let de = &mut serde_json::Deserializer::from_str(content);
+            let quiz = serde_path_to_error::deserialize(de)
+                .map_err(|e| {
+                    let line = e.inner().line();
+                    vec![located_serde_error(e.path(), line, e.inner().to_string())]
+                })
+                .and_then(|quiz| validate_quiz(quiz, settings));
+            report(quiz, Vec::new(), &SourceMap::Document)
+        }
+        QuizFormat::Yaml => {
+            let de = serde_yaml::Deserializer::from_str(content);
+            let quiz = serde_path_to_error::deserialize(de)
+                .map_err(|e| {
+                    let line = e.inner().location().map_or(0, |l| l.line());
+                    vec![located_serde_error(e.path(), line, e.inner().to_string())]
+                })
+                .and_then(|quiz| validate_quiz(quiz, settings));
+            report(quiz, Vec::new(), &SourceMap::Document)
+        }
+    }
+}
+
+fn report(
+    quiz: Result<Quiz, Vec<ParseError>>,
+    mut warnings: Vec<ParseWarning>,
+    source: &SourceMap,
+) -> QuizReport {
+    if let Ok(quiz) = &quiz {
+        warnings.extend(
+            content_warnings(quiz)
+                .into_iter()
+                .map(|(location, code, message)| source.warning(location, code, message)),
+        );
+    }
+    QuizReport { quiz, warnings }
+}
+
+/// `world_capitals.csv` becomes "world capitals".
+fn title_from_file_name(file_name: Option<&str>) -> String {
+    let stem = file_name
+        .map(|name| name.rsplit('/').next().unwrap_or(name))
+        .map(|name| name.rsplit_once('.').map_or(name, |(stem, _)| stem))
+        .unwrap_or_default();
+    let title = stem.replace(['_', '-'], " ").trim().to_string();
+    if title.is_empty() {
+        "Untitled quiz".to_string()
+    } else {
+        title
+    }
+}
+
+fn located_serde_error(
+    path: &serde_path_to_error::Path,
+    line: usize,
+    message: String,
+) -> ParseError {
+    let path = path.to_string();
+    let mut error = ParseError::new(line, message);
+    if path != "." {
+        error.path = Some(path);
+    }
+    error
+}
+
+/// Apply the text-format rules to a deserialized quiz and fill in defaults.
+fn validate_quiz(mut quiz: Quiz, settings: &ParseSettings) -> Result<Quiz, Vec<ParseError>> {
+    let mut errors: Vec<ParseError> = Vec::new();
+
+    quiz.title = quiz.title.trim().to_string();
+    if quiz.title.is_empty() {
+        errors.push(ParseError::at_path("title", "Quiz has no title"));
+    }
+
+    if let Some(seconds) = quiz.metadata.time_limit_sec
+        && let Some(message) = time_limit_violation(seconds)
+    {
+        errors.push(ParseError::at_path("metadata.time_limit_sec", message));
+    }
+    if let Some(Err(message)) = quiz
+        .metadata
+        .scoring_rule
+        .as_ref()
+        .map(ScoringRule::validate)
+    {
+        errors.push(ParseError::at_path("metadata.scoring_rule", message));
+    }
+    let default_time_limit = quiz
+        .metadata
+        .time_limit_sec
+        .unwrap_or(settings.default_time_limit);
+
+    let server_limits = settings.option_limits;
+    for (key, count) in [
+        ("min_options", quiz.metadata.min_options),
+        ("max_options", quiz.metadata.max_options),
+    ] {
+        if let Some(message) = count.and_then(|n| option_limit_violation(key, n, server_limits)) {
+            errors.push(ParseError::at_path(format!("metadata.{key}"), message));
+        }
+    }
+    if let Some(message) = option_limits_conflict(&quiz.metadata, server_limits) {
+        errors.push(ParseError::at_path("metadata", message));
+    }
+    let limits = quiz.metadata.option_limits(server_limits);
+
+    if quiz.questions.is_empty() {
+        errors.push(ParseError::at_path(
+            "questions",
+            "Quiz has no valid questions",
+        ));
+    }
+
+    for (ri, round) in quiz.rounds.iter_mut().enumerate() {
+        round.title = round.title.trim().to_string();
+        if round.title.is_empty() {
+            errors.push(ParseError::at_path(
+                format!("rounds[{ri}].title"),
+                "Round title is empty",
+            ));
+        }
+        if let Some(Err(message)) = round.scoring_rule.as_ref().map(ScoringRule::validate) {
+            errors.push(ParseError::at_path(
+                format!("rounds[{ri}].scoring_rule"),
+                message,
+            ));
+        }
+        if let Some(message) = round.time_limit_sec.and_then(time_limit_violation) {
+            errors.push(ParseError::at_path(
+                format!("rounds[{ri}].time_limit_sec"),
+                message,
+            ));
+        }
+    }
+    if let Some(message) = round_layout_violation(&quiz.rounds, quiz.questions.len()) {
+        errors.push(ParseError::at_path("rounds", message));
+    }
+    let round_time_limits: Vec<Option<u64>> = (0..quiz.questions.len())
+        .map(|qi| {
+            quiz.round_of(qi)
+                .and_then(|ri| quiz.rounds[ri].time_limit_sec)
+        })
+        .collect();
+
+    for (qi, question) in quiz.questions.iter_mut().enumerate() {
+        let time_limit = round_time_limits[qi].unwrap_or(default_time_limit);
+        validate_question(
+            question,
+            format!("questions[{qi}]"),
+            limits,
+            time_limit,
+            &mut errors,
+        );
+    }
+    for (ti, question) in quiz.tiebreakers.iter_mut().enumerate() {
+        let path = format!("tiebreakers[{ti}]");
+        if question.is_poll() {
+            errors.push(ParseError::at_path(
+                format!("{path}.kind"),
+                "Tiebreaker question cannot be a poll",
+            ));
+        }
+        validate_question(question, path, limits, default_time_limit, &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(quiz)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Trims and checks one question, filling in `time_limit` when it has none.
+fn validate_question(
+    question: &mut Question,
+    path: String,
+    limits: OptionLimits,
+    time_limit: u64,
+    errors: &mut Vec<ParseError>,
+) {
+    question.text = question.text.trim().to_string();
+    if question.text.is_empty() {
+        errors.push(ParseError::at_path(
+            format!("{path}.text"),
+            "Question text is empty",
+        ));
+    }
+
+    if let Some(message) = limits.violation(question.options.len()) {
+        errors.push(ParseError::at_path(format!("{path}.options"), message));
+    }
+    for (oi, option) in question.options.iter_mut().enumerate() {
+        option.text = option.text.trim().to_string();
+        if option.text.is_empty() {
+            errors.push(ParseError::at_path(
+                format!("{path}.options[{oi}].text"),
+                "Option text is empty",
+            ));
+        }
+        check_media(
+            &mut option.media,
+            format!("{path}.options[{oi}].media"),
+            errors,
+        );
+    }
+
+    if question.is_poll() {
+        question.correct_index = 0;
+    } else if question.correct_index == NO_CORRECT_INDEX {
+        errors.push(ParseError::at_path(
+            format!("{path}.correct_index"),
+            "Question has no correct_index",
+        ));
+    } else if question.correct_index >= question.options.len() {
+        errors.push(ParseError::at_path(
+            format!("{path}.correct_index"),
+            format!(
+                "correct_index {} does not refer to one of the {} options",
+                question.correct_index,
+                question.options.len()
+            ),
+        ));
+    }
+
+    if question
+        .explanation
+        .as_deref()
+        .is_some_and(|e| e.trim().is_empty())
+    {
+        errors.push(ParseError::at_path(
+            format!("{path}.explanation"),
+            "Explanation text is empty",
+        ));
+    }
+    check_media(&mut question.media, format!("{path}.media"), errors);
+
+    question.tags = dedup_tags(
+        question
+            .tags
+            .drain(..)
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty()),
+    );
+
+    if let Some(weight) = question.weight {
+        let message = if question.is_poll() {
+            Some(POLL_WEIGHT_ERROR.to_string())
+        } else {
+            weight_violation(weight)
+        };
+        if let Some(message) = message {
+            errors.push(ParseError::at_path(format!("{path}.weight"), message));
+        }
+    }
+
+    if question.time_limit_sec == 0 {
+        question.time_limit_sec = time_limit;
+    }
+}
+
+fn check_media(media: &mut Option<String>, path: String, errors: &mut Vec<ParseError>) {
+    if let Some(m) = media.as_mut() {
+        match validate_media_path(m.trim()) {
+            Ok(()) => *m = normalize_media_path(m.trim()),
+            Err(message) => errors.push(ParseError::at_path(path, message)),
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use std::fmt::Debug;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::models::quiz::Quiz;
+
+/// One saved revision of a library quiz. Revisions are numbered from 1 and never
+/// overwritten; saving a quiz again adds the next revision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredQuiz {
+    pub id: String,
+    pub version: u32,
+    /// Unix timestamp (seconds) of when this revision was saved.
+    pub saved_at: u64,
+    pub quiz: Quiz,
+}
+
+/// What `GET /api/quizzes` lists for each quiz, and for each revision of one.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuizSummary {
+    pub id: String,
+    pub version: u32,
+    pub saved_at: u64,
+    pub title: String,
+    pub question_count: usize,
+}
+
+impl StoredQuiz {
+    pub fn summary(&self) -> QuizSummary {
+        QuizSummary {
+            id: self.id.clone(),
+            version: self.version,
+            saved_at: self.saved_at,
+            title: self.quiz.title.clone(),
+            question_count: self.quiz.questions.len(),
+        }
+    }
+}
+
+/// Storage for quizzes that outlive a server restart, keyed by a host-chosen ID.
+///
+/// Methods may block on I/O; the API handlers call them on Tokio's blocking pool.
+pub trait QuizLibrary: Debug + Send + Sync {
+    /// The latest revision of every quiz, ordered by ID.
+    fn list(&self) -> io::Result<Vec<QuizSummary>>;
+    /// A given revision of a quiz, or the latest when `version` is `None`.
+    fn get(&self, id: &str, version: Option<u32>) -> io::Result<Option<StoredQuiz>>;
+    /// Every revision of a quiz, oldest first; empty if the quiz does not exist.
+    fn revisions(&self, id: &str) -> io::Result<Vec<QuizSummary>>;
+    /// Save `quiz` as the next revision of `id`.
+    fn save(&self, id: &str, quiz: Quiz) -> io::Result<StoredQuiz>;
+    /// Remove a quiz and all its revisions. Returns whether it existed.
+    fn delete(&self, id: &str) -> io::Result<bool>;
+}
+
+/// Library IDs double as directory names, so they are limited to lowercase
+/// letters, digits, `-` and `_`.
+pub fn is_valid_quiz_id(id: &str) -> bool {
+    (1..=64).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Keeps each quiz in `<root>/<id>/`, one `v<version>.json` file per revision.
+#[derive(Debug, Clone)]
+pub struct FsQuizLibrary {
+    root: PathBuf,
+}
+
+impl FsQuizLibrary {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn quiz_dir(&self, id: &str) -> PathBuf {
+        self.root.join(id)
+    }
+
+    /// Revision numbers present for `id`, ascending.
+    fn versions(&self, id: &str) -> io::Result<Vec<u32>> {
+        let entries = match fs::read_dir(self.quiz_dir(id)) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut versions = Vec::new();
+        for entry in entries {
+            let name = entry?.file_name();
+            if let Some(version) = name
+                .to_str()
+                .and_then(|n| n.strip_prefix('v')?.strip_suffix(".json"))
+                .and_then(|v| v.parse().ok())
+            {
+                versions.push(version);
+            }
+        }
+        versions.sort_unstable();
+        Ok(versions)
+    }
+
+    fn read(&self, id: &str, version: u32) -> io::Result<Option<StoredQuiz>> {
+        let path = self.quiz_dir(id).join(format!("v{version}.json"));
+        match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl QuizLibrary for FsQuizLibrary {
+    fn list(&self) -> io::Result<Vec<QuizSummary>> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut summaries = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let Some(id) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if !is_valid_quiz_id(&id) || !entry.file_type()?.is_dir() {
+                continue;
+            }
+            if let Some(stored) = self.get(&id, None)? {
+                summaries.push(stored.summary());
+            }
+        }
+        summaries.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(summaries)
+    }
+
+    fn get(&self, id: &str, version: Option<u32>) -> io::Result<Option<StoredQuiz>> {
+        match version {
+            Some(version) => self.read(id, version),
+            None => match self.versions(id)?.last() {
+                Some(&latest) => self.read(id, latest),
+                None => Ok(None),
+            },
+        }
+    }
+
+    fn revisions(&self, id: &str) -> io::Result<Vec<QuizSummary>> {
+        let mut revisions = Vec::new();
+        for version in self.versions(id)? {
+            if let Some(stored) = self.read(id, version)? {
+                revisions.push(stored.summary());
+            }
+        }
+        Ok(revisions)
+    }
+
+    fn save(&self, id: &str, mut quiz: Quiz) -> io::Result<StoredQuiz> {
+        let dir = self.quiz_dir(id);
+        fs::create_dir_all(&dir)?;
+        let mut version = self.versions(id)?.last().map_or(1, |v| v + 1);
+        // Linking fails if the revision exists, so concurrent saves of the same
+        // quiz take distinct revision numbers instead of overwriting each other.
+        loop {
+            let stored = StoredQuiz {
+                id: id.to_string(),
+                version,
+                saved_at: now(),
+                quiz,
+            };
+            match write_new(&dir.join(format!("v{version}.json")), &stored) {
+                Ok(()) => return Ok(stored),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    quiz = stored.quiz;
+                    version += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn delete(&self, id: &str) -> io::Result<bool> {
+        match fs::remove_dir_all(self.quiz_dir(id)) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Write a revision file that must not already exist. The file is written under a
+/// temporary name and linked into place, so readers never see a partial revision.
+fn write_new(path: &Path, stored: &StoredQuiz) -> io::Result<()> {
+    let json = serde_json::to_vec_pretty(stored).map_err(io::Error::other)?;
+    let temp = path.with_file_name(format!(".{}.tmp", uuid::Uuid::new_v4()));
+    let result = fs::File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(&json)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::hard_link(&temp, path));
+    let _ = fs::remove_file(&temp);
+    result
+}
+
+/// A library that lives only as long as the server, for embedders that keep their
+/// own copies of quizzes and want nothing written to disk. Pass it to
+/// [`build_router_with_library`](crate::build_router_with_library).
+#[derive(Debug, Default)]
+pub struct MemoryQuizLibrary {
+    quizzes: DashMap<String, Vec<StoredQuiz>>,
+}
+
+impl QuizLibrary for MemoryQuizLibrary {
+    fn list(&self) -> io::Result<Vec<QuizSummary>> {
+        let mut summaries: Vec<QuizSummary> = self
+            .quizzes
+            .iter()
+            .filter_map(|revisions| revisions.last().map(StoredQuiz::summary))
+            .collect();
+        summaries.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(summaries)
+    }
+
+    fn get(&self, id: &str, version: Option<u32>) -> io::Result<Option<StoredQuiz>> {
+        let Some(revisions) = self.quizzes.get(id) else {
+            return Ok(None);
+        };
+        Ok(match version {
+            Some(version) => revisions.iter().find(|r| r.version == version).cloned(),
+            None => revisions.last().cloned(),
+        })
+    }
+
+    fn revisions(&self, id: &str) -> io::Result<Vec<QuizSummary>> {
+        Ok(self
+            .quizzes
+            .get(id)
+            .map(|revisions| revisions.iter().map(StoredQuiz::summary).collect())
+            .unwrap_or_default())
+    }
+
+    fn save(&self, id: &str, quiz: Quiz) -> io::Result<StoredQuiz> {
+        let mut revisions = self.quizzes.entry(id.to_string()).or_default();
+        let stored = StoredQuiz {
+            id: id.to_string(),
+            version: revisions.last().map_or(1, |r| r.version + 1),
+            saved_at: now(),
+            quiz,
+        };
+        revisions.push(stored.clone());
+        Ok(stored)
+    }
+
+    fn delete(&self, id: &str) -> io::Result<bool> {
+        Ok(self.quizzes.remove(id).is_some())
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use crate::errors::{ParseWarning, TextSpan, WarningCode};
+use crate::models::quiz::{Quiz, TextSourceMap};
+
+/// Quizzes longer than this make for very long game sessions.
+pub const MANY_QUESTIONS: usize = 100;
+/// Character counts above which text is unlikely to fit on a player's screen.
+pub const LONG_QUESTION_CHARS: usize = 300;
+pub const LONG_OPTION_CHARS: usize = 120;
+pub const LONG_EXPLANATION_CHARS: usize = 600;
+
+/// The part of a quiz a warning is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizLocation {
+    Quiz,
+    Title,
+    Question(usize),
+    Option(usize, usize),
+    Explanation(usize),
+}
+
+/// How to turn a `QuizLocation` back into a place in the uploaded document.
+pub enum SourceMap<'a> {
+    /// Line numbers recorded while parsing the text format.
+    Text {
+        content: &'a str,
+        lines: &'a TextSourceMap,
+    },
+    /// The spreadsheet row of each question.
+    Csv { rows: &'a [usize] },
+    /// JSON/YAML: fields are identified by document path.
+    Document,
+}
+
+impl SourceMap<'_> {
+    pub fn warning(
+        &self,
+        location: QuizLocation,
+        code: WarningCode,
+        message: String,
+    ) -> ParseWarning {
+        let mut warning = ParseWarning::new(code, 0, message);
+        match self {
+            SourceMap::Text { content, lines } => {
+                let line = match location {
+                    QuizLocation::Quiz | QuizLocation::Title => Some(lines.title),
+                    QuizLocation::Question(qi) => lines.questions.get(qi).map(|q| q.line),
+                    QuizLocation::Option(qi, oi) => lines
+                        .questions
+                        .get(qi)
+                        .and_then(|q| q.options.get(oi).copied()),
+                    QuizLocation::Explanation(qi) => {
+                        lines.questions.get(qi).and_then(|q| q.explanation)
+                    }
+                };
+                if let Some(line) = line {
+                    warning.line = line;
+                    warning.span = TextSpan::of_line(content, line);
+                }
+            }
+            SourceMap::Csv { rows } => {
+                warning.row = match location {
+                    QuizLocation::Question(qi)
+                    | QuizLocation::Option(qi, _)
+                    | QuizLocation::Explanation(qi) => rows.get(qi).copied(),
+                    QuizLocation::Quiz | QuizLocation::Title => None,
+                };
+            }
+            SourceMap::Document => warning.path = Some(document_path(location)),
+        }
+        warning
+    }
+}
+
+fn document_path(location: QuizLocation) -> String {
+    match location {
+        QuizLocation::Quiz => "questions".to_string(),
+        QuizLocation::Title => "title".to_string(),
+        QuizLocation::Question(qi) => format!("questions[{qi}].text"),
+        QuizLocation::Option(qi, oi) => format!("questions[{qi}].options[{oi}].text"),
+        QuizLocation::Explanation(qi) => format!("questions[{qi}].explanation"),
+    }
+}
+
+/// Format-independent checks on a parsed quiz: duplicates, very long text and
+/// whitespace characters that are usually copy-paste accidents.
+pub fn content_warnings(quiz: &Quiz) -> Vec<(QuizLocation, WarningCode, String)> {
+    let mut warnings = Vec::new();
+
+    if quiz.questions.len() > MANY_QUESTIONS {
+        warnings.push((
+            QuizLocation::Quiz,
+            WarningCode::TooManyQuestions,
+            format!(
+                "Quiz has more than {MANY_QUESTIONS} questions. This may result in very long game sessions."
+            ),
+        ));
+    }
+    check_whitespace(&mut warnings, QuizLocation::Title, &quiz.title);
+
+    let mut seen_questions: HashMap<String, usize> = HashMap::new();
+    for (qi, question) in quiz.questions.iter().enumerate() {
+        let location = QuizLocation::Question(qi);
+        if let Some(first) = seen_questions.get(&normalize(&question.text)) {
+            warnings.push((
+                location,
+                WarningCode::DuplicateQuestion,
+                format!("Question {} duplicates question {}", qi + 1, first + 1),
+            ));
+        } else {
+            seen_questions.insert(normalize(&question.text), qi);
+        }
+        check_length(
+            &mut warnings,
+            location,
+            "Question",
+            &question.text,
+            LONG_QUESTION_CHARS,
+        );
+        check_whitespace(&mut warnings, location, &question.text);
+
+        let mut seen_options: HashMap<String, usize> = HashMap::new();
+        for (oi, option) in question.options.iter().enumerate() {
+            let location = QuizLocation::Option(qi, oi);
+            if let Some(first) = seen_options.get(&normalize(&option.text)) {
+                warnings.push((
+                    location,
+                    WarningCode::DuplicateOption,
+                    format!(
+                        "Option {} of question {} duplicates option {}",
+                        oi + 1,
+                        qi + 1,
+                        first + 1
+                    ),
+                ));
+            } else {
+                seen_options.insert(normalize(&option.text), oi);
+            }
+            check_length(
+                &mut warnings,
+                location,
+                "Option",
+                &option.text,
+                LONG_OPTION_CHARS,
+            );
+            check_whitespace(&mut warnings, location, &option.text);
+        }
+
+        if let Some(explanation) = &question.explanation {
+            let location = QuizLocation::Explanation(qi);
+            check_length(
+                &mut warnings,
+                location,
+                "Explanation",
+                explanation,
+                LONG_EXPLANATION_CHARS,
+            );
+            check_whitespace(&mut warnings, location, explanation);
+        }
+    }
+
+    warnings
+}
+
+/// Case- and spacing-insensitive form used to spot duplicates.
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn check_length(
+    warnings: &mut Vec<(QuizLocation, WarningCode, String)>,
+    location: QuizLocation,
+    what: &str,
+    text: &str,
+    limit: usize,
+) {
+    let chars = text.chars().count();
+    if chars > limit {
+        warnings.push((
+            location,
+            WarningCode::LongText,
+            format!("{what} is {chars} characters long; more than {limit} may not fit on screen"),
+        ));
+    }
+}
+
+fn check_whitespace(
+    warnings: &mut Vec<(QuizLocation, WarningCode, String)>,
+    location: QuizLocation,
+    text: &str,
+) {
+    if let Some(c) = text.chars().find(|&c| is_suspicious_whitespace(c)) {
+        warnings.push((
+            location,
+            WarningCode::SuspiciousWhitespace,
+            format!(
+                "Text contains an invisible or non-standard space (U+{:04X})",
+                c as u32
+            ),
+        ));
+    }
+}
+
+/// Non-breaking, zero-width and other unusual spaces, typically pasted from a
+/// word processor. Tabs and line breaks are left alone for code snippets.
+fn is_suspicious_whitespace(c: char) -> bool {
+    const SPACES: &[char] = &[
+        '\u{00A0}', '\u{1680}', '\u{202F}', '\u{205F}', '\u{3000}', '\u{FEFF}', '\u{0B}', '\u{0C}',
+    ];
+    SPACES.contains(&c) || ('\u{2000}'..='\u{200B}').contains(&c)
+}
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+use crate::models::scoring_rule::{
+    CustomRule, MAX_POINTS_LIMIT, MAX_SCORE, MAX_STREAK_INCREMENT, Scorer, ScoringContext,
+    ScoringOutcome, ScoringRule,
+};
+use crate::models::session::MAX_TIME_LIMIT_SEC;
+
+/// Every scoring rule a host may pick, by name: the built-in rules plus any
+/// [`Scorer`]s the embedding application registers.
+#[derive(Clone)]
+pub struct ScorerRegistry {
+    scorers: Vec<Arc<dyn Scorer>>,
+}
+
+impl Default for ScorerRegistry {
+    /// The built-in rules, in the order hosts are offered them.
+    fn default() -> Self {
+        let max_points = json!({
+            "type": "integer",
+            "minimum": 1,
+            "maximum": MAX_POINTS_LIMIT,
+            "default": MAX_SCORE,
+        });
+        let streak_increment = json!({
+            "type": "number",
+            "minimum": 0,
+            "maximum": MAX_STREAK_INCREMENT,
+            "default": 0.5,
+        });
+        let streak_cap = json!({ "type": "number", "minimum": 1 });
+        let schedule = json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 1, "maximum": MAX_POINTS_LIMIT },
+            "minItems": 1,
+        });
+        let builtins = [
+            (
+                ScoringRule::SteppedDecay,
+                "Stepped Decay",
+                json!({
+                    "max_points": max_points,
+                    "step_sec": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": MAX_TIME_LIMIT_SEC,
+                        "default": 5,
+                    },
+                }),
+            ),
+            (
+                ScoringRule::LinearDecay,
+                "Linear Decay",
+                json!({ "max_points": max_points }),
+            ),
+            (
+                ScoringRule::FixedScore,
+                "Fixed Score",
+                json!({ "max_points": max_points }),
+            ),
+            (
+                ScoringRule::StreakBonus,
+                "Streak Bonus",
+                json!({
+                    "max_points": max_points,
+                    "streak_increment": streak_increment,
+                    "streak_cap": streak_cap,
+                }),
+            ),
+            (
+                ScoringRule::PositionRace,
+                "Position Race",
+                json!({ "schedule": schedule }),
+            ),
+            (
+                ScoringRule::composite(ScoringRule::SteppedDecay, Vec::new()),
+                "Composite",
+                json!({
+                    "base": {
+                        "description": "Any rule but composite, by name or as an object",
+                        "type": ["string", "object"],
+                    },
+                    "modifiers": {
+                        "type": "array",
+                        "items": {
+                            "oneOf": [
+                                {
+                                    "properties": {
+                                        "modifier": { "const": "streak" },
+                                        "streak_increment": streak_increment,
+                                        "streak_cap": streak_cap,
+                                    },
+                                },
+                                {
+                                    "properties": {
+                                        "modifier": { "const": "position_bonus" },
+                                        "schedule": schedule,
+                                    },
+                                },
+                                {
+                                    "properties": {
+                                        "modifier": { "const": "wrong_answer_penalty" },
+                                        "points": {
+                                            "type": "integer",
+                                            "minimum": 0,
+                                            "maximum": MAX_POINTS_LIMIT,
+                                            "default": MAX_SCORE / 4,
+                                        },
+                                    },
+                                },
+                            ],
+                            "required": ["modifier"],
+                        },
+                    },
+                }),
+            ),
+        ];
+        Self {
+            scorers: builtins
+                .into_iter()
+                .map(|(rule, display_name, properties)| {
+                    Arc::new(BuiltinScorer {
+                        rule,
+                        display_name,
+                        params_schema: json!({ "type": "object", "properties": properties }),
+                    }) as Arc<dyn Scorer>
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ScorerRegistry {
+    /// Add a custom rule, listed after those already registered. Fails if a
+    /// rule with the same name is already registered, built-ins included.
+    pub fn register(&mut self, scorer: impl Scorer + 'static) -> Result<&mut Self, String> {
+        if self.get(scorer.name()).is_some() {
+            return Err(format!(
+                "scoring rule '{}' is already registered",
+                scorer.name()
+            ));
+        }
+        self.scorers.push(Arc::new(scorer));
+        Ok(self)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn Scorer>> {
+        self.scorers.iter().find(|scorer| scorer.name() == name)
+    }
+
+    /// Every registered rule, built-ins first.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Scorer> {
+        self.scorers.iter().map(|scorer| scorer.as_ref())
+    }
+
+    /// The validated rule a `set_scoring_rule` payload asks for: a rule name, or
+    /// an object naming the rule under `rule` alongside its parameters.
+    pub fn resolve(&self, payload: &Value) -> Result<ScoringRule, String> {
+        let name = payload.as_str().or_else(|| payload["rule"].as_str());
+        let rule = match name {
+            Some(name) if !is_builtin(name) => {
+                let scorer = self.get(name).ok_or_else(|| {
+                    format!(
+                        "unknown scoring rule '{name}', expected one of {}",
+                        self.iter().map(|s| s.name()).collect::<Vec<_>>().join(", ")
+                    )
+                })?;
+                ScoringRule::Custom(CustomRule::new(scorer.clone(), params_of(payload)))
+            }
+            _ => serde_json::from_value(payload.clone()).map_err(|e| e.to_string())?,
+        };
+        rule.validate()?;
+        Ok(rule)
+    }
+}
+
+/// A built-in rule's entry in the registry, so it is listed the same way as a
+/// custom one. `resolve` reads a built-in rule's parameters into a
+/// [`ScoringRule`] itself, so this scorer only ever scores the defaults.
+struct BuiltinScorer {
+    /// The rule with default parameters.
+    rule: ScoringRule,
+    display_name: &'static str,
+    params_schema: Value,
+}
+
+impl Scorer for BuiltinScorer {
+    fn name(&self) -> &str {
+        self.rule.name()
+    }
+
+    fn display_name(&self) -> &str {
+        self.display_name
+    }
+
+    fn params_schema(&self) -> Value {
+        self.params_schema.clone()
+    }
+
+    fn score(&self, _params: &Value, ctx: &ScoringContext) -> ScoringOutcome {
+        self.rule.score_unweighted(ctx)
+    }
+
+    fn uses_streak(&self) -> bool {
+        self.rule.uses_streak()
+    }
+}
+
+fn is_builtin(name: &str) -> bool {
+    ScoringRule::NAMES.contains(&name) || name == "composite"
+}
+
+/// A payload's fields other than `rule`; an empty object for a bare name.
+fn params_of(payload: &Value) -> Value {
+    match payload {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .filter(|(key, _)| *key != "rule")
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        ),
+        _ => json!({}),
+    }
+}
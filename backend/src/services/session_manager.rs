@@ -5,6 +5,7 @@ use tokio::sync::RwLock;
 
 use crate::config::AppConfig;
 use crate::errors::AppError;
+use crate::models::asset::QuizAssets;
 use crate::models::quiz::Quiz;
 use crate::models::session::GameSession;
 
@@ -14,6 +15,7 @@ pub type SharedSession = Arc<RwLock<GameSession>>;
 pub struct SessionManager {
     sessions: Arc<DashMap<String, SharedSession>>,
     quizzes: Arc<DashMap<String, Quiz>>,
+    quiz_assets: Arc<DashMap<String, Arc<QuizAssets>>>,
     config: AppConfig,
 }
 
@@ -22,6 +24,7 @@ impl SessionManager {
         Self {
             sessions: Arc::new(DashMap::new()),
             quizzes: Arc::new(DashMap::new()),
+            quiz_assets: Arc::new(DashMap::new()),
             config,
         }
     }
@@ -34,6 +37,14 @@ impl SessionManager {
         self.quizzes.get(quiz_id).map(|q| q.clone())
     }
 
+    pub fn store_quiz_assets(&self, quiz_id: String, assets: Arc<QuizAssets>) {
+        self.quiz_assets.insert(quiz_id, assets);
+    }
+
+    pub fn get_quiz_assets(&self, quiz_id: &str) -> Option<Arc<QuizAssets>> {
+        self.quiz_assets.get(quiz_id).map(|a| a.clone())
+    }
+
     pub fn create_session(&self, quiz: Quiz) -> Result<SharedSession, AppError> {
         if self.sessions.len() >= self.config.max_sessions {
             return Err(AppError::MaxSessionsReached);
@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use axum::Router;
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use http_body_util::BodyExt;
 use tower::ServiceExt;
 
-use quiz_server::config::AppConfig;
+use quiz_server::config::{AppConfig, DEFAULT_QUIZ_LIBRARY_DIR};
+use quiz_server::models::quiz::OptionLimits;
+use quiz_server::services::quiz_library::MemoryQuizLibrary;
+use quiz_server::services::scorer_registry::ScorerRegistry;
 use quiz_server::services::session_manager::SessionManager;
 
 fn test_config() -> AppConfig {
@@ -14,14 +19,25 @@ fn test_config() -> AppConfig {
         max_players: 50,
         question_time_sec: 20,
         reconnect_timeout_sec: 120,
+        max_upload_bytes: 10 * 1024 * 1024,
         static_dir: None,
+        option_limits: OptionLimits::default(),
+        quiz_library_dir: DEFAULT_QUIZ_LIBRARY_DIR.into(),
     }
 }
 
 fn test_app() -> Router {
-    let config = test_config();
+    app_with_config(test_config())
+}
+
+fn app_with_config(config: AppConfig) -> Router {
     let session_manager = SessionManager::new(config.clone());
-    quiz_server::build_router(session_manager, config)
+    quiz_server::build_router_with_library(
+        session_manager,
+        config,
+        ScorerRegistry::default(),
+        Arc::new(MemoryQuizLibrary::default()),
+    )
 }
 
 // === POST /api/quiz ===
@@ -267,3 +283,745 @@ async fn get_session_invalid_code_returns_404() {
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
     assert_eq!(json["error"], "session_not_found");
 }
+
+// Helper: POST a text quiz file, return (status, json body)
+async fn post_quiz(app: &Router, quiz_content: &str) -> (StatusCode, serde_json::Value) {
+    post_quiz_file(app, "quiz.txt", quiz_content.as_bytes()).await
+}
+
+// Helper: POST arbitrary bytes as the quiz_file field, return (status, json body)
+async fn post_quiz_file(
+    app: &Router,
+    filename: &str,
+    content: &[u8],
+) -> (StatusCode, serde_json::Value) {
+    post_quiz_form(app, filename, content, None).await
+}
+
+// Helper: POST the quiz_file field plus an optional format field
+async fn post_quiz_form(
+    app: &Router,
+    filename: &str,
+    content: &[u8],
+    format: Option<&str>,
+) -> (StatusCode, serde_json::Value) {
+    send_quiz_form(app, "POST", "/api/quiz", filename, content, format).await
+}
+
+// Helper: send an upload form to any quiz endpoint
+async fn send_quiz_form(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    filename: &str,
+    content: &[u8],
+    format: Option<&str>,
+) -> (StatusCode, serde_json::Value) {
+    let boundary = "----TestBoundary";
+    let mut body = Vec::new();
+    if let Some(format) = format {
+        body.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"format\"\r\n\r\n{format}\r\n"
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"quiz_file\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn upload_preview_flags_questions_with_explanations() {
+    let app = test_app();
+
+    let (status, json) = post_quiz(
+        &app,
+        "# Explained\n? Q1\n- A\n* B\n> Because B.\n? Q2\n* X\n- Y\n",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["preview"][0]["has_explanation"], true);
+    assert_eq!(json["preview"][1]["has_explanation"], false);
+}
+
+// === Quiz bundles (zip with media) ===
+
+fn make_bundle(files: &[(&str, &[u8])]) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, data) in files {
+        writer
+            .start_file(*name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(data).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+async fn create_session_for(app: &Router, quiz_id: &str) -> String {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/sessions")
+                .header("Content-Type", "application/json")
+                .body(Body::from(format!(r#"{{"quiz_id":"{}"}}"#, quiz_id)))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body_bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+    json["join_code"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn upload_bundle_serves_assets_under_session_url() {
+    let app = test_app();
+    let quiz =
+        "# Animals\n? Which animal is this?\n@ images/cat.png\n- Dog\n* Cat\n@ sounds/meow.mp3\n";
+    let bundle = make_bundle(&[
+        ("quiz.txt", quiz.as_bytes()),
+        ("images/cat.png", b"\x89PNG fake"),
+        ("sounds/meow.mp3", b"ID3 fake"),
+    ]);
+
+    let (status, json) = post_quiz_file(&app, "animals.zip", &bundle).await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json["asset_count"], 2);
+    assert_eq!(json["preview"][0]["has_media"], true);
+
+    let join_code = create_session_for(&app, json["quiz_id"].as_str().unwrap()).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/sessions/{join_code}/assets/images/cat.png"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"\x89PNG fake");
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/sessions/{join_code}/assets/images/dog.png"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn upload_bundle_with_missing_reference_returns_400_with_line() {
+    let app = test_app();
+    let quiz = "# Animals\n? Which animal?\n- Dog\n* Cat\n@ images/cat.png\n";
+    let bundle = make_bundle(&[("quiz.txt", quiz.as_bytes())]);
+
+    let (status, json) = post_quiz_file(&app, "animals.zip", &bundle).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_quiz_file");
+    assert_eq!(json["messages"][0]["line"], 5);
+    assert!(
+        json["messages"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("images/cat.png")
+    );
+}
+
+#[tokio::test]
+async fn upload_text_quiz_with_media_reference_returns_400() {
+    let app = test_app();
+    let (status, json) = post_quiz(&app, "# Q\n? Which?\n@ cat.png\n- A\n* B\n").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_quiz_file");
+}
+
+#[tokio::test]
+async fn upload_bundle_with_unsupported_file_returns_415() {
+    let app = test_app();
+    let bundle = make_bundle(&[
+        ("quiz.txt", b"# Q\n? Q1\n- A\n* B\n"),
+        ("payload.js", b"alert(1)"),
+    ]);
+
+    let (status, json) = post_quiz_file(&app, "quiz.zip", &bundle).await;
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(json["error"], "unsupported_media_type");
+}
+
+#[tokio::test]
+async fn upload_bundle_without_quiz_file_returns_400() {
+    let app = test_app();
+    let bundle = make_bundle(&[("cat.png", b"png")]);
+
+    let (status, json) = post_quiz_file(&app, "quiz.zip", &bundle).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_upload");
+}
+
+#[tokio::test]
+async fn upload_over_size_limit_returns_413() {
+    let config = AppConfig {
+        max_upload_bytes: 1024,
+        ..test_config()
+    };
+    let app = quiz_server::build_router(SessionManager::new(config.clone()), config);
+    let bundle = make_bundle(&[
+        ("quiz.txt", b"# Q\n? Q1\n- A\n* B\n"),
+        ("big.png", &[0u8; 4096]),
+    ]);
+
+    let (status, json) = post_quiz_file(&app, "quiz.zip", &bundle).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(json["error"], "upload_too_large");
+}
+
+// === JSON / YAML import ===
+
+const JSON_QUIZ: &str = r#"{
+  "title": "Capitals",
+  "questions": [
+    {
+      "text": "Capital of France?",
+      "options": [{ "text": "London" }, { "text": "Paris" }],
+      "correct_index": 1
+    }
+  ]
+}"#;
+
+#[tokio::test]
+async fn upload_json_quiz_detected_by_extension() {
+    let app = test_app();
+    let (status, json) = post_quiz_file(&app, "quiz.json", JSON_QUIZ.as_bytes()).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["title"], "Capitals");
+    assert_eq!(json["question_count"], 1);
+    assert_eq!(json["preview"][0]["option_count"], 2);
+}
+
+#[tokio::test]
+async fn upload_yaml_quiz_detected_by_extension() {
+    let app = test_app();
+    let yaml = "title: Capitals\nquestions:\n  - text: Capital of Italy?\n    options:\n      - text: Rome\n      - text: Milan\n    correct_index: 0\n";
+    let (status, json) = post_quiz_file(&app, "quiz.yml", yaml.as_bytes()).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["question_count"], 1);
+}
+
+#[tokio::test]
+async fn upload_with_explicit_format_overrides_extension() {
+    let app = test_app();
+    let (status, json) =
+        post_quiz_form(&app, "upload.dat", JSON_QUIZ.as_bytes(), Some("json")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["title"], "Capitals");
+}
+
+#[tokio::test]
+async fn upload_with_unknown_format_returns_400() {
+    let app = test_app();
+    let (status, json) = post_quiz_form(&app, "quiz.txt", b"# Q\n", Some("xml")).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_upload");
+}
+
+#[tokio::test]
+async fn upload_invalid_json_quiz_reports_document_path() {
+    let app = test_app();
+    let content = r#"{"title": "Q", "questions": [{"text": "Q1", "options": [{"text": "A"}], "correct_index": 0}]}"#;
+    let (status, json) = post_quiz_file(&app, "quiz.json", content.as_bytes()).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_quiz_file");
+    assert_eq!(json["messages"][0]["path"], "questions[0].options");
+}
+
+#[tokio::test]
+async fn upload_json_bundle_reports_missing_media_by_path() {
+    let app = test_app();
+    let quiz = r#"{"title": "Q", "questions": [{"text": "Q1", "media": "cat.png", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1}]}"#;
+    let bundle = make_bundle(&[("quiz.json", quiz.as_bytes())]);
+
+    let (status, json) = post_quiz_file(&app, "quiz.zip", &bundle).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["messages"][0]["path"], "questions[0].media");
+}
+
+#[tokio::test]
+async fn upload_csv_quiz_reports_row_and_column() {
+    let app = test_app();
+    let csv = "Question,Option A,Option B,Correct\nCapital of France?,London,Paris,B\n";
+    let (status, json) = post_quiz_file(&app, "capitals.csv", csv.as_bytes()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["title"], "capitals");
+
+    let csv = "Question,Option A,Option B,Correct\nCapital of France?,London,Paris,E\n";
+    let (status, json) = post_quiz_file(&app, "capitals.csv", csv.as_bytes()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["messages"][0]["row"], 2);
+    assert_eq!(json["messages"][0]["column"], "Correct");
+}
+
+// === Export ===
+
+#[tokio::test]
+async fn export_quiz_returns_text_format() {
+    let app = test_app();
+    let csv = "Question,Option A,Option B,Correct\nCapital of France?,London,Paris,B\n";
+    let (_, json) = post_quiz_file(&app, "world_capitals.csv", csv.as_bytes()).await;
+    let quiz_id = json["quiz_id"].as_str().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/quiz/{quiz_id}/export"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-disposition"],
+        "attachment; filename=\"world-capitals.txt\""
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(
+        std::str::from_utf8(&body).unwrap(),
+        "# world capitals\n\n? Capital of France?\n- London\n* Paris\n"
+    );
+}
+
+#[tokio::test]
+async fn export_unknown_quiz_returns_404() {
+    let app = test_app();
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/quiz/nope/export")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// === GET /api/scoring-rules ===
+
+#[tokio::test]
+async fn scoring_rules_lists_builtins_with_parameter_schemas() {
+    let response = test_app()
+        .oneshot(
+            Request::builder()
+                .uri("/api/scoring-rules")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let rules = json.as_array().unwrap();
+    assert_eq!(rules.len(), 6);
+    assert_eq!(rules[1]["name"], "linear_decay");
+    assert_eq!(rules[1]["display_name"], "Linear Decay");
+    assert_eq!(
+        rules[1]["params_schema"]["properties"]["max_points"]["maximum"],
+        10_000
+    );
+}
+
+// === GET /api/info ===
+
+#[tokio::test]
+async fn server_info_reports_configured_limits() {
+    let app = app_with_config(AppConfig {
+        option_limits: OptionLimits::new(3, 6).unwrap(),
+        ..test_config()
+    });
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/info")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        json["option_limits"],
+        serde_json::json!({"min": 3, "max": 6})
+    );
+    assert_eq!(
+        json["time_limit_sec"],
+        serde_json::json!({"min": 10, "max": 60, "default": 20})
+    );
+    assert_eq!(json["max_upload_bytes"], 10 * 1024 * 1024);
+}
+
+#[tokio::test]
+async fn upload_applies_configured_option_limits() {
+    let app = app_with_config(AppConfig {
+        option_limits: OptionLimits::new(2, 6).unwrap(),
+        ..test_config()
+    });
+    let six = "# Quiz\n? Q\n- A\n- B\n- C\n- D\n- E\n* F\n";
+    let (status, _) = post_quiz(&app, six).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let seven = "# Quiz\n? Q\n- A\n- B\n- C\n- D\n- E\n- F\n* G\n";
+    let (status, json) = post_quiz(&app, seven).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        json["messages"][0]["message"],
+        "Question has 7 options, maximum is 6"
+    );
+}
+
+// === Quiz library ===
+
+// Helper: send a request with an optional JSON body, return (status, json body or null)
+async fn send_json(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => request
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    };
+    let response = app.clone().oneshot(request.unwrap()).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+async fn save_library_quiz(
+    app: &Router,
+    id: &str,
+    content: &str,
+) -> (StatusCode, serde_json::Value) {
+    let uri = format!("/api/quizzes/{id}");
+    send_quiz_form(app, "PUT", &uri, "quiz.txt", content.as_bytes(), None).await
+}
+
+#[tokio::test]
+async fn library_saves_versioned_revisions() {
+    let app = test_app();
+
+    let (status, json) = save_library_quiz(
+        &app,
+        "capitals",
+        "# Capitals\n? France?\n- London\n* Paris\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["quiz"]["version"], 1);
+    assert!(json["warnings"].is_array());
+
+    let (status, json) = save_library_quiz(
+        &app,
+        "capitals",
+        "# Capitals\n? France?\n- London\n* Paris\n? Italy?\n* Rome\n- Milan\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["quiz"]["version"], 2);
+
+    let (status, json) = send_json(&app, "GET", "/api/quizzes", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let quizzes = json["quizzes"].as_array().unwrap();
+    assert_eq!(quizzes.len(), 1);
+    assert_eq!(quizzes[0]["id"], "capitals");
+    assert_eq!(quizzes[0]["title"], "Capitals");
+    assert_eq!(quizzes[0]["question_count"], 2);
+    assert_eq!(quizzes[0]["version"], 2);
+
+    let (status, json) = send_json(&app, "GET", "/api/quizzes/capitals", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["version"], 2);
+    assert_eq!(json["quiz"]["questions"].as_array().unwrap().len(), 2);
+
+    let (_, json) = send_json(&app, "GET", "/api/quizzes/capitals?version=1", None).await;
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["quiz"]["questions"].as_array().unwrap().len(), 1);
+
+    let (_, json) = send_json(&app, "GET", "/api/quizzes/capitals/revisions", None).await;
+    let versions: Vec<_> = json["revisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["version"].as_u64().unwrap())
+        .collect();
+    assert_eq!(versions, [1, 2]);
+}
+
+#[tokio::test]
+async fn library_delete_removes_all_revisions() {
+    let app = test_app();
+    save_library_quiz(&app, "weekly", "# Weekly\n? Q\n- A\n* B\n").await;
+
+    let (status, _) = send_json(&app, "DELETE", "/api/quizzes/weekly", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    for uri in ["/api/quizzes/weekly", "/api/quizzes/weekly/revisions"] {
+        let (status, json) = send_json(&app, "GET", uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json["error"], "quiz_not_found");
+    }
+    let (status, _) = send_json(&app, "DELETE", "/api/quizzes/weekly", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn library_rejects_invalid_ids_and_quizzes() {
+    let app = test_app();
+
+    let (status, json) = save_library_quiz(&app, "Capitals", "# Q\n? Q\n- A\n* B\n").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_request");
+
+    let (status, json) = save_library_quiz(&app, "broken", "# Broken\n? Q\n- A\n").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_quiz_file");
+    let (status, _) = send_json(&app, "GET", "/api/quizzes/broken", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn library_is_stored_in_configured_dir() {
+    let dir = std::env::temp_dir().join(format!("quiz-library-{}", uuid::Uuid::new_v4()));
+    let config = AppConfig {
+        quiz_library_dir: dir.to_string_lossy().into_owned(),
+        ..test_config()
+    };
+    let app = quiz_server::build_router(SessionManager::new(config.clone()), config.clone());
+    let (status, _) = save_library_quiz(&app, "weekly", "# Weekly\n? Q\n- A\n* B\n").await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let restarted = quiz_server::build_router(SessionManager::new(config.clone()), config);
+    let (status, json) = send_json(&restarted, "GET", "/api/quizzes/weekly", None).await;
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["quiz"]["title"], "Weekly");
+}
+
+#[tokio::test]
+async fn create_session_from_library_quiz() {
+    let app = test_app();
+    save_library_quiz(
+        &app,
+        "capitals",
+        "# Capitals v1\n? France?\n- London\n* Paris\n",
+    )
+    .await;
+    save_library_quiz(
+        &app,
+        "capitals",
+        "# Capitals v2\n? France?\n- London\n* Paris\n",
+    )
+    .await;
+
+    for (version, title) in [(None, "Capitals v2"), (Some(1), "Capitals v1")] {
+        let (status, json) = send_json(
+            &app,
+            "POST",
+            "/api/sessions",
+            Some(serde_json::json!({ "library_id": "capitals", "version": version })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/api/sessions/{}", json["join_code"].as_str().unwrap());
+        let (_, session) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(session["quiz_title"], title);
+    }
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        "/api/sessions",
+        Some(serde_json::json!({ "library_id": "missing" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, json) =
+        send_json(&app, "POST", "/api/sessions", Some(serde_json::json!({}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_request");
+}
+
+#[tokio::test]
+async fn create_session_draws_questions_from_bank() {
+    let app = test_app();
+    let mut bank = String::from("# Bank\n");
+    for i in 0..12 {
+        let tag = if i % 2 == 0 { "even" } else { "odd" };
+        bank.push_str(&format!("? Question {i}\n% {tag}\n- A\n* B\n"));
+    }
+    let (_, json) = post_quiz(&app, &bank).await;
+    let quiz_id = json["quiz_id"].as_str().unwrap().to_string();
+
+    let body = serde_json::json!({
+        "quiz_id": quiz_id,
+        "question_count": 5,
+        "tag_quotas": { "odd": 3 },
+        "draw_seed": 99,
+    });
+    let (status, first) = send_json(&app, "POST", "/api/sessions", Some(body.clone())).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(first["total_questions"], 5);
+    assert_eq!(first["draw"]["seed"], 99);
+    assert_eq!(first["draw"]["bank_size"], 12);
+    let drawn = first["draw"]["questions"].as_array().unwrap();
+    assert_eq!(drawn.len(), 5);
+    let odd = drawn.iter().filter(|q| q["tags"][0] == "odd").count();
+    assert!(odd >= 3, "{drawn:?}");
+    for q in drawn {
+        assert_eq!(q["text"], format!("Question {}", q["bank_index"]));
+    }
+
+    let (_, second) = send_json(&app, "POST", "/api/sessions", Some(body)).await;
+    assert_eq!(second["draw"], first["draw"]);
+
+    let (status, json) = send_json(
+        &app,
+        "POST",
+        "/api/sessions",
+        Some(serde_json::json!({ "quiz_id": quiz_id, "question_count": 13 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "invalid_request");
+}
+
+// === Lint ===
+
+async fn post_lint(
+    app: &Router,
+    filename: &str,
+    content: &[u8],
+) -> (StatusCode, serde_json::Value) {
+    let boundary = "----TestBoundary";
+    let mut body = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"quiz_file\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/quiz/lint")
+                .header(
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn lint_reports_errors_and_warnings_with_ranges() {
+    let app = test_app();
+    let (status, json) = post_lint(&app, "quiz.txt", b"# Quiz\n# Again\n? Q\n  - A\n").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["errors"][0]["line"], 3);
+    assert_eq!(
+        json["errors"][0]["span"],
+        serde_json::json!({"start_line": 3, "start_column": 1, "end_line": 3, "end_column": 4})
+    );
+    assert_eq!(json["warnings"][0]["code"], "ignored_title");
+    assert_eq!(json["warnings"][0]["span"]["start_line"], 2);
+}
+
+#[tokio::test]
+async fn lint_valid_quiz_is_not_stored() {
+    let app = test_app();
+    let (status, json) = post_lint(&app, "quiz.txt", b"# Quiz\n? Q\n- A\n* B\n").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["valid"], true);
+    assert_eq!(json["errors"], serde_json::json!([]));
+    assert!(json.get("quiz_id").is_none());
+}
+
+#[tokio::test]
+async fn upload_returns_structured_warnings() {
+    let app = test_app();
+    let (status, json) = post_quiz(&app, "# Quiz\n? Q\n- Paris\n* Rome\n- Paris\n").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["warnings"][0]["code"], "duplicate_option");
+    assert_eq!(json["warnings"][0]["line"], 5);
+}
@@ -0,0 +1,88 @@
+use quiz_server::models::elimination::{EliminationSettings, MAX_LIVES};
+use quiz_server::models::player::Answer;
+use quiz_server::models::quiz::{Question, QuestionKind, QuizOption};
+use rstest::rstest;
+
+fn settings(lives: u32, max_answer_sec: Option<u64>) -> EliminationSettings {
+    EliminationSettings {
+        enabled: true,
+        lives,
+        max_answer_sec,
+    }
+}
+
+fn question() -> Question {
+    Question {
+        text: "What is 1+1?".to_string(),
+        options: ["1", "2"]
+            .map(|text| QuizOption {
+                text: text.to_string(),
+                ..Default::default()
+            })
+            .to_vec(),
+        correct_index: 1,
+        ..Default::default()
+    }
+}
+
+fn answer(selected_index: usize, time_taken_ms: u64) -> Answer {
+    Answer {
+        question_index: 0,
+        selected_index,
+        time_taken_ms,
+        points_awarded: 0,
+    }
+}
+
+#[test]
+fn default_settings_are_off_with_one_life() {
+    let defaults = EliminationSettings::default();
+    assert!(!defaults.enabled);
+    assert_eq!(defaults.lives, 1);
+    assert_eq!(defaults.max_answer_sec, None);
+    assert!(defaults.validate().is_ok());
+}
+
+#[rstest]
+#[case(settings(0, None))]
+#[case(settings(MAX_LIVES + 1, None))]
+#[case(settings(1, Some(0)))]
+#[case(settings(1, Some(61)))]
+fn out_of_range_settings_are_rejected(#[case] settings: EliminationSettings) {
+    assert!(settings.validate().is_err());
+}
+
+#[rstest]
+#[case(None, true)]
+#[case(Some(answer(0, 1_000)), true)]
+#[case(Some(answer(1, 1_000)), false)]
+#[case(Some(answer(1, 9_000)), false)]
+fn missing_or_wrong_answers_cost_a_life(#[case] given: Option<Answer>, #[case] costs: bool) {
+    assert_eq!(
+        settings(1, None).costs_life(&question(), given.as_ref()),
+        costs
+    );
+}
+
+#[rstest]
+#[case(5_000, false)]
+#[case(5_001, true)]
+fn slow_correct_answers_cost_a_life_when_configured(
+    #[case] time_taken_ms: u64,
+    #[case] costs: bool,
+) {
+    let given = answer(1, time_taken_ms);
+    assert_eq!(
+        settings(1, Some(5)).costs_life(&question(), Some(&given)),
+        costs
+    );
+}
+
+#[test]
+fn polls_never_cost_a_life() {
+    let poll = Question {
+        kind: QuestionKind::Poll,
+        ..question()
+    };
+    assert!(!settings(1, Some(1)).costs_life(&poll, None));
+}
@@ -4,7 +4,8 @@ use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpListener;
 use tokio_tungstenite::tungstenite::Message;
 
-use quiz_server::config::AppConfig;
+use quiz_server::config::{AppConfig, DEFAULT_QUIZ_LIBRARY_DIR};
+use quiz_server::models::quiz::OptionLimits;
 use quiz_server::services::session_manager::SessionManager;
 
 fn test_config() -> AppConfig {
@@ -14,7 +15,10 @@ fn test_config() -> AppConfig {
         max_players: 50,
         question_time_sec: 30, // long enough to not auto-expire during test
         reconnect_timeout_sec: 120,
+        max_upload_bytes: 10 * 1024 * 1024,
         static_dir: None,
+        option_limits: OptionLimits::default(),
+        quiz_library_dir: DEFAULT_QUIZ_LIBRARY_DIR.into(),
     }
 }
 
@@ -69,6 +73,45 @@ async fn create_session(addr: &str, quiz_id: &str) -> String {
     json["join_code"].as_str().unwrap().to_string()
 }
 
+async fn recv_next(
+    ws: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+) -> serde_json::Value {
+    let msg = tokio::time::timeout(Duration::from_secs(10), ws.next())
+        .await
+        .expect("timed out waiting for message")
+        .expect("stream ended")
+        .expect("WebSocket error");
+    serde_json::from_str(msg.to_text().unwrap()).unwrap()
+}
+
+async fn recv_of_type(
+    ws: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+    msg_type: &str,
+) -> serde_json::Value {
+    for _ in 0..10 {
+        let json = recv_next(ws).await;
+        if json["type"].as_str() == Some(msg_type) {
+            return json;
+        }
+    }
+    panic!("did not receive message of type '{msg_type}'");
+}
+
+async fn send_msg(
+    ws: &mut tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+    payload: serde_json::Value,
+) {
+    ws.send(Message::Text(payload.to_string().into()))
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn host_game_flow_upload_create_start_finish() {
     let addr = start_test_server().await;
@@ -200,6 +243,18 @@ async fn host_game_flow_upload_create_start_finish() {
     let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
     assert_eq!(json["type"], "question_ended");
 
+    // Host alone receives the answer breakdown
+    let msg = tokio::time::timeout(Duration::from_secs(10), host_ws.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    assert_eq!(json["type"], "answer_distribution");
+    assert_eq!(json["payload"]["picks"], serde_json::json!([0, 1]));
+    assert_eq!(json["payload"]["correct_percentage"], 100.0);
+    assert_eq!(json["payload"]["no_answer"], 0);
+
     // Player receives question_ended
     let msg = tokio::time::timeout(Duration::from_secs(10), player_ws.next())
         .await
@@ -208,6 +263,21 @@ async fn host_game_flow_upload_create_start_finish() {
         .unwrap();
     let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
     assert_eq!(json["type"], "question_ended");
+    assert_eq!(
+        json["payload"]["leaderboard"][0]["points_gained"],
+        json["payload"]["leaderboard"][0]["score"]
+    );
+
+    // Player receives their own standing
+    let msg = tokio::time::timeout(Duration::from_secs(10), player_ws.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    assert_eq!(json["type"], "your_standing");
+    assert_eq!(json["payload"]["rank"], 1);
+    assert_eq!(json["payload"]["previous_rank"], serde_json::Value::Null);
 
     // Both receive next question
     let msg = tokio::time::timeout(Duration::from_secs(10), host_ws.next())
@@ -268,6 +338,14 @@ async fn host_game_flow_upload_create_start_finish() {
     let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
     assert_eq!(json["type"], "question_ended");
 
+    let msg = tokio::time::timeout(Duration::from_secs(10), host_ws.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    assert_eq!(json["type"], "answer_distribution");
+
     let msg = tokio::time::timeout(Duration::from_secs(10), player_ws.next())
         .await
         .unwrap()
@@ -275,6 +353,16 @@ async fn host_game_flow_upload_create_start_finish() {
         .unwrap();
     let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
     assert_eq!(json["type"], "question_ended");
+    assert_eq!(json["payload"]["leaderboard"][0]["previous_rank"], 1);
+    assert_eq!(json["payload"]["leaderboard"][0]["rank_change"], 0);
+
+    let msg = tokio::time::timeout(Duration::from_secs(10), player_ws.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+    assert_eq!(json["type"], "your_standing");
 
     // Both should receive game_finished (last question was Q2)
     let msg = tokio::time::timeout(Duration::from_secs(10), host_ws.next())
@@ -294,3 +382,77 @@ async fn host_game_flow_upload_create_start_finish() {
     let json: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
     assert_eq!(json["type"], "game_finished");
 }
+
+#[tokio::test]
+async fn host_disconnect_during_tiebreak_pauses_until_reconnect() {
+    let addr = start_test_server().await;
+
+    let quiz_content = "# Tiebreak Quiz\n? Q1\n- A\n* B\n== Tiebreaker\n? TB\n- C\n* D\n";
+    let quiz_id = upload_quiz(&addr, quiz_content).await;
+    let join_code = create_session(&addr, &quiz_id).await;
+
+    let (mut host_ws, _) =
+        tokio_tungstenite::connect_async(format!("ws://{addr}/ws/host/{join_code}"))
+            .await
+            .unwrap();
+    let mut players = Vec::new();
+    for name in ["Al", "Bo"] {
+        let (player_ws, _) = tokio_tungstenite::connect_async(format!(
+            "ws://{addr}/ws/player/{join_code}?name={name}"
+        ))
+        .await
+        .unwrap();
+        players.push(player_ws);
+    }
+    recv_of_type(&mut host_ws, "player_joined").await;
+    recv_of_type(&mut host_ws, "player_joined").await;
+
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({"type": "set_tie_breaker", "payload": {"tie_breaker": "sudden_death"}}),
+    )
+    .await;
+    recv_of_type(&mut host_ws, "tie_breaker_set").await;
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({"type": "start_game", "payload": {}}),
+    )
+    .await;
+
+    // Both answer wrong, tying on zero
+    for player_ws in &mut players {
+        recv_of_type(player_ws, "question").await;
+        send_msg(
+            player_ws,
+            serde_json::json!({
+                "type": "submit_answer",
+                "payload": {"question_index": 0, "selected_index": 0}
+            }),
+        )
+        .await;
+    }
+    recv_of_type(&mut host_ws, "tiebreak_question").await;
+    recv_of_type(&mut players[0], "tiebreak_question").await;
+
+    host_ws.close(None).await.unwrap();
+    drop(host_ws);
+    let json = recv_of_type(&mut players[0], "game_paused").await;
+    assert_eq!(json["payload"]["reason"], "host_disconnected");
+
+    // The session is still there for the host to come back to
+    let (mut host_ws, _) =
+        tokio_tungstenite::connect_async(format!("ws://{addr}/ws/host/{join_code}"))
+            .await
+            .unwrap();
+    recv_of_type(&mut players[0], "game_resumed").await;
+
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({"type": "end_question", "payload": {}}),
+    )
+    .await;
+    let json = recv_of_type(&mut host_ws, "tiebreak_result").await;
+    assert_eq!(json["payload"]["tiebreak_index"], 0);
+    recv_of_type(&mut players[0], "tiebreak_result").await;
+    recv_of_type(&mut players[0], "game_finished").await;
+}
@@ -1,5 +1,8 @@
-use quiz_server::models::leaderboard::compute_leaderboard;
-use quiz_server::models::player::Player;
+use quiz_server::models::leaderboard::{
+    AskedQuestion, LeaderboardEntry, RankMovement, TieBreaker, compute_leaderboard,
+    compute_leaderboard_with, compute_survival_leaderboard, rank_movement,
+};
+use quiz_server::models::player::{Answer, Player};
 
 #[test]
 fn leaderboard_sorts_by_score_descending() {
@@ -100,9 +103,223 @@ fn leaderboard_single_player() {
     assert_eq!(board[0].correct_count, 5);
 }
 
-fn make_player(name: &str, score: u32, correct_count: u32, avatar: &str) -> Player {
+#[test]
+fn leaderboard_ranks_negative_totals_below_zero() {
+    let p1 = make_player("Alice", -250, 0, "🙂");
+    let p2 = make_player("Bob", 0, 0, "🙂");
+    let p3 = make_player("Charlie", -500, 1, "🙂");
+    let p4 = make_player("Dana", -250, 1, "🙂");
+
+    let board = compute_leaderboard(&[&p1, &p2, &p3, &p4], true);
+
+    let order: Vec<_> = board
+        .iter()
+        .map(|e| (e.display_name.as_str(), e.score, e.rank))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            ("Bob", 0, 1),
+            ("Alice", -250, 2),
+            ("Dana", -250, 2),
+            ("Charlie", -500, 4)
+        ]
+    );
+    assert!(board[0].is_winner);
+    assert!(!board[1].is_winner);
+}
+
+#[test]
+fn answer_time_tie_breaker_ranks_the_faster_player_first() {
+    let mut alice = make_player("Alice", 500, 2, "🙂");
+    let mut bob = make_player("Bob", 500, 2, "🙂");
+    let charlie = make_player("Charlie", 800, 3, "🙂");
+    answer(&mut alice, &[4000, 6000]);
+    answer(&mut bob, &[3000, 5000]);
+
+    let board = compute_leaderboard_with(
+        &[&alice, &bob, &charlie],
+        true,
+        TieBreaker::AnswerTime,
+        &asked(&[0, 1]),
+    );
+
+    let order: Vec<_> = board
+        .iter()
+        .map(|e| (e.display_name.as_str(), e.rank))
+        .collect();
+    assert_eq!(order, [("Charlie", 1), ("Bob", 2), ("Alice", 3)]);
+}
+
+#[test]
+fn answer_time_tie_breaker_charges_skipped_questions_the_full_time_limit() {
+    let mut skipper = make_player("Alice", 500, 1, "🙂");
+    let mut steady = make_player("Bob", 500, 1, "🙂");
+    // Question 1 is a poll, so only 0 and 2 are asked for scoring
+    answer(&mut skipper, &[1000]);
+    answer(&mut steady, &[8000, 19_000, 8000]);
+
+    let board = compute_leaderboard_with(
+        &[&skipper, &steady],
+        true,
+        TieBreaker::AnswerTime,
+        &asked(&[0, 2]),
+    );
+
+    // Alice: 1000 + 20000 for the skip; Bob: 8000 + 8000, his poll time not counted
+    assert_eq!(board[0].display_name, "Bob");
+    assert_eq!((board[0].rank, board[1].rank), (1, 2));
+}
+
+#[test]
+fn correct_count_tie_breaker_ranks_more_correct_answers_first() {
+    let p1 = make_player("Alice", 500, 1, "🙂");
+    let p2 = make_player("Bob", 500, 3, "🙂");
+
+    let board = compute_leaderboard_with(&[&p1, &p2], true, TieBreaker::CorrectCount, &[]);
+
+    assert_eq!(board[0].display_name, "Bob");
+    assert!(board[0].is_winner);
+    assert_eq!(board[1].rank, 2);
+    assert!(!board[1].is_winner);
+}
+
+#[test]
+fn sudden_death_ranks_the_longest_survivor_first() {
+    let mut p1 = make_player("Alice", 500, 2, "🙂");
+    let mut p2 = make_player("Bob", 500, 2, "🙂");
+    p1.tiebreak_survived = 1;
+    p2.tiebreak_survived = 2;
+
+    let board = compute_leaderboard_with(&[&p1, &p2], true, TieBreaker::SuddenDeath, &[]);
+
+    assert_eq!(board[0].display_name, "Bob");
+    assert!(board[0].is_winner);
+    assert!(!board[1].is_winner);
+}
+
+#[test]
+fn players_still_tied_after_the_tie_breaker_share_rank() {
+    let p1 = make_player("Zara", 500, 2, "🙂");
+    let p2 = make_player("Alice", 500, 2, "🙂");
+
+    let board = compute_leaderboard_with(&[&p1, &p2], true, TieBreaker::CorrectCount, &[]);
+
+    assert_eq!(board[0].display_name, "Alice");
+    assert_eq!((board[0].rank, board[1].rank), (1, 1));
+    assert!(board[1].is_winner);
+}
+
+#[test]
+fn survival_leaderboard_ranks_survivors_then_the_last_eliminated() {
+    let survivor = make_player("Zed", 100, 1, "🙂");
+    let mut late = make_player("Alice", 900, 4, "🙂");
+    late.eliminated_at = Some(4);
+    let mut early = make_player("Bob", 950, 4, "🙂");
+    early.eliminated_at = Some(1);
+    let mut early_too = make_player("Carol", 950, 4, "🙂");
+    early_too.eliminated_at = Some(1);
+
+    let board = compute_survival_leaderboard(
+        &[&early, &late, &survivor, &early_too],
+        true,
+        TieBreaker::None,
+        &[],
+    );
+
+    let order: Vec<_> = board
+        .iter()
+        .map(|e| (e.display_name.as_str(), e.rank, e.eliminated))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            ("Zed", 1, false),
+            ("Alice", 2, true),
+            ("Bob", 3, true),
+            ("Carol", 3, true)
+        ]
+    );
+    assert!(board[0].is_winner);
+    assert!(!board[1].is_winner);
+}
+
+#[test]
+fn no_tie_breaker_matches_compute_leaderboard() {
+    let p1 = make_player("Alice", 500, 1, "🙂");
+    let p2 = make_player("Bob", 500, 3, "🙂");
+
+    let with = compute_leaderboard_with(&[&p1, &p2], true, TieBreaker::None, &[]);
+    let without = compute_leaderboard(&[&p1, &p2], true);
+
+    let ranks = |board: Vec<LeaderboardEntry>| -> Vec<_> {
+        board
+            .into_iter()
+            .map(|e| (e.display_name, e.rank))
+            .collect()
+    };
+    assert_eq!(ranks(with), ranks(without));
+}
+
+fn answer(player: &mut Player, times_ms: &[u64]) {
+    for (question_index, &time_taken_ms) in times_ms.iter().enumerate() {
+        player.answers.push(Answer {
+            question_index,
+            selected_index: 0,
+            time_taken_ms,
+            points_awarded: 0,
+        });
+    }
+}
+
+/// Scored questions at `positions`, each with a 20-second limit.
+fn asked(positions: &[usize]) -> Vec<AskedQuestion> {
+    positions
+        .iter()
+        .map(|&position| AskedQuestion {
+            position,
+            time_limit_ms: 20_000,
+        })
+        .collect()
+}
+
+fn make_player(name: &str, score: i32, correct_count: u32, avatar: &str) -> Player {
     let mut p = Player::new(format!("id-{name}"), name.to_string(), avatar.to_string());
     p.score = score;
     p.correct_count = correct_count;
     p
 }
+
+#[test]
+fn rank_movement_compares_with_the_previous_leaderboard() {
+    let alice = make_player("Alice", 500, 1, "🙂");
+    let mut bob = make_player("Bob", 200, 0, "🙂");
+    let previous = compute_leaderboard(&[&alice, &bob], false);
+    bob.score = 900;
+    let carol = make_player("Carol", 100, 1, "🙂");
+
+    let board = compute_leaderboard(&[&alice, &bob, &carol], false);
+    let movements: Vec<_> = board.iter().map(|e| rank_movement(e, &previous)).collect();
+
+    assert_eq!(
+        movements,
+        [
+            RankMovement {
+                points_gained: 700,
+                previous_rank: Some(2),
+                rank_change: 1,
+            },
+            RankMovement {
+                points_gained: 0,
+                previous_rank: Some(1),
+                rank_change: -1,
+            },
+            // Not on the previous board: everything is new
+            RankMovement {
+                points_gained: 100,
+                previous_rank: None,
+                rank_change: 0,
+            },
+        ]
+    );
+}
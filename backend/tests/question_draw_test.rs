@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use quiz_server::models::draw::DrawRequest;
+use quiz_server::models::quiz::{Question, Quiz, QuizOption, Round};
+use rstest::rstest;
+
+/// A bank where question `i` is tagged `odd` or `even`, and every third one `hard`.
+fn bank(size: usize) -> Quiz {
+    Quiz {
+        title: "Bank".into(),
+        questions: (0..size)
+            .map(|i| {
+                let mut tags = vec![if i % 2 == 0 { "even" } else { "odd" }.to_string()];
+                if i % 3 == 0 {
+                    tags.push("hard".into());
+                }
+                Question {
+                    text: format!("Question {i}"),
+                    options: ["A", "B"]
+                        .iter()
+                        .map(|text| QuizOption {
+                            text: text.to_string(),
+                            media: None,
+                        })
+                        .collect(),
+                    correct_index: 0,
+                    time_limit_sec: 20,
+                    tags,
+                    ..Default::default()
+                }
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn request(count: Option<usize>, quotas: &[(&str, usize)], seed: u64) -> DrawRequest {
+    DrawRequest {
+        question_count: count,
+        tag_quotas: quotas
+            .iter()
+            .map(|&(tag, n)| (tag.to_string(), n))
+            .collect::<BTreeMap<_, _>>(),
+        seed: Some(seed),
+    }
+}
+
+#[test]
+fn draw_picks_distinct_questions_in_bank_order() {
+    let bank = bank(300);
+    let draw = request(Some(15), &[], 42).draw(&bank).unwrap();
+
+    assert_eq!(draw.seed, 42);
+    assert_eq!(draw.bank_size, 300);
+    assert_eq!(draw.question_indices.len(), 15);
+    assert!(draw.question_indices.windows(2).all(|w| w[0] < w[1]));
+
+    let quiz = draw.apply(bank.clone());
+    assert_eq!(quiz.title, "Bank");
+    assert_eq!(quiz.questions.len(), 15);
+    for (question, &index) in quiz.questions.iter().zip(&draw.question_indices) {
+        assert_eq!(question, &bank.questions[index]);
+    }
+}
+
+#[test]
+fn draw_is_reproducible_from_seed() {
+    let bank = bank(300);
+    let first = request(Some(15), &[], 7).draw(&bank).unwrap();
+    assert_eq!(first, request(Some(15), &[], 7).draw(&bank).unwrap());
+    assert_ne!(first, request(Some(15), &[], 8).draw(&bank).unwrap());
+}
+
+#[test]
+fn draw_fills_tag_quotas() {
+    let bank = bank(60);
+    for seed in 0..20 {
+        let draw = request(Some(10), &[("hard", 4), ("odd", 3)], seed)
+            .draw(&bank)
+            .unwrap();
+        assert_eq!(draw.question_indices.len(), 10);
+        let tagged = |tag: &str| {
+            draw.question_indices
+                .iter()
+                .filter(|&&i| bank.questions[i].tags.iter().any(|t| t == tag))
+                .count()
+        };
+        assert!(tagged("hard") >= 4, "seed {seed}: {draw:?}");
+        assert!(tagged("odd") >= 3, "seed {seed}: {draw:?}");
+    }
+}
+
+#[test]
+fn draw_count_defaults_to_quota_total() {
+    let draw = request(None, &[("even", 2), ("odd", 3)], 1)
+        .draw(&bank(20))
+        .unwrap();
+    assert_eq!(draw.question_indices.len(), 5);
+}
+
+#[test]
+fn draw_without_seed_records_a_random_one() {
+    let mut req = request(Some(3), &[], 0);
+    req.seed = None;
+    let draw = req.draw(&bank(10)).unwrap();
+    assert_eq!(
+        draw,
+        request(Some(3), &[], draw.seed).draw(&bank(10)).unwrap()
+    );
+}
+
+#[rstest]
+#[case::zero(Some(0), &[], "at least 1")]
+#[case::more_than_bank(Some(11), &[], "from a quiz with 10")]
+#[case::quotas_exceed_count(Some(3), &[("odd", 2), ("even", 2)], "add up to 4")]
+#[case::not_enough_tagged(Some(8), &[("hard", 5)], "only 4 matching")]
+#[case::unknown_tag(Some(2), &[("music", 1)], "only 0 matching")]
+fn draw_rejects_impossible_requests(
+    #[case] count: Option<usize>,
+    #[case] quotas: &[(&str, usize)],
+    #[case] message: &str,
+) {
+    let error = request(count, quotas, 1).draw(&bank(10)).unwrap_err();
+    assert!(error.contains(message), "{error}");
+}
+
+#[test]
+fn draw_shrinks_rounds_to_the_drawn_questions() {
+    let mut bank = bank(10);
+    bank.rounds = ["One", "Two", "Three"]
+        .iter()
+        .zip([4, 3, 3])
+        .map(|(title, question_count)| Round {
+            title: title.to_string(),
+            question_count,
+            ..Default::default()
+        })
+        .collect();
+    // Only questions from the first and last rounds are tagged `music`
+    for i in 0..10 {
+        bank.questions[i].tags = if (4..7).contains(&i) {
+            Vec::new()
+        } else {
+            vec!["music".into()]
+        };
+    }
+
+    let draw = request(Some(3), &[("music", 3)], 3).draw(&bank).unwrap();
+    let quiz = draw.apply(bank);
+    let titles: Vec<&str> = quiz.rounds.iter().map(|r| r.title.as_str()).collect();
+    assert!(!titles.contains(&"Two"), "{titles:?}");
+    assert_eq!(
+        quiz.rounds.iter().map(|r| r.question_count).sum::<usize>(),
+        3
+    );
+}
@@ -0,0 +1,303 @@
+use proptest::prelude::*;
+use quiz_server::models::quiz::{
+    Question, QuestionKind, Quiz, QuizMetadata, QuizOption, Round, parse_quiz,
+};
+use quiz_server::models::scoring_rule::ScoringRule;
+use quiz_server::services::quiz_export::export_quiz;
+
+const DEFAULT_TIME_LIMIT: u64 = 20;
+
+#[test]
+fn export_writes_canonical_text_format() {
+    let quiz = parse_quiz(
+        "---\nauthor: Ada\nscoring_rule: fixed_score\n---\n# Capitals\n? Capital of France?\n@ img/france.png\n- London\n* Paris\n@ img/paris.png\n> Since 987.\n~ Favourite city?\n- Rome\n- Oslo\n",
+        DEFAULT_TIME_LIMIT,
+    )
+    .unwrap();
+
+    assert_eq!(
+        export_quiz(&quiz),
+        "---\nauthor: Ada\nscoring_rule: fixed_score\n---\n\n# Capitals\n\n? Capital of France?\n@ img/france.png\n- London\n* Paris\n@ img/paris.png\n> Since 987.\n\n~ Favourite city?\n- Rome\n- Oslo\n"
+    );
+}
+
+#[test]
+fn export_keeps_text_that_starts_with_markers() {
+    let quiz = Quiz {
+        title: "# Hash".into(),
+        questions: vec![Question {
+            text: "?? not a poll".into(),
+            options: vec![
+                QuizOption {
+                    text: "* not correct".into(),
+                    media: None,
+                },
+                QuizOption {
+                    text: "- // not a comment".into(),
+                    media: None,
+                },
+            ],
+            correct_index: 1,
+            time_limit_sec: DEFAULT_TIME_LIMIT,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_quiz(&export_quiz(&quiz), DEFAULT_TIME_LIMIT).unwrap(),
+        quiz
+    );
+}
+
+#[test]
+fn export_writes_multi_line_text_as_continuations() {
+    let quiz = Quiz {
+        title: "Q".into(),
+        questions: vec![Question {
+            text: "What does this print?\nfn main() {\n    println!(\"hi\");\n}".into(),
+            options: vec![
+                QuizOption {
+                    text: "  hi".into(),
+                    media: None,
+                },
+                QuizOption {
+                    text: "\\n".into(),
+                    media: None,
+                },
+            ],
+            time_limit_sec: DEFAULT_TIME_LIMIT,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let exported = export_quiz(&quiz);
+    assert_eq!(
+        exported,
+        "# Q\n\n? What does this print?\n| fn main() {\n|     println!(\"hi\");\n| }\n* \\  hi\n- \\n\n"
+    );
+    assert_eq!(parse_quiz(&exported, DEFAULT_TIME_LIMIT).unwrap(), quiz);
+}
+
+#[test]
+fn export_keeps_baseline_backslash_text() {
+    let content = "# \\# One\n\n? \\?? not a poll\n- \\-5\n* \\\\n\n> \\> see C:\\temp\n";
+    let quiz = parse_quiz(content, DEFAULT_TIME_LIMIT).unwrap();
+    assert_eq!(export_quiz(&quiz), content);
+}
+
+#[test]
+fn export_writes_round_headers() {
+    let content = "# Pub Quiz\n\n= Warm-up\n\n? Q1\n- A\n* B\n\n= Lightning [time_limit: 10, scoring_rule: position_race]\n\n? Q2\n- A\n* B\n\n= Odds [and ends] []\n\n? Q3\n- A\n* B\n";
+    let quiz = parse_quiz(content, DEFAULT_TIME_LIMIT).unwrap();
+    assert_eq!(quiz.rounds[2].title, "Odds [and ends]");
+    assert_eq!(export_quiz(&quiz), content);
+}
+
+#[test]
+fn export_writes_the_tiebreaker_section_last() {
+    let content = "# Pub Quiz\n\n= Warm-up\n\n? Q1\n- A\n* B\n\n== Tiebreaker\n\n? T1\n* A\n- B\n\n? T2\n- A\n* B\n";
+    let quiz = parse_quiz(content, DEFAULT_TIME_LIMIT).unwrap();
+    assert_eq!(quiz.tiebreakers.len(), 2);
+    assert_eq!(export_quiz(&quiz), content);
+}
+
+// === Round-trip property ===
+
+/// Single-line text with no surrounding whitespace, biased towards characters
+/// that are also format markers.
+fn line() -> impl Strategy<Value = String> {
+    r"[-a-z*?#>@/|\\,:][-a-z0-9 *?#>@/|\\,.:]{0,15}[a-z0-9?*#|\\]"
+}
+
+/// Text for questions, options and explanations: may be indented and span several
+/// lines, including blank and indented ones, but never ends in a line break.
+fn text() -> impl Strategy<Value = String> {
+    (
+        "( {0,2})",
+        line(),
+        proptest::collection::vec((any::<bool>(), "( {0,4})", line()), 0..3),
+    )
+        .prop_map(|(indent, first, rest)| {
+            let mut text = format!("{indent}{first}");
+            for (blank_before, indent, line) in rest {
+                if blank_before {
+                    text.push('\n');
+                }
+                text.push('\n');
+                text.push_str(&indent);
+                text.push_str(&line);
+            }
+            text
+        })
+}
+
+fn media() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of("[a-z]{1,8}(/[a-z]{1,8})?\\.png")
+}
+
+fn scoring_rule() -> impl Strategy<Value = ScoringRule> {
+    prop_oneof![
+        Just(ScoringRule::SteppedDecay),
+        Just(ScoringRule::LinearDecay),
+        Just(ScoringRule::FixedScore),
+        Just(ScoringRule::StreakBonus),
+        Just(ScoringRule::PositionRace),
+    ]
+}
+
+fn metadata() -> impl Strategy<Value = QuizMetadata> {
+    let rule = proptest::option::of(scoring_rule());
+    (
+        proptest::option::of(line()),
+        proptest::option::of(line()),
+        rule,
+        proptest::option::of(10u64..=60),
+        any::<bool>(),
+        any::<bool>(),
+        proptest::collection::vec("[a-z][a-z0-9 ]{0,8}[a-z0-9]", 0..3),
+        proptest::option::of(Just(2usize)),
+        proptest::option::of(Just(4usize)),
+    )
+        .prop_map(
+            |(author, description, scoring_rule, time_limit_sec, sq, so, tags, min, max)| {
+                QuizMetadata {
+                    author,
+                    description,
+                    scoring_rule,
+                    time_limit_sec,
+                    shuffle_questions: sq,
+                    shuffle_options: so,
+                    tags,
+                    min_options: min,
+                    max_options: max,
+                }
+            },
+        )
+}
+
+fn question(time_limit_sec: u64) -> impl Strategy<Value = Question> {
+    let option = (text(), media()).prop_map(|(text, media)| QuizOption { text, media });
+    (
+        text(),
+        proptest::collection::vec(option, 2..=4),
+        any::<prop::sample::Index>(),
+        any::<bool>(),
+        proptest::option::of(text()),
+        media(),
+        proptest::collection::btree_set("[a-z][a-z0-9 ]{0,8}[a-z0-9]", 0..3),
+        // Quarter steps up to the maximum, so the weight survives printing exactly
+        proptest::option::of((1u32..=40).prop_map(|quarters| quarters as f64 / 4.0)),
+    )
+        .prop_map(
+            move |(text, options, correct, poll, explanation, media, tags, weight)| {
+                let kind = if poll {
+                    QuestionKind::Poll
+                } else {
+                    QuestionKind::Standard
+                };
+                Question {
+                    text,
+                    correct_index: if poll {
+                        0
+                    } else {
+                        correct.index(options.len())
+                    },
+                    options,
+                    time_limit_sec,
+                    kind,
+                    explanation,
+                    media,
+                    tags: tags.into_iter().collect(),
+                    weight: weight.filter(|_| !poll),
+                }
+            },
+        )
+}
+
+/// Rounds splitting `question_count` questions, or none. Each `true` in `cuts`
+/// starts a new round after that question.
+fn rounds(question_count: usize) -> impl Strategy<Value = Vec<Round>> {
+    let settings = (
+        line(),
+        proptest::option::of(10u64..=60),
+        proptest::option::of(scoring_rule()),
+    );
+    (
+        any::<bool>(),
+        proptest::collection::vec(any::<bool>(), question_count - 1),
+        proptest::collection::vec(settings, question_count),
+    )
+        .prop_map(|(enabled, cuts, settings)| {
+            if !enabled {
+                return Vec::new();
+            }
+            let mut sizes = vec![1];
+            for cut in cuts {
+                if cut {
+                    sizes.push(1);
+                } else {
+                    *sizes.last_mut().unwrap() += 1;
+                }
+            }
+            sizes
+                .into_iter()
+                .zip(settings)
+                .map(
+                    |(question_count, (title, time_limit_sec, scoring_rule))| Round {
+                        title,
+                        question_count,
+                        scoring_rule,
+                        time_limit_sec,
+                    },
+                )
+                .collect()
+        })
+}
+
+fn quiz() -> impl Strategy<Value = Quiz> {
+    metadata().prop_flat_map(|metadata| {
+        let time_limit = metadata.time_limit_sec.unwrap_or(DEFAULT_TIME_LIMIT);
+        (
+            line(),
+            proptest::collection::vec(question(time_limit), 1..6),
+            proptest::collection::vec(question(time_limit), 0..3),
+        )
+            .prop_flat_map(move |(title, questions, tiebreakers)| {
+                let metadata = metadata.clone();
+                // Tiebreaker questions always have a correct answer
+                let tiebreakers: Vec<Question> =
+                    tiebreakers.into_iter().filter(|q| !q.is_poll()).collect();
+                rounds(questions.len()).prop_map(move |rounds| {
+                    let mut quiz = Quiz {
+                        title: title.clone(),
+                        questions: questions.clone(),
+                        metadata: metadata.clone(),
+                        rounds,
+                        tiebreakers: tiebreakers.clone(),
+                    };
+                    // Questions take their round's time limit when parsed
+                    for (range, round) in quiz.round_ranges().into_iter().zip(&quiz.rounds) {
+                        if let Some(seconds) = round.time_limit_sec {
+                            for question in &mut quiz.questions[range] {
+                                question.time_limit_sec = seconds;
+                            }
+                        }
+                    }
+                    quiz
+                })
+            })
+    })
+}
+
+proptest! {
+    #[test]
+    fn parse_of_export_round_trips(quiz in quiz()) {
+        let exported = export_quiz(&quiz);
+        match parse_quiz(&exported, DEFAULT_TIME_LIMIT) {
+            Ok(parsed) => prop_assert_eq!(parsed, quiz, "exported:\n{}", exported),
+            Err(errors) => prop_assert!(false, "exported:\n{}\n{:?}", exported, errors),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use quiz_server::models::quiz::{Question, Quiz, QuizOption};
+use quiz_server::services::quiz_library::{
+    FsQuizLibrary, MemoryQuizLibrary, QuizLibrary, is_valid_quiz_id,
+};
+use rstest::rstest;
+
+/// A fresh directory under the system temp dir, removed on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("quiz-library-{}", uuid::Uuid::new_v4()));
+        Self(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn quiz(title: &str, question_count: usize) -> Quiz {
+    Quiz {
+        title: title.to_string(),
+        questions: (0..question_count)
+            .map(|i| Question {
+                text: format!("Question {}", i + 1),
+                options: ["A", "B"]
+                    .iter()
+                    .map(|text| QuizOption {
+                        text: text.to_string(),
+                        media: None,
+                    })
+                    .collect(),
+                correct_index: 1,
+                time_limit_sec: 20,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn exercise_library(library: &dyn QuizLibrary) {
+    assert!(library.list().unwrap().is_empty());
+    assert!(library.get("capitals", None).unwrap().is_none());
+
+    let first = library.save("capitals", quiz("Capitals", 1)).unwrap();
+    assert_eq!(first.version, 1);
+    let second = library.save("capitals", quiz("Capitals v2", 3)).unwrap();
+    assert_eq!(second.version, 2);
+    library.save("animals", quiz("Animals", 2)).unwrap();
+
+    let list = library.list().unwrap();
+    let ids: Vec<_> = list.iter().map(|s| (s.id.as_str(), s.version)).collect();
+    assert_eq!(ids, [("animals", 1), ("capitals", 2)]);
+    assert_eq!(list[1].title, "Capitals v2");
+    assert_eq!(list[1].question_count, 3);
+
+    let latest = library.get("capitals", None).unwrap().unwrap();
+    assert_eq!(latest.quiz, quiz("Capitals v2", 3));
+    let old = library.get("capitals", Some(1)).unwrap().unwrap();
+    assert_eq!(old.quiz, quiz("Capitals", 1));
+    assert!(library.get("capitals", Some(3)).unwrap().is_none());
+
+    let versions: Vec<_> = library
+        .revisions("capitals")
+        .unwrap()
+        .iter()
+        .map(|r| r.version)
+        .collect();
+    assert_eq!(versions, [1, 2]);
+
+    assert!(library.delete("capitals").unwrap());
+    assert!(!library.delete("capitals").unwrap());
+    assert!(library.get("capitals", None).unwrap().is_none());
+    assert!(library.revisions("capitals").unwrap().is_empty());
+    assert_eq!(library.list().unwrap().len(), 1);
+}
+
+#[test]
+fn memory_library_versions_and_deletes() {
+    exercise_library(&MemoryQuizLibrary::default());
+}
+
+#[test]
+fn fs_library_versions_and_deletes() {
+    let dir = TempDir::new();
+    exercise_library(&FsQuizLibrary::new(&dir.0));
+}
+
+#[test]
+fn fs_library_survives_reopening() {
+    let dir = TempDir::new();
+    FsQuizLibrary::new(&dir.0)
+        .save("weekly", quiz("Weekly", 2))
+        .unwrap();
+
+    let reopened = FsQuizLibrary::new(&dir.0);
+    let stored = reopened.get("weekly", None).unwrap().unwrap();
+    assert_eq!(stored.version, 1);
+    assert_eq!(stored.quiz, quiz("Weekly", 2));
+    assert_eq!(
+        reopened.save("weekly", quiz("Weekly", 2)).unwrap().version,
+        2
+    );
+}
+
+#[rstest]
+#[case("capitals", true)]
+#[case("week-12_final", true)]
+#[case("", false)]
+#[case("Capitals", false)]
+#[case("../etc", false)]
+#[case("a/b", false)]
+#[case(&"x".repeat(65), false)]
+fn quiz_ids_are_safe_directory_names(#[case] id: &str, #[case] valid: bool) {
+    assert_eq!(is_valid_quiz_id(id), valid);
+}
@@ -0,0 +1,139 @@
+use quiz_server::errors::{TextSpan, WarningCode};
+use quiz_server::models::quiz::ParseSettings;
+use quiz_server::services::quiz_import::{QuizFormat, lint_quiz_document};
+use rstest::rstest;
+
+fn settings() -> ParseSettings {
+    ParseSettings::with_time_limit(20)
+}
+
+fn text_warnings(content: &str) -> Vec<(WarningCode, usize)> {
+    let report = lint_quiz_document(QuizFormat::Text, content, None, &settings());
+    assert!(report.quiz.is_ok(), "{:?}", report.quiz);
+    report.warnings.iter().map(|w| (w.code, w.line)).collect()
+}
+
+#[test]
+fn lint_clean_quiz_has_no_warnings() {
+    assert!(text_warnings("# Quiz\n? Q1\n- A\n* B\n? Q2\n- C\n* D\n").is_empty());
+}
+
+#[rstest]
+#[case::ignored_title("# Quiz\n# Again\n? Q\n- A\n* B\n", WarningCode::IgnoredTitle, 2)]
+#[case::duplicate_question(
+    "# Quiz\n? Capital of France?\n- A\n* B\n? capital  of FRANCE?\n- C\n* D\n",
+    WarningCode::DuplicateQuestion,
+    5
+)]
+#[case::duplicate_option(
+    "# Quiz\n? Q\n- Paris\n* Rome\n- paris\n",
+    WarningCode::DuplicateOption,
+    5
+)]
+#[case::suspicious_whitespace(
+    "# Quiz\n? Q\n- A\u{00A0}B\n* C\n",
+    WarningCode::SuspiciousWhitespace,
+    3
+)]
+#[case::zero_width_space(
+    "# Quiz\n? Q\u{200B}\n- A\n* C\n",
+    WarningCode::SuspiciousWhitespace,
+    2
+)]
+fn lint_text_warning_points_at_line(
+    #[case] content: &str,
+    #[case] code: WarningCode,
+    #[case] line: usize,
+) {
+    assert_eq!(text_warnings(content), vec![(code, line)]);
+}
+
+#[test]
+fn lint_long_text_warnings() {
+    let content = format!(
+        "# Quiz\n? {}\n- {}\n* B\n> {}\n",
+        "q".repeat(301),
+        "o".repeat(121),
+        "e".repeat(601)
+    );
+    assert_eq!(
+        text_warnings(&content),
+        vec![
+            (WarningCode::LongText, 2),
+            (WarningCode::LongText, 3),
+            (WarningCode::LongText, 5),
+        ]
+    );
+}
+
+#[test]
+fn lint_too_many_questions_points_at_title() {
+    let mut content = String::from("# Big\n");
+    for i in 0..101 {
+        content.push_str(&format!("? Q{i}\n- A\n* B\n"));
+    }
+    assert_eq!(
+        text_warnings(&content),
+        vec![(WarningCode::TooManyQuestions, 1)]
+    );
+}
+
+#[test]
+fn lint_warning_span_covers_line_content() {
+    let report = lint_quiz_document(
+        QuizFormat::Text,
+        "# Quiz\n? Q\n  - Paris\n* Rome\n  - Paris  \n",
+        None,
+        &settings(),
+    );
+    assert_eq!(
+        report.warnings[0].span,
+        Some(TextSpan {
+            start_line: 5,
+            start_column: 3,
+            end_line: 5,
+            end_column: 10,
+        })
+    );
+}
+
+#[test]
+fn lint_warnings_survive_parse_errors() {
+    let report = lint_quiz_document(
+        QuizFormat::Text,
+        "# Quiz\n# Again\n? Q\n- A\n",
+        None,
+        &settings(),
+    );
+    assert!(report.quiz.is_err());
+    assert_eq!(report.warnings[0].code, WarningCode::IgnoredTitle);
+}
+
+#[test]
+fn lint_json_warnings_carry_path() {
+    let content = r#"{"title": "Q", "questions": [
+        {"text": "Q1", "options": [{"text": "A"}, {"text": "a"}], "correct_index": 0},
+        {"text": "q1", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 0}
+    ]}"#;
+    let report = lint_quiz_document(QuizFormat::Json, content, None, &settings());
+    let paths: Vec<_> = report
+        .warnings
+        .iter()
+        .map(|w| (w.code, w.path.as_deref().unwrap()))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            (WarningCode::DuplicateOption, "questions[0].options[1].text"),
+            (WarningCode::DuplicateQuestion, "questions[1].text"),
+        ]
+    );
+}
+
+#[test]
+fn lint_csv_warnings_carry_row() {
+    let content = "Question,Option A,Option B,Correct\nQ1,A,B,A\n,,,\nQ1,C,D,A\n";
+    let report = lint_quiz_document(QuizFormat::Csv, content, None, &settings());
+    assert_eq!(report.warnings[0].code, WarningCode::DuplicateQuestion);
+    assert_eq!(report.warnings[0].row, Some(4));
+}
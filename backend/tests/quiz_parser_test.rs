@@ -1,4 +1,10 @@
-use quiz_server::models::quiz::parse_quiz;
+use quiz_server::errors::WarningCode;
+use quiz_server::models::quiz::{
+    OptionLimits, ParseSettings, QuestionKind, QuizMetadata, parse_quiz, parse_quiz_with_source_map,
+};
+use quiz_server::models::scoring_rule::{FixedScoreParams, RuleParams, ScoringRule};
+use quiz_server::services::quiz_import::{QuizFormat, lint_quiz_document, parse_quiz_document};
+use rstest::rstest;
 
 #[test]
 fn parse_valid_quiz() {
@@ -259,3 +265,1103 @@ This line is unrecognized
     let errors = parse_quiz(content, 20).unwrap_err();
     assert!(errors.iter().any(|e| e.message.contains("Unrecognized")));
 }
+
+// === Poll questions ===
+
+#[test]
+fn parse_quiz_poll_question() {
+    let content = "\
+# Mixed
+? Capital of France?
+- London
+* Paris
+~ Which topic next?
+- History
+- Science
+- Sports
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions.len(), 2);
+    assert!(!quiz.questions[0].is_poll());
+    assert_eq!(quiz.questions[0].kind, QuestionKind::Standard);
+    assert!(quiz.questions[1].is_poll());
+    assert_eq!(quiz.questions[1].text, "Which topic next?");
+    assert_eq!(quiz.questions[1].options.len(), 3);
+}
+
+#[test]
+fn parse_quiz_double_question_mark_is_still_a_standard_question() {
+    // Before polls, every leading `?` was stripped, so `??` asked a scored question
+    let content = "# Old\n?? Capital of France?\n- London\n* Paris\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert!(!quiz.questions[0].is_poll());
+    assert_eq!(quiz.questions[0].text, "Capital of France?");
+    assert_eq!(quiz.questions[0].correct_index, 1);
+}
+
+#[test]
+fn parse_quiz_poll_with_correct_answer_is_rejected() {
+    let content = "\
+# Bad Poll
+~ Which topic next?
+- History
+* Science
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 2 && e.message.contains("Poll question cannot have a correct"))
+    );
+}
+
+#[test]
+fn parse_quiz_poll_still_enforces_option_count() {
+    let content = "\
+# Bad Poll
+~ Only one?
+- Lonely
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(errors.iter().any(|e| e.message.contains("minimum is 2")));
+}
+
+// === Explanations ===
+
+#[test]
+fn parse_quiz_explanation_is_attached_to_its_question() {
+    let content = "\
+# Explained
+? Capital of France?
+- London
+* Paris
+> Paris has been the capital since 987.
+? What is 1+1?
+- 1
+* 2
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(
+        quiz.questions[0].explanation.as_deref(),
+        Some("Paris has been the capital since 987.")
+    );
+    assert_eq!(quiz.questions[1].explanation, None);
+}
+
+#[test]
+fn parse_quiz_explanation_may_precede_options() {
+    let content = "\
+# Explained
+? Q
+> Why
+- A
+* B
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions[0].explanation.as_deref(), Some("Why"));
+    assert_eq!(quiz.questions[0].options.len(), 2);
+}
+
+#[test]
+fn parse_quiz_explanation_before_question() {
+    let content = "\
+# Quiz
+> Orphan explanation
+? Q1
+- A
+* B
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 2 && e.message.contains("Explanation found before any question"))
+    );
+}
+
+#[test]
+fn parse_quiz_duplicate_explanation() {
+    let content = "\
+# Quiz
+? Q1
+- A
+* B
+> First
+> Second
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 6 && e.message.contains("already has an explanation"))
+    );
+}
+
+#[test]
+fn parse_quiz_empty_explanation() {
+    let content = "\
+# Quiz
+? Q1
+- A
+* B
+>
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.message.contains("Explanation text is empty"))
+    );
+}
+
+// === Media references ===
+
+#[test]
+fn parse_quiz_media_attaches_to_question_or_preceding_option() {
+    let content = "\
+# Media
+? Which animal is this?
+@ images/cat.png
+- Dog
+@ ./images/dog-icon.png
+* Cat
+> Cats meow.
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    let q = &quiz.questions[0];
+    assert_eq!(q.media.as_deref(), Some("images/cat.png"));
+    assert_eq!(q.options[0].media.as_deref(), Some("images/dog-icon.png"));
+    assert_eq!(q.options[1].media, None);
+}
+
+#[test]
+fn parse_quiz_media_path_must_stay_inside_bundle() {
+    let content = "\
+# Media
+? Q
+@ ../secret.png
+- A
+* B
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 3 && e.message.contains("must not contain '..'"))
+    );
+}
+
+#[test]
+fn parse_quiz_duplicate_media_on_same_target() {
+    let content = "\
+# Media
+? Q
+@ a.png
+@ b.png
+- A
+* B
+";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 4 && e.message.contains("Media already attached"))
+    );
+}
+
+// === Front-matter ===
+
+#[test]
+fn parse_quiz_front_matter() {
+    let content = "\
+---
+author: Ada Lovelace
+description: Warm-up round
+scoring_rule: streak_bonus
+time_limit: 30
+shuffle_questions: true
+shuffle_options: no
+tags: math, history ,
+---
+# Front Matter Quiz
+? Q1
+- A
+* B
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    let meta = &quiz.metadata;
+    assert_eq!(quiz.title, "Front Matter Quiz");
+    assert_eq!(meta.author.as_deref(), Some("Ada Lovelace"));
+    assert_eq!(meta.description.as_deref(), Some("Warm-up round"));
+    assert_eq!(meta.scoring_rule, Some(ScoringRule::StreakBonus));
+    assert_eq!(meta.time_limit_sec, Some(30));
+    assert!(meta.shuffle_questions);
+    assert!(!meta.shuffle_options);
+    assert_eq!(meta.tags, vec!["math", "history"]);
+    // Front-matter time limit also becomes each question's default
+    assert_eq!(quiz.questions[0].time_limit_sec, 30);
+}
+
+#[test]
+fn parse_quiz_without_front_matter_has_default_metadata() {
+    let quiz = parse_quiz("# Q\n? Q1\n- A\n* B\n", 20).unwrap();
+    assert_eq!(quiz.metadata, QuizMetadata::default());
+}
+
+#[rstest]
+#[case("scoring_rule: fastest_finger", 3, "Unknown scoring_rule")]
+#[case("time_limit: 5", 3, "between 10 and 60")]
+#[case("time_limit: soon", 3, "whole number")]
+#[case("shuffle_options: maybe", 3, "true or false")]
+#[case("difficulty: hard", 3, "Unknown front-matter key")]
+#[case("just some words", 3, "key: value")]
+#[case("description:", 3, "empty value")]
+fn parse_quiz_front_matter_errors_have_line_numbers(
+    #[case] field: &str,
+    #[case] line: usize,
+    #[case] expected: &str,
+) {
+    let content = format!("---\nauthor: Someone\n{field}\n---\n# Quiz\n? Q1\n- A\n* B\n");
+    let errors = parse_quiz(&content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(expected)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_quiz_front_matter_duplicate_key() {
+    let content = "---\nauthor: A\nauthor: B\n---\n# Quiz\n? Q1\n- A\n* B\n";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 3 && e.message.contains("Duplicate front-matter key"))
+    );
+}
+
+#[test]
+fn parse_quiz_front_matter_unclosed() {
+    let content = "---\nauthor: A\n# Quiz\n? Q1\n- A\n* B\n";
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == 1 && e.message.contains("not closed"))
+    );
+}
+
+// === Option count limits ===
+
+fn settings_with_options(min: usize, max: usize) -> ParseSettings {
+    ParseSettings {
+        default_time_limit: 20,
+        option_limits: OptionLimits::new(min, max).unwrap(),
+    }
+}
+
+#[rstest]
+#[case(2, 4, true)]
+#[case(3, 3, true)]
+#[case(2, 10, true)]
+#[case(1, 4, false)]
+#[case(4, 3, false)]
+#[case(2, 11, false)]
+fn option_limits_stay_within_floor_and_ceiling(
+    #[case] min: usize,
+    #[case] max: usize,
+    #[case] valid: bool,
+) {
+    assert_eq!(OptionLimits::new(min, max).is_some(), valid);
+}
+
+#[test]
+fn parse_quiz_uses_server_option_limits() {
+    let content = "# Quiz
+? Six options
+- A
+- B
+- C
+- D
+- E
+* F
+";
+    let settings = settings_with_options(2, 6);
+    let quiz = parse_quiz_with_source_map(content, &settings)
+        .result
+        .unwrap();
+    assert_eq!(quiz.questions[0].options.len(), 6);
+
+    let settings = settings_with_options(3, 5);
+    let errors = parse_quiz_with_source_map(content, &settings)
+        .result
+        .unwrap_err();
+    assert_eq!(errors[0].line, 2);
+    assert_eq!(errors[0].message, "Question has 6 options, maximum is 5");
+
+    let errors = parse_quiz_with_source_map(
+        "# Quiz
+? Two
+- A
+* B
+",
+        &settings,
+    )
+    .result
+    .unwrap_err();
+    assert_eq!(errors[0].message, "Question has 2 option(s), minimum is 3");
+}
+
+#[test]
+fn parse_quiz_front_matter_narrows_option_limits() {
+    let content =
+        "---\nmin_options: 3\nmax_options: 3\n---\n# Quiz\n? Q1\n- A\n- B\n* C\n\n? Q2\n- A\n* B\n";
+    let settings = settings_with_options(2, 6);
+    let errors = parse_quiz_with_source_map(content, &settings)
+        .result
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, 11);
+    assert_eq!(errors[0].message, "Question has 2 option(s), minimum is 3");
+}
+
+#[rstest]
+#[case(
+    "max_options: 7",
+    2,
+    "max_options must be between 2 and 6 on this server"
+)]
+#[case(
+    "min_options: 1",
+    2,
+    "min_options must be between 2 and 6 on this server"
+)]
+#[case("min_options: three", 2, "whole number")]
+#[case(
+    "min_options: 5\nmax_options: 4",
+    1,
+    "min_options (5) is greater than max_options (4)"
+)]
+fn parse_quiz_front_matter_option_limit_errors(
+    #[case] fields: &str,
+    #[case] line: usize,
+    #[case] expected: &str,
+) {
+    let content = format!("---\n{fields}\n---\n# Quiz\n? Q1\n- A\n* B\n- C\n- D\n");
+    let errors = parse_quiz_with_source_map(&content, &settings_with_options(2, 6))
+        .result
+        .unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(expected)),
+        "{errors:?}"
+    );
+}
+
+#[rstest]
+#[case(r#"{"min_options": 7}"#, "metadata.min_options", "between 2 and 6")]
+#[case(
+    r#"{"min_options": 4, "max_options": 3}"#,
+    "metadata",
+    "greater than max_options"
+)]
+fn parse_json_metadata_option_limit_errors(
+    #[case] metadata: &str,
+    #[case] path: &str,
+    #[case] expected: &str,
+) {
+    let content = format!(
+        r#"{{"title": "Q", "metadata": {metadata}, "questions": [{{"text": "Q", "options": [{{"text": "A"}}, {{"text": "B"}}, {{"text": "C"}}]}}]}}"#
+    );
+    let errors = lint_quiz_document(
+        QuizFormat::Json,
+        &content,
+        None,
+        &settings_with_options(2, 6),
+    )
+    .quiz
+    .unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.path.as_deref() == Some(path) && e.message.contains(expected)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_csv_uses_server_option_limits() {
+    let content = "question,option a,option b,option c,option d,option e,correct\nQ,A,B,C,D,E,e\n";
+    assert!(parse_quiz_document(QuizFormat::Csv, content, None, 20).is_err());
+    let report = lint_quiz_document(QuizFormat::Csv, content, None, &settings_with_options(2, 5));
+    assert_eq!(report.quiz.unwrap().questions[0].correct_index, 4);
+}
+
+// === JSON / YAML import ===
+
+#[test]
+fn parse_json_document_fills_defaults() {
+    let content = r#"{
+        "title": " Capitals ",
+        "metadata": { "scoring_rule": "fixed_score" },
+        "questions": [
+            { "text": "Capital of France?", "options": [{ "text": "London" }, { "text": "Paris" }], "correct_index": 1 },
+            { "text": "Favourite?", "kind": "poll", "options": [{ "text": "A" }, { "text": "B" }], "time_limit_sec": 30 }
+        ]
+    }"#;
+    let quiz = parse_quiz_document(QuizFormat::Json, content, None, 20).unwrap();
+
+    assert_eq!(quiz.title, "Capitals");
+    assert_eq!(quiz.metadata.scoring_rule, Some(ScoringRule::FixedScore));
+    assert_eq!(quiz.questions[0].correct_index, 1);
+    assert_eq!(quiz.questions[0].time_limit_sec, 20);
+    assert_eq!(quiz.questions[1].kind, QuestionKind::Poll);
+    assert_eq!(quiz.questions[1].time_limit_sec, 30);
+}
+
+#[test]
+fn parse_yaml_document_matches_text_format() {
+    let yaml = "title: Capitals\nquestions:\n  - text: Capital of France?\n    options:\n      - text: London\n      - text: Paris\n    correct_index: 1\n    explanation: Since 987.\n";
+    let text = "# Capitals\n? Capital of France?\n- London\n* Paris\n> Since 987.\n";
+
+    assert_eq!(
+        parse_quiz_document(QuizFormat::Yaml, yaml, None, 20).unwrap(),
+        parse_quiz(text, 20).unwrap()
+    );
+}
+
+#[rstest]
+#[case::no_title(
+    r#"{"title": "", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}]}]}"#,
+    "title",
+    "no title"
+)]
+#[case::no_questions(
+    r#"{"title": "Q", "questions": []}"#,
+    "questions",
+    "no valid questions"
+)]
+#[case::too_many_options(r#"{"title": "Q", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}, {"text": "C"}, {"text": "D"}, {"text": "E"}]}]}"#, "questions[0].options", "maximum is 4")]
+#[case::missing_correct_index(
+    r#"{"title": "Q", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}]}]}"#,
+    "questions[0].correct_index",
+    "no correct_index"
+)]
+#[case::correct_out_of_range(r#"{"title": "Q", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 2}]}"#, "questions[0].correct_index", "does not refer")]
+#[case::empty_option(
+    r#"{"title": "Q", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": " "}]}]}"#,
+    "questions[0].options[1].text",
+    "empty"
+)]
+#[case::unsafe_media(r#"{"title": "Q", "questions": [{"text": "Q", "media": "../x.png", "options": [{"text": "A"}, {"text": "B"}]}]}"#, "questions[0].media", "")]
+#[case::missing_field(
+    r#"{"title": "Q", "questions": [{"options": []}]}"#,
+    "questions[0]",
+    "missing field `text`"
+)]
+#[case::wrong_type(r#"{"title": "Q", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": "one"}]}"#, "questions[0].correct_index", "invalid type")]
+fn parse_json_document_errors_carry_path(
+    #[case] content: &str,
+    #[case] path: &str,
+    #[case] expected: &str,
+) {
+    let errors = parse_quiz_document(QuizFormat::Json, content, None, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.path.as_deref() == Some(path) && e.message.contains(expected)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_yaml_document_syntax_error_has_line() {
+    let yaml = "title: Q\nquestions:\n  - text: [unclosed\n";
+    let errors = parse_quiz_document(QuizFormat::Yaml, yaml, None, 20).unwrap_err();
+    assert!(errors[0].line > 0, "{errors:?}");
+}
+
+#[rstest]
+#[case(Some("quiz.json"), "", QuizFormat::Json)]
+#[case(Some("quiz.YAML"), "", QuizFormat::Yaml)]
+#[case(Some("quiz.txt"), "{", QuizFormat::Text)]
+#[case(None, "  {\"title\": \"Q\"}", QuizFormat::Json)]
+#[case(None, "# Quiz", QuizFormat::Text)]
+#[case(Some("bank.csv"), "Question,Correct", QuizFormat::Csv)]
+fn quiz_format_detection(
+    #[case] file_name: Option<&str>,
+    #[case] content: &str,
+    #[case] expected: QuizFormat,
+) {
+    assert_eq!(QuizFormat::detect(file_name, content), expected);
+}
+
+// === CSV import ===
+
+#[test]
+fn parse_csv_document_with_quoted_fields() {
+    let content = "Question,Option A,Option B,Option C,Correct,Time Limit,Explanation\n\
+\"Capital of France, the country?\",London,Paris,,B,30,\n\
+\"Which line\nbreaks?\",\"one, two\",\"say \"\"hi\"\"\",three,1,,\"Multi\nline\"\n";
+    let quiz =
+        parse_quiz_document(QuizFormat::Csv, content, Some("world_capitals.csv"), 20).unwrap();
+
+    assert_eq!(quiz.title, "world capitals");
+    assert_eq!(quiz.questions.len(), 2);
+
+    let q1 = &quiz.questions[0];
+    assert_eq!(q1.text, "Capital of France, the country?");
+    assert_eq!(q1.options.len(), 2);
+    assert_eq!(q1.correct_index, 1);
+    assert_eq!(q1.time_limit_sec, 30);
+    assert_eq!(q1.explanation, None);
+
+    let q2 = &quiz.questions[1];
+    assert_eq!(q2.text, "Which line\nbreaks?");
+    assert_eq!(q2.options[0].text, "one, two");
+    assert_eq!(q2.options[1].text, "say \"hi\"");
+    assert_eq!(q2.correct_index, 0);
+    assert_eq!(q2.time_limit_sec, 20);
+    assert_eq!(q2.explanation.as_deref(), Some("Multi\nline"));
+}
+
+#[test]
+fn parse_csv_document_matches_text_format() {
+    let csv = "question,option_1,option_2,answer\nCapital of France?,London,Paris,2\n";
+    let text = "# capitals\n? Capital of France?\n- London\n* Paris\n";
+
+    assert_eq!(
+        parse_quiz_document(QuizFormat::Csv, csv, Some("capitals.csv"), 20).unwrap(),
+        parse_quiz(text, 20).unwrap()
+    );
+}
+
+#[rstest]
+#[case::unknown_column(
+    "Question,Option A,Option B,Correct,Notes\nQ,A,B,A,x\n",
+    1,
+    Some("Notes"),
+    "Unknown column"
+)]
+#[case::missing_correct_column("Question,Option A,Option B\nQ,A,B\n", 1, None, "'correct'")]
+#[case::option_gap_column(
+    "Question,Option A,Option C,Correct\nQ,A,C,A\n",
+    1,
+    None,
+    "Missing column 'Option B'"
+)]
+#[case::empty_question(
+    "Question,Option A,Option B,Correct\nQ1,A,B,A\n,A,B,A\n",
+    3,
+    Some("Question"),
+    "Question text is empty"
+)]
+#[case::too_few_options(
+    "Question,Option A,Option B,Correct\nQ,A,,A\n",
+    2,
+    None,
+    "minimum is 2"
+)]
+#[case::option_gap(
+    "Question,Option A,Option B,Option C,Correct\nQ,A,,C,A\n",
+    2,
+    Some("Option B"),
+    "later option is filled"
+)]
+#[case::correct_out_of_range(
+    "Question,Option A,Option B,Correct\nQ,A,B,C\n",
+    2,
+    Some("Correct"),
+    "does not refer"
+)]
+#[case::correct_garbage(
+    "Question,Option A,Option B,Correct\nQ,A,B,yes\n",
+    2,
+    Some("Correct"),
+    "option letter"
+)]
+#[case::correct_missing(
+    "Question,Option A,Option B,Correct\nQ,A,B,\n",
+    2,
+    Some("Correct"),
+    "missing"
+)]
+#[case::bad_time_limit(
+    "Question,Option A,Option B,Correct,Time Limit\nQ,A,B,A,soon\n",
+    2,
+    Some("Time Limit"),
+    "whole number"
+)]
+#[case::time_limit_range(
+    "Question,Option A,Option B,Correct,Time Limit\nQ,A,B,A,5\n",
+    2,
+    Some("Time Limit"),
+    "between 10 and 60"
+)]
+fn parse_csv_document_errors_carry_row_and_column(
+    #[case] content: &str,
+    #[case] row: usize,
+    #[case] column: Option<&str>,
+    #[case] expected: &str,
+) {
+    let errors = parse_quiz_document(QuizFormat::Csv, content, None, 20).unwrap_err();
+    assert!(
+        errors.iter().any(|e| e.row == Some(row)
+            && e.column.as_deref() == column
+            && e.message.contains(expected)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_csv_document_row_after_multiline_field_keeps_row_number() {
+    let content = "Question,Option A,Option B,Correct\n\"Two\nlines\",A,B,A\nQ,A,B,Z\n";
+    let errors = parse_quiz_document(QuizFormat::Csv, content, None, 20).unwrap_err();
+    assert_eq!(errors[0].row, Some(3));
+    assert_eq!(errors[0].line, 4);
+}
+
+// === Escapes and continuation lines ===
+
+#[test]
+fn parse_quiz_sample_fixture_has_single_line_text() {
+    let content = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../fixtures/sample.txt"
+    ))
+    .unwrap();
+    let quiz = parse_quiz(&content, 20).unwrap();
+    assert!(
+        quiz.questions
+            .iter()
+            .all(|q| !q.text.contains('\n') && !q.text.starts_with('\\'))
+    );
+}
+
+#[rstest]
+#[case::option_starting_with_dash("- -5 degrees", "-5 degrees")]
+#[case::option_starting_with_star("- *not* correct", "*not* correct")]
+#[case::option_starting_with_slashes("- // not a comment", "// not a comment")]
+#[case::option_starting_with_pipe("- | not a continuation", "| not a continuation")]
+#[case::escaped_leading_spaces("- \\  indented", "  indented")]
+#[case::escaped_backslash_before_space("- \\\\ x", "\\ x")]
+#[case::backslash_before_marker_kept("- \\-5", "\\-5")]
+#[case::double_backslash_kept("- \\\\n", "\\\\n")]
+#[case::unescaped_backslash_kept("- C:\\temp", "C:\\temp")]
+#[case::backslash_other_char_kept("- \\temp", "\\temp")]
+#[case::lone_backslash_kept("- \\", "\\")]
+#[case::mid_text_backslash_kept("- a \\- b", "a \\- b")]
+fn parse_quiz_option_text_edges(#[case] option_line: &str, #[case] expected: &str) {
+    let content = format!("# Quiz\n? Q\n{option_line}\n* Other\n");
+    let quiz = parse_quiz(&content, 20).unwrap();
+    assert_eq!(quiz.questions[0].options[0].text, expected);
+}
+
+#[test]
+fn parse_quiz_escaped_title_and_question() {
+    let content = "# \\  One\n? \\ \tTwo\n- A\n* B\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.title, "  One");
+    assert_eq!(quiz.questions[0].text, " \tTwo");
+}
+
+#[test]
+fn parse_quiz_baseline_backslash_text_unchanged() {
+    let content = "# \\# One\n? \\?? not a poll\n- \\-5\n* \\\\n\n> \\> see C:\\temp\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.title, "\\# One");
+    let q = &quiz.questions[0];
+    assert_eq!(q.text, "\\?? not a poll");
+    assert_eq!(q.options[0].text, "\\-5");
+    assert_eq!(q.options[1].text, "\\\\n");
+    assert_eq!(q.explanation.as_deref(), Some("\\> see C:\\temp"));
+}
+
+#[test]
+fn parse_quiz_continuation_lines() {
+    let content = "# Quiz
+? What does this print?
+| fn main() {
+|     println!(\"hi\");
+|
+| }
+* hi
+| (with a newline)
+- nothing
+> println! adds
+|   a trailing newline.
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    let q = &quiz.questions[0];
+    assert_eq!(
+        q.text,
+        "What does this print?\nfn main() {\n    println!(\"hi\");\n\n}"
+    );
+    assert_eq!(q.options[0].text, "hi\n(with a newline)");
+    assert_eq!(q.options[1].text, "nothing");
+    assert_eq!(
+        q.explanation.as_deref(),
+        Some("println! adds\n  a trailing newline.")
+    );
+}
+
+#[test]
+fn parse_quiz_continuation_keeps_directive_characters_verbatim() {
+    let content = "# Quiz\n? Q\n|- not an option\n|* nor this\n| // nor a comment\n- A\n* B\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(
+        quiz.questions[0].text,
+        "Q\n- not an option\n* nor this\n// nor a comment"
+    );
+    assert_eq!(quiz.questions[0].options.len(), 2);
+}
+
+#[test]
+fn parse_quiz_media_after_continued_option_attaches_to_option() {
+    let content = "# Quiz\n? Q\n- A\n| more\n@ a.png\n* B\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions[0].options[0].media.as_deref(), Some("a.png"));
+    assert_eq!(quiz.questions[0].media, None);
+}
+
+#[rstest]
+#[case::before_any_question("# Quiz\n| orphan\n? Q\n- A\n* B\n", 2)]
+#[case::after_title("# Quiz\n| subtitle\n? Q\n- A\n* B\n", 2)]
+#[case::after_blank_line("# Quiz\n? Q\n\n| detached\n- A\n* B\n", 4)]
+#[case::after_comment("# Quiz\n? Q\n// note\n| detached\n- A\n* B\n", 4)]
+#[case::after_media("# Quiz\n? Q\n@ a.png\n| caption\n- A\n* B\n", 4)]
+fn parse_quiz_continuation_errors(#[case] content: &str, #[case] line: usize) {
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains("Continuation line")),
+        "{errors:?}"
+    );
+}
+
+// === Question tags ===
+
+#[test]
+fn parse_quiz_question_tags() {
+    let content = "# Quiz\n? Q1\n% geography, easy\n- A\n* B\n% europe, easy\n? Q2\n- A\n* B\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions[0].tags, ["geography", "easy", "europe"]);
+    assert!(quiz.questions[1].tags.is_empty());
+}
+
+#[rstest]
+#[case::before_any_question("# Quiz\n% easy\n? Q\n- A\n* B\n", 2, "before any question")]
+#[case::empty("# Quiz\n? Q\n%  , \n- A\n* B\n", 3, "Tag line is empty")]
+fn parse_quiz_tag_errors(#[case] content: &str, #[case] line: usize, #[case] message: &str) {
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(message)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_structured_and_csv_question_tags() {
+    let json = r#"{"title": "Quiz", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1, "tags": [" easy ", "", "easy", "maths"]}]}"#;
+    let quiz = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap();
+    assert_eq!(quiz.questions[0].tags, ["easy", "maths"]);
+
+    let csv = "Question,Option A,Option B,Correct,Tags\nQ,A,B,B,\"easy, maths, easy\"\nR,A,B,A,\n";
+    let quiz = parse_quiz_document(QuizFormat::Csv, csv, Some("bank.csv"), 20).unwrap();
+    assert_eq!(quiz.questions[0].tags, ["easy", "maths"]);
+    assert!(quiz.questions[1].tags.is_empty());
+}
+
+// === Scoring rule parameters ===
+
+#[test]
+fn parse_structured_scoring_rule_parameters() {
+    let json = r#"{"title": "Quiz", "metadata": {"scoring_rule": {"rule": "fixed_score", "max_points": 250}}, "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1}]}"#;
+    let quiz = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap();
+    assert_eq!(
+        quiz.metadata.scoring_rule,
+        Some(ScoringRule::with_params(RuleParams::FixedScore(
+            FixedScoreParams { max_points: 250 }
+        )))
+    );
+
+    let json = r#"{"title": "Quiz", "rounds": [{"title": "R", "question_count": 1, "scoring_rule": {"rule": "stepped_decay", "step_sec": 0}}], "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1}]}"#;
+    let errors = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap_err();
+    assert_eq!(errors[0].path.as_deref(), Some("rounds[0].scoring_rule"));
+    assert!(errors[0].message.contains("step_sec"), "{errors:?}");
+}
+
+// === Question weights ===
+
+#[test]
+fn parse_quiz_question_weight() {
+    let content = "# Quiz\n? Q1\n! 2\n- A\n* B\n? Q2\n- A\n* B\n! 0.5\n";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions[0].weight, Some(2.0));
+    assert_eq!(quiz.questions[1].weight(), 0.5);
+    assert_eq!(
+        parse_quiz("# Quiz\n? Q\n- A\n* B\n", 20).unwrap().questions[0].weight(),
+        1.0
+    );
+}
+
+#[rstest]
+#[case::before_any_question("# Quiz\n! 2\n? Q\n- A\n* B\n", 2, "before any question")]
+#[case::twice("# Quiz\n? Q\n! 2\n! 3\n- A\n* B\n", 4, "already has a weight")]
+#[case::not_a_number("# Quiz\n? Q\n! double\n- A\n* B\n", 3, "must be a number")]
+#[case::zero("# Quiz\n? Q\n! 0\n- A\n* B\n", 3, "greater than 0")]
+#[case::too_large("# Quiz\n? Q\n! 11\n- A\n* B\n", 3, "at most 10")]
+#[case::poll(
+    "# Quiz\n~ Q\n! 2\n- A\n- B\n",
+    2,
+    "Poll question cannot have a weight"
+)]
+fn parse_quiz_weight_errors(#[case] content: &str, #[case] line: usize, #[case] message: &str) {
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(message)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_structured_and_csv_question_weight() {
+    let json = r#"{"title": "Quiz", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1, "weight": 3}]}"#;
+    let quiz = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap();
+    assert_eq!(quiz.questions[0].weight, Some(3.0));
+
+    let json = r#"{"title": "Quiz", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1, "weight": -1}]}"#;
+    let errors = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap_err();
+    assert_eq!(errors[0].path.as_deref(), Some("questions[0].weight"));
+
+    let csv = "Question,Option A,Option B,Correct,Weight\nQ,A,B,B,2\nR,A,B,A,\n";
+    let quiz = parse_quiz_document(QuizFormat::Csv, csv, Some("bank.csv"), 20).unwrap();
+    assert_eq!(quiz.questions[0].weight, Some(2.0));
+    assert_eq!(quiz.questions[1].weight, None);
+
+    let csv = "Question,Option A,Option B,Correct,Weight\nQ,A,B,B,lots\n";
+    let errors = parse_quiz_document(QuizFormat::Csv, csv, Some("bank.csv"), 20).unwrap_err();
+    assert!(errors[0].message.contains("not a number"), "{errors:?}");
+}
+
+// === Rounds ===
+
+#[test]
+fn parse_quiz_rounds() {
+    let content = "\
+# Pub Quiz
+= Warm-up
+? Q1
+- A
+* B
+? Q2
+- A
+* B
+= Lightning Round [time_limit: 10, scoring_rule: position_race]
+? Q3
+- A
+* B
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.title, "Pub Quiz");
+    assert_eq!(quiz.rounds.len(), 2);
+    assert_eq!(quiz.rounds[0].title, "Warm-up");
+    assert_eq!(quiz.rounds[0].question_count, 2);
+    assert_eq!(quiz.rounds[0].scoring_rule, None);
+    assert_eq!(quiz.rounds[1].title, "Lightning Round");
+    assert_eq!(quiz.rounds[1].question_count, 1);
+    assert_eq!(quiz.rounds[1].scoring_rule, Some(ScoringRule::PositionRace));
+    assert_eq!(quiz.rounds[1].time_limit_sec, Some(10));
+    assert_eq!(quiz.questions[1].time_limit_sec, 20);
+    assert_eq!(quiz.questions[2].time_limit_sec, 10);
+    assert_eq!(quiz.round_ranges(), [0..2, 2..3]);
+    assert_eq!(quiz.round_of(2), Some(1));
+}
+
+#[test]
+fn parse_quiz_without_rounds_has_one_range() {
+    let quiz = parse_quiz("# Quiz\n? Q\n- A\n* B\n", 20).unwrap();
+    assert!(quiz.rounds.is_empty());
+    assert_eq!(quiz.round_ranges(), std::slice::from_ref(&(0..1)));
+    assert_eq!(quiz.round_of(0), None);
+}
+
+#[test]
+fn parse_quiz_hash_headings_do_not_start_sections() {
+    let content = "## Geography Night\n? Q1\n- A\n* B\n## Capitals\n? Q2\n* A\n- B\n### Tiebreaker\n? Q3\n* A\n- B\n";
+    let report = lint_quiz_document(
+        QuizFormat::Text,
+        content,
+        None,
+        &settings_with_options(2, 4),
+    );
+    let quiz = report.quiz.unwrap();
+    assert_eq!(quiz.title, "Geography Night");
+    assert_eq!(quiz.questions.len(), 3);
+    assert!(quiz.rounds.is_empty());
+    assert!(quiz.tiebreakers.is_empty());
+    assert_eq!(report.warnings[0].code, WarningCode::IgnoredTitle);
+}
+
+#[rstest]
+#[case::outside_round(
+    "# Quiz\n? Q\n- A\n* B\n= R\n? Q2\n- A\n* B\n",
+    2,
+    "before the first round"
+)]
+#[case::empty_round("# Quiz\n= R1\n= R2\n? Q\n- A\n* B\n", 2, "'R1' has no questions")]
+#[case::empty_title(
+    "# Quiz\n= [time_limit: 20]\n? Q\n- A\n* B\n",
+    2,
+    "Round title is empty"
+)]
+#[case::unknown_setting(
+    "# Quiz\n= R [points: 2]\n? Q\n- A\n* B\n",
+    2,
+    "Unknown round setting 'points'"
+)]
+#[case::bad_setting("# Quiz\n= R [fast]\n? Q\n- A\n* B\n", 2, "must be 'key: value'")]
+#[case::bad_time_limit("# Quiz\n= R [time_limit: 5]\n? Q\n- A\n* B\n", 2, "between 10 and 60")]
+#[case::bad_rule(
+    "# Quiz\n= R [scoring_rule: fastest]\n? Q\n- A\n* B\n",
+    2,
+    "Unknown scoring_rule"
+)]
+fn parse_quiz_round_errors(#[case] content: &str, #[case] line: usize, #[case] message: &str) {
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(message)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_structured_rounds() {
+    let yaml = "\
+title: Pub Quiz
+rounds:
+  - title: Warm-up
+    question_count: 1
+  - title: Lightning
+    question_count: 1
+    time_limit_sec: 15
+questions:
+  - text: Q1
+    options: [{text: A}, {text: B}]
+    correct_index: 0
+  - text: Q2
+    options: [{text: A}, {text: B}]
+    correct_index: 1
+";
+    let quiz = parse_quiz_document(QuizFormat::Yaml, yaml, None, 20).unwrap();
+    assert_eq!(quiz.rounds.len(), 2);
+    assert_eq!(quiz.questions[0].time_limit_sec, 20);
+    assert_eq!(quiz.questions[1].time_limit_sec, 15);
+
+    let uneven = yaml.replace(
+        "question_count: 1\n  - title: Lightning",
+        "question_count: 2\n  - title: Lightning",
+    );
+    let errors = parse_quiz_document(QuizFormat::Yaml, &uneven, None, 20).unwrap_err();
+    assert!(
+        errors.iter().any(|e| e.path.as_deref() == Some("rounds")
+            && e.message == "Rounds cover 3 questions but the quiz has 2"),
+        "{errors:?}"
+    );
+}
+
+// === Tiebreakers ===
+
+#[test]
+fn parse_quiz_tiebreaker_section() {
+    let content = "\
+# Pub Quiz
+= Lightning [time_limit: 10]
+? Q1
+- A
+* B
+== Tiebreaker
+? T1
+* A
+- B
+? T2
+- A
+* B
+";
+    let quiz = parse_quiz(content, 20).unwrap();
+    assert_eq!(quiz.questions.len(), 1);
+    assert_eq!(quiz.rounds[0].question_count, 1);
+    assert_eq!(quiz.tiebreakers.len(), 2);
+    assert_eq!(quiz.tiebreakers[0].text, "T1");
+    assert_eq!(quiz.tiebreakers[1].correct_index, 1);
+    // Tiebreakers use the quiz time limit, not the last round's
+    assert_eq!(quiz.tiebreakers[0].time_limit_sec, 20);
+}
+
+#[rstest]
+#[case::unknown_section(
+    "# Quiz\n? Q\n- A\n* B\n== Bonus\n? T\n- A\n* B\n",
+    5,
+    "Unknown section"
+)]
+#[case::repeated(
+    "# Quiz\n? Q\n- A\n* B\n== Tiebreaker\n? T\n- A\n* B\n== Tiebreaker\n",
+    9,
+    "already started on line 5"
+)]
+#[case::round_after(
+    "# Quiz\n= R\n? Q\n- A\n* B\n== Tiebreaker\n? T\n- A\n* B\n= R2\n? Q2\n- A\n* B\n",
+    10,
+    "tiebreaker questions must come last"
+)]
+#[case::poll(
+    "# Quiz\n? Q\n- A\n* B\n== Tiebreaker\n~ T\n- A\n- B\n",
+    6,
+    "cannot be a poll"
+)]
+#[case::empty(
+    "# Quiz\n? Q\n- A\n* B\n== Tiebreaker\n",
+    5,
+    "Tiebreaker section has no questions"
+)]
+#[case::only_tiebreakers(
+    "# Quiz\n== Tiebreaker\n? T\n- A\n* B\n",
+    1,
+    "Quiz has no valid questions"
+)]
+fn parse_quiz_tiebreaker_errors(#[case] content: &str, #[case] line: usize, #[case] message: &str) {
+    let errors = parse_quiz(content, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.line == line && e.message.contains(message)),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn parse_structured_tiebreakers() {
+    let json = r#"{"title": "Quiz", "questions": [{"text": "Q", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 1}], "tiebreakers": [{"text": "T", "options": [{"text": "A"}, {"text": "B"}], "correct_index": 0}]}"#;
+    let quiz = parse_quiz_document(QuizFormat::Json, json, None, 20).unwrap();
+    assert_eq!(quiz.tiebreakers.len(), 1);
+    assert_eq!(quiz.tiebreakers[0].time_limit_sec, 20);
+
+    let poll = json.replace(r#""correct_index": 0}]"#, r#""kind": "poll"}]"#);
+    let errors = parse_quiz_document(QuizFormat::Json, &poll, None, 20).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.path.as_deref() == Some("tiebreakers[0].kind")),
+        "{errors:?}"
+    );
+}
@@ -0,0 +1,190 @@
+use quiz_server::models::scoring_rule::{
+    LinearDecayParams, RuleParams, Scorer, ScoringContext, ScoringOutcome, ScoringRule,
+};
+use quiz_server::services::scorer_registry::ScorerRegistry;
+use serde_json::{Value, json};
+
+/// `points` for a correct answer (default 500), nothing otherwise.
+struct FlatRate;
+
+impl Scorer for FlatRate {
+    fn name(&self) -> &str {
+        "flat_rate"
+    }
+
+    fn display_name(&self) -> &str {
+        "Flat Rate"
+    }
+
+    fn validate(&self, params: &Value) -> Result<(), String> {
+        match &params["points"] {
+            Value::Null => Ok(()),
+            points if points.as_u64().is_some_and(|p| p <= 5000) => Ok(()),
+            _ => Err("points must be a whole number up to 5000".into()),
+        }
+    }
+
+    fn score(&self, params: &Value, ctx: &ScoringContext) -> ScoringOutcome {
+        let points = params["points"].as_u64().unwrap_or(500) as u32;
+        ScoringOutcome::points(if ctx.correct { points } else { 0 })
+    }
+}
+
+fn registry() -> ScorerRegistry {
+    let mut registry = ScorerRegistry::default();
+    registry.register(FlatRate).unwrap();
+    registry
+}
+
+fn ctx(correct: bool, weight: f64) -> ScoringContext {
+    ScoringContext {
+        correct,
+        time_taken_ms: 0,
+        time_limit_sec: 20,
+        streak: 0,
+        correct_answer_count: 0,
+        weight,
+    }
+}
+
+#[test]
+fn default_registry_lists_the_builtin_rules() {
+    let registry = ScorerRegistry::default();
+    let names: Vec<_> = registry.iter().map(|s| s.name()).collect();
+    assert_eq!(
+        names,
+        [
+            "stepped_decay",
+            "linear_decay",
+            "fixed_score",
+            "streak_bonus",
+            "position_race",
+            "composite"
+        ]
+    );
+}
+
+#[test]
+fn custom_rules_are_listed_after_the_builtins() {
+    let registry = registry();
+    let last = registry.iter().last().unwrap();
+    assert_eq!(last.name(), "flat_rate");
+    assert_eq!(last.display_name(), "Flat Rate");
+    assert_eq!(last.params_schema()["type"], "object");
+}
+
+#[test]
+fn builtin_schemas_describe_their_parameters() {
+    let registry = ScorerRegistry::default();
+    let schema = registry.get("stepped_decay").unwrap().params_schema();
+    assert_eq!(schema["properties"]["max_points"]["default"], 1000);
+    assert_eq!(schema["properties"]["step_sec"]["default"], 5);
+    let schema = registry.get("position_race").unwrap().params_schema();
+    assert_eq!(schema["properties"]["schedule"]["type"], "array");
+}
+
+#[test]
+fn resolve_builtin_rules_by_name_or_object() {
+    let registry = registry();
+    assert_eq!(
+        registry.resolve(&json!("fixed_score")),
+        Ok(ScoringRule::FixedScore)
+    );
+    assert_eq!(
+        registry.resolve(&json!({"rule": "linear_decay", "max_points": 2000})),
+        Ok(ScoringRule::with_params(RuleParams::LinearDecay(
+            LinearDecayParams { max_points: 2000 }
+        )))
+    );
+    assert!(
+        registry
+            .resolve(&json!({"rule": "linear_decay", "max_points": 0}))
+            .is_err()
+    );
+}
+
+#[test]
+fn resolve_custom_rule_with_parameters() {
+    let rule = registry()
+        .resolve(&json!({"rule": "flat_rate", "points": 800}))
+        .unwrap();
+    assert_eq!(rule.name(), "flat_rate");
+    assert_eq!(rule.display_name(), "Flat Rate");
+    assert_eq!(rule.to_json(), json!({"rule": "flat_rate", "points": 800}));
+    assert_eq!(rule.score(&ctx(true, 1.0)).points, 800);
+    assert_eq!(rule.score(&ctx(false, 1.0)).points, 0);
+}
+
+#[test]
+fn custom_rule_by_bare_name_gets_empty_parameters() {
+    let rule = registry().resolve(&json!("flat_rate")).unwrap();
+    assert_eq!(rule.to_json(), json!({"rule": "flat_rate"}));
+    assert_eq!(rule.score(&ctx(true, 1.0)).points, 500);
+}
+
+#[test]
+fn question_weight_scales_custom_rules_too() {
+    let rule = registry().resolve(&json!("flat_rate")).unwrap();
+    assert_eq!(rule.score(&ctx(true, 2.0)).points, 1000);
+}
+
+#[test]
+fn resolve_runs_the_custom_rule_validation() {
+    let err = registry()
+        .resolve(&json!({"rule": "flat_rate", "points": -1}))
+        .unwrap_err();
+    assert_eq!(err, "points must be a whole number up to 5000");
+}
+
+#[test]
+fn resolve_unknown_rule_lists_registered_names() {
+    let err = registry().resolve(&json!("fastest_finger")).unwrap_err();
+    assert!(err.contains("'fastest_finger'"), "{err}");
+    assert!(err.contains("flat_rate"), "{err}");
+}
+
+#[test]
+fn registering_a_name_twice_fails() {
+    let mut registry = registry();
+    assert_eq!(
+        registry.register(FlatRate).err().as_deref(),
+        Some("scoring rule 'flat_rate' is already registered")
+    );
+    assert_eq!(
+        registry.iter().filter(|s| s.name() == "flat_rate").count(),
+        1
+    );
+}
+
+#[test]
+fn builtin_names_cannot_be_replaced() {
+    struct Impostor;
+    impl Scorer for Impostor {
+        fn name(&self) -> &str {
+            "linear_decay"
+        }
+        fn display_name(&self) -> &str {
+            "Impostor"
+        }
+        fn score(&self, _params: &Value, _ctx: &ScoringContext) -> ScoringOutcome {
+            ScoringOutcome::points(0)
+        }
+    }
+    let mut registry = ScorerRegistry::default();
+    assert_eq!(
+        registry.register(Impostor).err().as_deref(),
+        Some("scoring rule 'linear_decay' is already registered")
+    );
+    assert_eq!(
+        registry.get("linear_decay").unwrap().display_name(),
+        "Linear Decay"
+    );
+}
+
+#[test]
+fn builtin_scorer_scores_the_default_rule() {
+    let registry = ScorerRegistry::default();
+    let scorer = registry.get("fixed_score").unwrap();
+    assert_eq!(scorer.score(&json!({}), &ctx(true, 1.0)).points, 1000);
+    assert_eq!(scorer.score(&json!({}), &ctx(false, 1.0)).points, 0);
+}
@@ -1,14 +1,34 @@
-use quiz_server::models::scoring_rule::{MAX_SCORE, ScoringContext, ScoringRule};
+use quiz_server::models::scoring_rule::{
+    FixedScoreParams, LinearDecayParams, MAX_SCORE, PositionBonusModifier, PositionRaceParams,
+    RuleParams, ScoringContext, ScoringModifier, ScoringRule, SteppedDecayParams,
+    StreakBonusParams, StreakModifier, WrongAnswerPenaltyModifier, linear_decay_points,
+    streak_multiplier,
+};
+use serde_json::json;
 
 fn pts(rule: ScoringRule, correct: bool, time_taken_ms: u64, time_limit_sec: u64) -> u32 {
-    rule.score(&ScoringContext {
-        correct,
+    let points = rule
+        .score(&ScoringContext {
+            correct,
+            time_taken_ms,
+            time_limit_sec,
+            streak: 0,
+            correct_answer_count: 0,
+            weight: 1.0,
+        })
+        .points;
+    u32::try_from(points).expect("rules without a penalty never score below zero")
+}
+
+fn weighted(weight: f64, time_taken_ms: u64) -> ScoringContext {
+    ScoringContext {
+        correct: true,
         time_taken_ms,
-        time_limit_sec,
+        time_limit_sec: 20,
         streak: 0,
         correct_answer_count: 0,
-    })
-    .points
+        weight,
+    }
 }
 
 // ── Wrong answer ──────────────────────────────────────────────────────────────
@@ -109,6 +129,87 @@ fn fixed_score_always_gives_max_regardless_of_time() {
     assert_eq!(pts(ScoringRule::FixedScore, true, 99999, 20), MAX_SCORE);
 }
 
+// ── Question weight ───────────────────────────────────────────────────────────
+
+#[test]
+fn weight_scales_stepped_decay() {
+    // step 1 → 750, doubled
+    let out = ScoringRule::SteppedDecay.score(&weighted(2.0, 5000));
+    assert_eq!(out.points, 1500);
+}
+
+#[test]
+fn weight_scales_linear_decay() {
+    // 3 steps → 850, times 1.5 = 1275
+    let out = ScoringRule::LinearDecay.score(&weighted(1.5, 3000));
+    assert_eq!(out.points, 1275);
+}
+
+#[test]
+fn weight_scales_fixed_score() {
+    assert_eq!(
+        ScoringRule::FixedScore.score(&weighted(3.0, 0)).points,
+        3 * MAX_SCORE as i32
+    );
+    assert_eq!(
+        ScoringRule::FixedScore.score(&weighted(0.5, 0)).points,
+        MAX_SCORE as i32 / 2
+    );
+}
+
+#[test]
+fn weight_scales_streak_bonus_after_the_multiplier() {
+    // streak 2 → ×2.0 → 2000, doubled
+    let out = ScoringRule::StreakBonus.score(&ScoringContext {
+        streak: 2,
+        ..weighted(2.0, 0)
+    });
+    assert_eq!(out.points, 4000);
+    assert!((out.streak_multiplier - 2.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn weight_scales_position_race_and_keeps_position() {
+    // second correct → 750, doubled
+    let out = ScoringRule::PositionRace.score(&ScoringContext {
+        correct_answer_count: 1,
+        ..weighted(2.0, 0)
+    });
+    assert_eq!(out.points, 1500);
+    assert_eq!(out.position, Some(2));
+}
+
+#[test]
+fn weight_rounds_to_the_nearest_point() {
+    // 1000 - 50 × 7 = 650; × 1.25 = 812.5 → 813
+    let out = ScoringRule::LinearDecay.score(&weighted(1.25, 7000));
+    assert_eq!(out.points, 813);
+}
+
+#[test]
+fn small_weight_never_zeroes_a_correct_answer() {
+    // past the time limit → 1 point, which a weight of 0.1 cannot erase
+    let out = ScoringRule::SteppedDecay.score(&weighted(0.1, 99999));
+    assert_eq!(out.points, 1);
+}
+
+#[test]
+fn weight_leaves_wrong_answers_at_zero() {
+    for rule in [
+        ScoringRule::SteppedDecay,
+        ScoringRule::LinearDecay,
+        ScoringRule::FixedScore,
+        ScoringRule::StreakBonus,
+        ScoringRule::PositionRace,
+    ] {
+        let ctx = ScoringContext {
+            correct: false,
+            ..weighted(5.0, 0)
+        };
+        assert_eq!(rule.score(&ctx).points, 0);
+    }
+}
+
 // ── Display names ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -124,3 +225,362 @@ fn display_names_are_correct() {
 fn default_rule_is_stepped_decay() {
     assert_eq!(ScoringRule::default(), ScoringRule::SteppedDecay);
 }
+
+// ── Parameters ────────────────────────────────────────────────────────────────
+
+#[test]
+fn stepped_decay_uses_custom_max_points_and_step() {
+    // 20s limit, 10s steps → 2 steps of 1000 from 2000
+    let rule = ScoringRule::with_params(RuleParams::SteppedDecay(SteppedDecayParams {
+        max_points: 2000,
+        step_sec: 10,
+    }));
+    assert_eq!(pts(rule.clone(), true, 9999, 20), 2000);
+    assert_eq!(pts(rule.clone(), true, 10000, 20), 1000);
+    assert_eq!(pts(rule, true, 20000, 20), 1);
+}
+
+#[test]
+fn linear_and_fixed_use_custom_max_points() {
+    let linear = ScoringRule::with_params(RuleParams::LinearDecay(LinearDecayParams {
+        max_points: 500,
+    }));
+    // step_size = 500 / 20 = 25; 4 steps → 400
+    assert_eq!(pts(linear, true, 4000, 20), 400);
+    let fixed =
+        ScoringRule::with_params(RuleParams::FixedScore(FixedScoreParams { max_points: 100 }));
+    assert_eq!(pts(fixed, true, 15000, 20), 100);
+}
+
+#[test]
+fn streak_bonus_uses_custom_increment_and_cap() {
+    let rule = ScoringRule::with_params(RuleParams::StreakBonus(StreakBonusParams {
+        max_points: 100,
+        streak_increment: 1.0,
+        streak_cap: Some(3.0),
+    }));
+    let score = |streak| {
+        rule.score(&ScoringContext {
+            streak,
+            ..weighted(1.0, 0)
+        })
+    };
+    assert_eq!(score(1).points, 200);
+    assert_eq!(score(2).points, 300);
+    let capped = score(5);
+    assert_eq!(capped.points, 300);
+    assert!((capped.streak_multiplier - 3.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn position_race_uses_custom_schedule() {
+    let rule = ScoringRule::with_params(RuleParams::PositionRace(PositionRaceParams {
+        schedule: vec![300, 200],
+    }));
+    let score = |correct_answer_count| {
+        rule.score(&ScoringContext {
+            correct_answer_count,
+            ..weighted(1.0, 0)
+        })
+        .points
+    };
+    assert_eq!(score(0), 300);
+    assert_eq!(score(1), 200);
+    assert_eq!(score(7), 200);
+}
+
+#[test]
+fn default_parameters_match_the_original_constants() {
+    assert_eq!(
+        ScoringRule::SteppedDecay,
+        ScoringRule::with_params(RuleParams::SteppedDecay(SteppedDecayParams {
+            max_points: MAX_SCORE,
+            step_sec: 5,
+        }))
+    );
+    assert_eq!(
+        PositionRaceParams::default().schedule,
+        [1000, 750, 500, 250]
+    );
+    assert_eq!(StreakBonusParams::default().streak_increment, 0.5);
+    assert_eq!(StreakBonusParams::default().streak_cap, None);
+}
+
+#[test]
+fn non_default_parameters_keep_the_rule_identity() {
+    let rule = ScoringRule::with_params(RuleParams::StreakBonus(StreakBonusParams {
+        max_points: 500,
+        ..Default::default()
+    }));
+    assert!(matches!(rule, ScoringRule::WithParams(_)));
+    assert_ne!(rule, ScoringRule::StreakBonus);
+    assert_eq!(rule.name(), "streak_bonus");
+    assert_eq!(rule.display_name(), "Streak Bonus");
+    assert!(rule.uses_streak());
+    assert_eq!(
+        ScoringRule::StreakBonus.params(),
+        Some(RuleParams::StreakBonus(StreakBonusParams::default()))
+    );
+}
+
+// ── Serialization ─────────────────────────────────────────────────────────────
+
+#[test]
+fn default_rule_serializes_as_its_name() {
+    for name in ScoringRule::NAMES {
+        let rule = ScoringRule::from_name(name).unwrap();
+        assert_eq!(rule.name(), name);
+        assert_eq!(serde_json::to_value(&rule).unwrap(), json!(name));
+        assert_eq!(
+            serde_json::from_value::<ScoringRule>(json!(name)).unwrap(),
+            rule
+        );
+    }
+}
+
+#[test]
+fn custom_rule_serializes_as_tagged_object() {
+    let rule = ScoringRule::with_params(RuleParams::LinearDecay(LinearDecayParams {
+        max_points: 2000,
+    }));
+    let value = serde_json::to_value(&rule).unwrap();
+    assert_eq!(value, json!({"rule": "linear_decay", "max_points": 2000}));
+    assert_eq!(serde_json::from_value::<ScoringRule>(value).unwrap(), rule);
+}
+
+#[test]
+fn object_form_defaults_missing_parameters() {
+    let rule: ScoringRule =
+        serde_json::from_value(json!({"rule": "stepped_decay", "step_sec": 10})).unwrap();
+    assert_eq!(
+        rule,
+        ScoringRule::with_params(RuleParams::SteppedDecay(SteppedDecayParams {
+            max_points: MAX_SCORE,
+            step_sec: 10,
+        }))
+    );
+    assert_eq!(
+        serde_json::from_value::<ScoringRule>(json!({"rule": "fixed_score"})).unwrap(),
+        ScoringRule::FixedScore
+    );
+}
+
+#[test]
+fn unknown_rule_or_parameter_is_rejected() {
+    assert!(serde_json::from_value::<ScoringRule>(json!("fastest_finger")).is_err());
+    assert!(serde_json::from_value::<ScoringRule>(json!({"rule": "fastest_finger"})).is_err());
+    assert!(
+        serde_json::from_value::<ScoringRule>(json!({"rule": "fixed_score", "step_sec": 3}))
+            .is_err()
+    );
+}
+
+#[test]
+fn to_json_spells_out_every_parameter() {
+    assert_eq!(
+        ScoringRule::SteppedDecay.to_json(),
+        json!({"rule": "stepped_decay", "max_points": 1000, "step_sec": 5})
+    );
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn default_rules_are_valid() {
+    for name in ScoringRule::NAMES {
+        assert_eq!(ScoringRule::from_name(name).unwrap().validate(), Ok(()));
+    }
+}
+
+#[test]
+fn out_of_range_parameters_are_rejected() {
+    let invalid = [
+        (
+            json!({"rule": "fixed_score", "max_points": 0}),
+            "max_points",
+        ),
+        (
+            json!({"rule": "linear_decay", "max_points": 10001}),
+            "max_points",
+        ),
+        (json!({"rule": "stepped_decay", "step_sec": 0}), "step_sec"),
+        (json!({"rule": "stepped_decay", "step_sec": 61}), "step_sec"),
+        (
+            json!({"rule": "streak_bonus", "streak_increment": -0.5}),
+            "streak_increment",
+        ),
+        (
+            json!({"rule": "streak_bonus", "streak_cap": 0.5}),
+            "streak_cap",
+        ),
+        (json!({"rule": "position_race", "schedule": []}), "schedule"),
+        (
+            json!({"rule": "position_race", "schedule": [500, 0]}),
+            "schedule",
+        ),
+        (
+            json!({"rule": "position_race", "schedule": [500, 900]}),
+            "schedule",
+        ),
+    ];
+    for (value, field) in invalid {
+        let rule: ScoringRule = serde_json::from_value(value.clone()).unwrap();
+        let message = rule.validate().unwrap_err();
+        assert!(message.contains(field), "{value}: {message}");
+    }
+}
+
+// ── Composite rules ───────────────────────────────────────────────────────────
+
+fn linear_with_streak() -> ScoringRule {
+    ScoringRule::composite(
+        ScoringRule::LinearDecay,
+        vec![ScoringModifier::Streak(StreakModifier::default())],
+    )
+}
+
+#[test]
+fn composite_multiplies_decayed_points_by_streak() {
+    // 3s → 850 linear points, streak 2 → ×2.0
+    let out = linear_with_streak().score(&ScoringContext {
+        streak: 2,
+        ..weighted(1.0, 3000)
+    });
+    assert_eq!(out.points, 1700);
+    assert!((out.streak_multiplier - 2.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn composite_without_modifiers_matches_its_base() {
+    let composite = ScoringRule::composite(ScoringRule::SteppedDecay, vec![]);
+    for ms in [0, 5000, 12000, 30000] {
+        assert_eq!(
+            pts(composite.clone(), true, ms, 20),
+            pts(ScoringRule::SteppedDecay, true, ms, 20)
+        );
+    }
+}
+
+#[test]
+fn composite_position_bonus_adds_points_for_early_answers() {
+    let rule = ScoringRule::composite(
+        ScoringRule::FixedScore,
+        vec![ScoringModifier::PositionBonus(PositionBonusModifier {
+            schedule: vec![300, 100],
+        })],
+    );
+    let score = |correct_answer_count| {
+        rule.score(&ScoringContext {
+            correct_answer_count,
+            ..weighted(1.0, 0)
+        })
+    };
+    assert_eq!(score(0).points, 1300);
+    assert_eq!(score(0).position, Some(1));
+    assert_eq!(score(1).points, 1100);
+    assert_eq!(score(2).points, 1000);
+    assert_eq!(score(2).position, Some(3));
+}
+
+#[test]
+fn composite_penalty_applies_only_to_wrong_answers() {
+    let rule = ScoringRule::composite(
+        ScoringRule::FixedScore,
+        vec![ScoringModifier::WrongAnswerPenalty(
+            WrongAnswerPenaltyModifier { points: 200 },
+        )],
+    );
+    let right = rule.score(&weighted(1.0, 0));
+    assert_eq!((right.points, right.penalty), (1000, 0));
+    let wrong = rule.score(&ScoringContext {
+        correct: false,
+        ..weighted(2.0, 0)
+    });
+    // the penalty comes off the points, and the question's weight scales it too
+    assert_eq!((wrong.points, wrong.penalty), (-400, 400));
+}
+
+#[test]
+fn composite_weight_applies_once_after_modifiers() {
+    let out = linear_with_streak().score(&ScoringContext {
+        streak: 1,
+        ..weighted(2.0, 0)
+    });
+    // 1000 × 1.5 × 2
+    assert_eq!(out.points, 3000);
+}
+
+#[test]
+fn composite_display_name_lists_base_and_modifiers() {
+    assert_eq!(linear_with_streak().display_name(), "Linear Decay + Streak");
+    let rule = ScoringRule::composite(
+        ScoringRule::SteppedDecay,
+        vec![
+            ScoringModifier::PositionBonus(PositionBonusModifier::default()),
+            ScoringModifier::WrongAnswerPenalty(WrongAnswerPenaltyModifier::default()),
+        ],
+    );
+    assert_eq!(
+        rule.display_name(),
+        "Stepped Decay + Position Bonus + Wrong-Answer Penalty"
+    );
+}
+
+#[test]
+fn composite_round_trips_through_json() {
+    let value = json!({
+        "rule": "composite",
+        "base": "linear_decay",
+        "modifiers": [{"modifier": "streak", "streak_cap": 3.0}]
+    });
+    let rule: ScoringRule = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        rule,
+        ScoringRule::composite(
+            ScoringRule::LinearDecay,
+            vec![ScoringModifier::Streak(StreakModifier {
+                streak_increment: 0.5,
+                streak_cap: Some(3.0),
+            })],
+        )
+    );
+    let back: ScoringRule = serde_json::from_value(serde_json::to_value(&rule).unwrap()).unwrap();
+    assert_eq!(back, rule);
+    assert_eq!(rule.to_json()["base"], "linear_decay");
+}
+
+#[test]
+fn composite_validation() {
+    let invalid = [
+        json!({"rule": "composite", "base": {"rule": "composite", "base": "fixed_score"}}),
+        json!({"rule": "composite", "base": {"rule": "fixed_score", "max_points": 0}}),
+        json!({"rule": "composite", "base": "fixed_score",
+               "modifiers": [{"modifier": "streak"}, {"modifier": "streak"}]}),
+        json!({"rule": "composite", "base": "fixed_score",
+               "modifiers": [{"modifier": "position_bonus", "schedule": []}]}),
+    ];
+    for value in invalid {
+        let rule: ScoringRule = serde_json::from_value(value.clone()).unwrap();
+        assert!(rule.validate().is_err(), "{value}");
+    }
+    assert!(serde_json::from_value::<ScoringRule>(json!({"rule": "composite"})).is_err());
+    assert_eq!(linear_with_streak().validate(), Ok(()));
+}
+
+#[test]
+fn uses_streak_covers_streak_bonus_and_streak_modifier() {
+    assert!(ScoringRule::StreakBonus.uses_streak());
+    assert!(linear_with_streak().uses_streak());
+    assert!(!ScoringRule::LinearDecay.uses_streak());
+    assert!(!ScoringRule::composite(ScoringRule::LinearDecay, vec![]).uses_streak());
+}
+
+#[test]
+fn building_blocks_match_the_rules_built_from_them() {
+    assert_eq!(
+        linear_decay_points(&LinearDecayParams::default(), 3000, 20),
+        pts(ScoringRule::LinearDecay, true, 3000, 20)
+    );
+    assert_eq!(streak_multiplier(3, 0.5, None), 2.5);
+    assert_eq!(streak_multiplier(3, 0.5, Some(2.0)), 2.0);
+}
@@ -1,5 +1,6 @@
-use quiz_server::config::AppConfig;
-use quiz_server::models::quiz::{Question, Quiz, QuizOption};
+use quiz_server::config::{AppConfig, DEFAULT_QUIZ_LIBRARY_DIR};
+use quiz_server::models::quiz::{OptionLimits, Question, Quiz, QuizMetadata, QuizOption};
+use quiz_server::models::scoring_rule::ScoringRule;
 use quiz_server::services::session_manager::SessionManager;
 
 fn test_config(max_sessions: usize) -> AppConfig {
@@ -9,7 +10,10 @@ fn test_config(max_sessions: usize) -> AppConfig {
         max_players: 50,
         question_time_sec: 20,
         reconnect_timeout_sec: 120,
+        max_upload_bytes: 10 * 1024 * 1024,
         static_dir: None,
+        option_limits: OptionLimits::default(),
+        quiz_library_dir: DEFAULT_QUIZ_LIBRARY_DIR.into(),
     }
 }
 
@@ -19,13 +23,24 @@ fn sample_quiz() -> Quiz {
         questions: vec![Question {
             text: "What is 1+1?".into(),
             options: vec![
-                QuizOption { text: "1".into() },
-                QuizOption { text: "2".into() },
-                QuizOption { text: "3".into() },
+                QuizOption {
+                    text: "1".into(),
+                    ..Default::default()
+                },
+                QuizOption {
+                    text: "2".into(),
+                    ..Default::default()
+                },
+                QuizOption {
+                    text: "3".into(),
+                    ..Default::default()
+                },
             ],
             correct_index: 1,
             time_limit_sec: 20,
+            ..Default::default()
         }],
+        ..Default::default()
     }
 }
 
@@ -118,3 +133,28 @@ fn get_nonexistent_quiz_returns_none() {
     let mgr = SessionManager::new(test_config(10));
     assert!(mgr.get_quiz("nope").is_none());
 }
+
+#[tokio::test]
+async fn create_session_seeds_settings_from_front_matter() {
+    let mgr = SessionManager::new(test_config(10));
+    let mut quiz = sample_quiz();
+    quiz.metadata = QuizMetadata {
+        scoring_rule: Some(ScoringRule::StreakBonus),
+        time_limit_sec: Some(45),
+        ..Default::default()
+    };
+
+    let session = mgr.create_session(quiz).unwrap();
+    let session = session.read().await;
+    assert_eq!(session.scoring_rule, ScoringRule::StreakBonus);
+    assert_eq!(session.time_limit_sec, 45);
+}
+
+#[tokio::test]
+async fn create_session_without_front_matter_uses_defaults() {
+    let mgr = SessionManager::new(test_config(10));
+    let session = mgr.create_session(sample_quiz()).unwrap();
+    let session = session.read().await;
+    assert_eq!(session.scoring_rule, ScoringRule::default());
+    assert_eq!(session.time_limit_sec, 20);
+}
@@ -0,0 +1,111 @@
+use quiz_server::models::player::Player;
+use quiz_server::models::quiz::{Question, Quiz, QuizOption};
+use quiz_server::models::session::GameSession;
+use quiz_server::models::shuffle::{MAX_SEED, ShuffleSettings, permutation, random_seed};
+use rstest::rstest;
+
+fn settings(questions: bool, options: bool, seed: u64) -> ShuffleSettings {
+    ShuffleSettings {
+        questions,
+        options,
+        seed,
+    }
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(4)]
+#[case(100)]
+fn permutation_contains_every_index_once(#[case] len: usize) {
+    let mut order = permutation(len, 12345);
+    order.sort_unstable();
+    assert_eq!(order, (0..len).collect::<Vec<_>>());
+}
+
+#[test]
+fn permutation_is_reproducible_from_seed() {
+    assert_eq!(permutation(20, 99), permutation(20, 99));
+    assert_ne!(permutation(20, 99), permutation(20, 100));
+}
+
+#[test]
+fn unshuffled_settings_keep_quiz_order() {
+    let off = settings(false, false, 7);
+    assert_eq!(off.question_order(5), [0, 1, 2, 3, 4]);
+    assert_eq!(off.option_order("player-1", 0, 4), [0, 1, 2, 3]);
+}
+
+#[test]
+fn option_order_differs_between_players_and_questions() {
+    let on = settings(false, true, 7);
+    let orders: Vec<Vec<usize>> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .flat_map(|player| (0..4).map(move |q| on.option_order(player, q, 10)))
+        .collect();
+    assert_eq!(on.option_order("a", 0, 10), orders[0]);
+    let distinct: std::collections::HashSet<_> = orders.iter().collect();
+    assert!(distinct.len() > orders.len() / 2, "{orders:?}");
+}
+
+#[test]
+fn option_order_is_reproducible_from_seed_and_display_name() {
+    let order_for = |player_id: &str| {
+        let quiz = Quiz {
+            questions: vec![Question {
+                text: "Q1".into(),
+                options: ["A", "B", "C", "D", "E", "F"]
+                    .iter()
+                    .map(|t| QuizOption {
+                        text: t.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut session = GameSession::new("TSTCDE".into(), quiz, 20);
+        session.shuffle = settings(false, true, 7);
+        session.players.insert(
+            player_id.into(),
+            Player::new(player_id.into(), "Alice".into(), "🙂".into()),
+        );
+        session.option_order(player_id, 0)
+    };
+    // Player IDs are fresh on every join, so a replay sees different ones
+    assert_eq!(order_for("first-game-id"), order_for("replay-id"));
+    assert_eq!(
+        order_for("first-game-id"),
+        settings(false, true, 7).option_order("Alice", 0, 6)
+    );
+}
+
+#[test]
+fn random_seeds_fit_in_a_javascript_number() {
+    for _ in 0..100 {
+        assert!(random_seed() <= MAX_SEED);
+    }
+}
+
+#[test]
+fn question_order_keeps_questions_within_rounds() {
+    let rounds = [0..3, 3..4, 4..9];
+    for seed in 0..20 {
+        let order = settings(true, false, seed).question_order_in_rounds(&rounds);
+        assert_eq!(order.len(), 9);
+        for range in &rounds {
+            let mut within: Vec<usize> = order[range.clone()].to_vec();
+            within.sort_unstable();
+            assert_eq!(within, range.clone().collect::<Vec<_>>(), "seed {seed}");
+        }
+    }
+    assert_eq!(
+        settings(true, false, 5).question_order_in_rounds(std::slice::from_ref(&(0..6))),
+        settings(true, false, 5).question_order(6)
+    );
+    assert_eq!(
+        settings(false, false, 5).question_order_in_rounds(&rounds),
+        (0..9).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,166 @@
+use quiz_server::models::leaderboard::compute_team_leaderboard;
+use quiz_server::models::player::{Answer, Player};
+use quiz_server::models::team::{
+    MAX_TEAMS, TeamAggregation, TeamSettings, balance_teams, validate_team_name,
+};
+use rstest::rstest;
+
+/// A player who scored `points[i]` on question `i`.
+fn make_player(name: &str, points: &[i32], correct_count: u32, team: Option<&str>) -> Player {
+    let mut p = Player::new(format!("id-{name}"), name.to_string(), "🙂".to_string());
+    p.answers = points
+        .iter()
+        .enumerate()
+        .map(|(question_index, &points_awarded)| Answer {
+            question_index,
+            selected_index: 0,
+            time_taken_ms: 0,
+            points_awarded,
+        })
+        .collect();
+    p.score = points.iter().sum();
+    p.correct_count = correct_count;
+    p.team = team.map(str::to_string);
+    p
+}
+
+fn teams(names: &[&str], aggregation: TeamAggregation) -> TeamSettings {
+    TeamSettings {
+        names: names.iter().map(|n| n.to_string()).collect(),
+        aggregation,
+    }
+}
+
+#[rstest]
+#[case(TeamAggregation::Sum, 900)]
+#[case(TeamAggregation::Average, 300)]
+#[case(TeamAggregation::Best, 500)]
+fn aggregation_combines_member_points(#[case] aggregation: TeamAggregation, #[case] want: i64) {
+    assert_eq!(aggregation.aggregate(&[500, 300, 100]), want);
+}
+
+#[test]
+fn aggregation_of_no_members_is_zero() {
+    assert_eq!(TeamAggregation::Best.aggregate(&[]), 0);
+    assert_eq!(TeamAggregation::Average.aggregate(&[]), 0);
+}
+
+#[test]
+fn join_finds_existing_team_ignoring_case() {
+    let mut settings = teams(&["Red"], TeamAggregation::Sum);
+    assert_eq!(settings.join("  red "), Ok("Red".to_string()));
+    assert_eq!(settings.join("Blue"), Ok("Blue".to_string()));
+    assert_eq!(settings.names, ["Red", "Blue"]);
+    assert!(settings.is_team_game());
+}
+
+#[test]
+fn join_refuses_a_team_past_the_limit() {
+    let names: Vec<String> = (0..MAX_TEAMS).map(|i| format!("Team {i}")).collect();
+    let mut settings = TeamSettings {
+        names,
+        aggregation: TeamAggregation::Sum,
+    };
+    assert!(settings.join("One too many").is_err());
+    assert_eq!(settings.join("team 3"), Ok("Team 3".to_string()));
+}
+
+#[rstest]
+#[case("")]
+#[case("   ")]
+#[case("A team name that is far too long to show")]
+fn invalid_team_names_are_rejected(#[case] name: &str) {
+    assert!(validate_team_name(name).is_err());
+}
+
+#[rstest]
+#[case(0, 3)]
+#[case(7, 3)]
+#[case(8, 2)]
+#[case(2, 5)]
+fn balanced_team_sizes_differ_by_at_most_one(#[case] players: usize, #[case] team_count: usize) {
+    let assignment = balance_teams(players, team_count, 42);
+    assert_eq!(assignment.len(), players);
+    let mut sizes = vec![0; team_count];
+    for team in assignment {
+        sizes[team] += 1;
+    }
+    let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+    assert!(max - min <= 1, "sizes {sizes:?}");
+}
+
+#[test]
+fn team_leaderboard_ranks_teams_by_aggregated_score() {
+    let alice = make_player("Alice", &[500], 2, Some("Red"));
+    let bob = make_player("Bob", &[100], 1, Some("Red"));
+    let carol = make_player("Carol", &[400], 2, Some("Blue"));
+    let dave = make_player("Dave", &[900], 3, None);
+
+    let players = [&alice, &bob, &carol, &dave];
+    let sum = compute_team_leaderboard(
+        &players,
+        &teams(&["Red", "Blue"], TeamAggregation::Sum),
+        true,
+    );
+    assert_eq!(sum.len(), 2);
+    assert_eq!(sum[0].team, "Red");
+    assert_eq!(sum[0].score, 600);
+    assert_eq!(sum[0].correct_count, 3);
+    assert_eq!(sum[0].member_count, 2);
+    assert!(sum[0].is_winner);
+    assert_eq!(sum[1].team, "Blue");
+    assert_eq!(sum[1].rank, 2);
+    assert!(!sum[1].is_winner);
+
+    let average = compute_team_leaderboard(
+        &players,
+        &teams(&["Red", "Blue"], TeamAggregation::Average),
+        false,
+    );
+    assert_eq!(average[0].team, "Blue");
+    assert_eq!(average[0].score, 400);
+    assert_eq!(average[1].score, 300);
+}
+
+#[test]
+fn team_leaderboard_leaves_out_empty_teams() {
+    let alice = make_player("Alice", &[500], 2, Some("Red"));
+    let board = compute_team_leaderboard(
+        &[&alice],
+        &teams(&["Red", "Blue"], TeamAggregation::Sum),
+        false,
+    );
+    assert_eq!(board.len(), 1);
+    assert_eq!(board[0].team, "Red");
+}
+
+#[test]
+fn solo_game_has_no_team_leaderboard() {
+    let alice = make_player("Alice", &[500], 2, None);
+    let board = compute_team_leaderboard(&[&alice], &TeamSettings::default(), true);
+    assert!(board.is_empty());
+}
+
+#[test]
+fn best_of_takes_the_best_member_on_each_question() {
+    let alice = make_player("Alice", &[1000, 0, 500], 2, Some("Red"));
+    let bob = make_player("Bob", &[0, 800, 200], 2, Some("Red"));
+    let board = compute_team_leaderboard(
+        &[&alice, &bob],
+        &teams(&["Red"], TeamAggregation::Best),
+        false,
+    );
+    assert_eq!(board[0].score, 2300);
+}
+
+#[test]
+fn team_score_saturates_instead_of_overflowing() {
+    let alice = make_player("Alice", &[i32::MAX], 1, Some("Red"));
+    let bob = make_player("Bob", &[i32::MAX], 1, Some("Red"));
+    let board = compute_team_leaderboard(
+        &[&alice, &bob],
+        &teams(&["Red"], TeamAggregation::Sum),
+        false,
+    );
+    assert_eq!(board[0].score, i32::MAX);
+}
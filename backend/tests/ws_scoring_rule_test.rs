@@ -4,7 +4,10 @@ use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpListener;
 use tokio_tungstenite::tungstenite::Message;
 
-use quiz_server::config::AppConfig;
+use quiz_server::config::{AppConfig, DEFAULT_QUIZ_LIBRARY_DIR};
+use quiz_server::models::quiz::OptionLimits;
+use quiz_server::models::scoring_rule::{Scorer, ScoringContext, ScoringOutcome};
+use quiz_server::services::scorer_registry::ScorerRegistry;
 use quiz_server::services::session_manager::SessionManager;
 
 fn test_config() -> AppConfig {
@@ -14,14 +17,21 @@ fn test_config() -> AppConfig {
         max_players: 50,
         question_time_sec: 30,
         reconnect_timeout_sec: 120,
+        max_upload_bytes: 10 * 1024 * 1024,
         static_dir: None,
+        option_limits: OptionLimits::default(),
+        quiz_library_dir: DEFAULT_QUIZ_LIBRARY_DIR.into(),
     }
 }
 
 async fn start_test_server() -> String {
+    start_test_server_with(ScorerRegistry::default()).await
+}
+
+async fn start_test_server_with(scorers: ScorerRegistry) -> String {
     let config = test_config();
     let session_manager = SessionManager::new(config.clone());
-    let app = quiz_server::build_router(session_manager, config);
+    let app = quiz_server::build_router_with_scorers(session_manager, config, scorers);
 
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
@@ -231,3 +241,91 @@ async fn player_cannot_set_scoring_rule() {
         assert_ne!(json["type"].as_str(), Some("scoring_rule_set"));
     }
 }
+
+#[tokio::test]
+async fn host_can_set_scoring_rule_parameters() {
+    let addr = start_test_server().await;
+    let quiz_id = upload_quiz(&addr, "# Test Quiz\n? Q1\n- Wrong\n* Right\n").await;
+    let join_code = create_session(&addr, &quiz_id).await;
+
+    let (mut host_ws, _) =
+        tokio_tungstenite::connect_async(format!("ws://{addr}/ws/host/{join_code}"))
+            .await
+            .unwrap();
+
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({
+            "type": "set_scoring_rule",
+            "payload": {"rule": "streak_bonus", "max_points": 500, "streak_cap": 2.0}
+        }),
+    )
+    .await;
+    let set = recv_of_type(&mut host_ws, "scoring_rule_set").await;
+    assert_eq!(set["payload"]["rule"], "streak_bonus");
+    assert_eq!(set["payload"]["max_points"], 500);
+    assert_eq!(set["payload"]["streak_increment"], 0.5);
+    assert_eq!(set["payload"]["streak_cap"], 2.0);
+
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({
+            "type": "set_scoring_rule",
+            "payload": {"rule": "streak_bonus", "streak_cap": 0.5}
+        }),
+    )
+    .await;
+    let error = recv_of_type(&mut host_ws, "error").await;
+    assert_eq!(error["payload"]["code"], "invalid_scoring_rule");
+}
+
+/// 100 points per second left on the clock.
+struct Countdown;
+
+impl Scorer for Countdown {
+    fn name(&self) -> &str {
+        "countdown"
+    }
+
+    fn display_name(&self) -> &str {
+        "Countdown"
+    }
+
+    fn score(&self, _params: &serde_json::Value, ctx: &ScoringContext) -> ScoringOutcome {
+        let left = ctx.time_limit_sec.saturating_sub(ctx.time_taken_ms / 1000);
+        ScoringOutcome::points(if ctx.correct { left as u32 * 100 } else { 0 })
+    }
+}
+
+#[tokio::test]
+async fn host_can_pick_a_registered_custom_rule() {
+    let mut scorers = ScorerRegistry::default();
+    scorers.register(Countdown).unwrap();
+    let addr = start_test_server_with(scorers).await;
+    let quiz_id = upload_quiz(&addr, "# Test Quiz\n? Q1\n- Wrong\n* Right\n").await;
+    let join_code = create_session(&addr, &quiz_id).await;
+
+    let rules: serde_json::Value = reqwest::get(format!("http://{addr}/api/scoring-rules"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let listed = rules.as_array().unwrap().last().unwrap();
+    assert_eq!(listed["name"], "countdown");
+    assert_eq!(listed["display_name"], "Countdown");
+
+    let (mut host_ws, _) =
+        tokio_tungstenite::connect_async(format!("ws://{addr}/ws/host/{join_code}"))
+            .await
+            .unwrap();
+
+    send_msg(
+        &mut host_ws,
+        serde_json::json!({"type": "set_scoring_rule", "payload": {"rule": "countdown"}}),
+    )
+    .await;
+    let set = recv_of_type(&mut host_ws, "scoring_rule_set").await;
+    assert_eq!(set["payload"]["rule"], "countdown");
+    assert_eq!(set["payload"]["display_name"], "Countdown");
+}
@@ -1,4 +1,5 @@
 import type { GameState } from "../hooks/useGameState";
+import { EliminationNote, Movement } from "./Leaderboard";
 import { Button } from "./ui/Button";
 import { Card } from "./ui/Card";
 import { neonBoxShadow } from "./ui/neon";
@@ -11,11 +12,15 @@ interface HostDashboardProps {
 }
 
 export function HostDashboard({ gameState, onEndQuestion }: HostDashboardProps) {
-	const { currentQuestion, answerCount, leaderboard } = gameState;
+	const { currentQuestion, answerCount, leaderboard, teamLeaderboard } = gameState;
 
 	if (!currentQuestion) return null;
 
 	const answeredRatio = answerCount ? answerCount.answered / answerCount.total : 0;
+	const distribution =
+		gameState.answerDistribution?.question_index === currentQuestion.question_index
+			? gameState.answerDistribution
+			: null;
 
 	return (
 		<Card style={{ maxWidth: "600px", width: "100%" }}>
@@ -35,6 +40,11 @@ export function HostDashboard({ gameState, onEndQuestion }: HostDashboardProps)
 					}}
 				>
 					Question {currentQuestion.question_index + 1} of {currentQuestion.total_questions}
+					{currentQuestion.weight !== 1 && (
+						<strong style={{ color: colors.primary, marginLeft: spacing.xs }}>
+							×{currentQuestion.weight} points
+						</strong>
+					)}
 				</span>
 				<Timer
 					key={currentQuestion.question_index}
@@ -49,28 +59,46 @@ export function HostDashboard({ gameState, onEndQuestion }: HostDashboardProps)
 					fontFamily: typography.fontBody,
 					marginBottom: spacing.lg,
 					textAlign: "center",
+					whiteSpace: "pre-wrap",
 				}}
 			>
 				{currentQuestion.text}
 			</h2>
 			<div style={{ marginBottom: spacing.lg }}>
-				{currentQuestion.options.map((option) => (
+				{currentQuestion.options.map((option, i) => (
 					<div
 						key={option}
 						style={{
+							display: "flex",
+							justifyContent: "space-between",
 							padding: spacing.md,
 							marginBottom: spacing.sm,
 							backgroundColor: colors.surface,
 							borderRadius: borderRadius.md,
-							color: colors.text,
+							color: distribution?.correct_index === i ? colors.success : colors.text,
 							fontFamily: typography.fontBody,
 							fontSize: typography.sizes.xl,
 							border: `1px solid ${colors.borderDim}`,
+							whiteSpace: "pre-wrap",
 						}}
 					>
-						{option}
+						<span>{option}</span>
+						{distribution && (
+							<span style={{ fontFamily: typography.fontDisplay }}>{distribution.picks[i]}</span>
+						)}
 					</div>
 				))}
+				{distribution && (
+					<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+						{distribution.correct_percentage !== null &&
+							`${distribution.correct_percentage}% correct · `}
+						{distribution.average_time_ms !== null &&
+							`avg ${(distribution.average_time_ms / 1000).toFixed(1)}s · `}
+						{distribution.median_time_ms !== null &&
+							`median ${(distribution.median_time_ms / 1000).toFixed(1)}s · `}
+						{distribution.no_answer} did not answer
+					</p>
+				)}
 			</div>
 			{answerCount && (
 				<div style={{ marginBottom: spacing.lg }}>
@@ -115,6 +143,20 @@ export function HostDashboard({ gameState, onEndQuestion }: HostDashboardProps)
 					>
 						Standings
 					</h3>
+					<EliminationNote result={gameState.eliminationResult} />
+					{teamLeaderboard.length > 0 && (
+						<p
+							aria-label="Team standings"
+							style={{
+								color: colors.textSecondary,
+								fontSize: typography.sizes.sm,
+								marginTop: 0,
+								marginBottom: spacing.sm,
+							}}
+						>
+							{teamLeaderboard.map((team) => `#${team.rank} ${team.team} ${team.score}`).join(" · ")}
+						</p>
+					)}
 					{leaderboard.slice(0, 5).map((entry) => (
 						<div
 							key={`${entry.rank}-${entry.display_name}`}
@@ -136,7 +178,8 @@ export function HostDashboard({ gameState, onEndQuestion }: HostDashboardProps)
 									fontSize: typography.sizes.sm,
 								}}
 							>
-								{entry.score} pts
+								{entry.score} pts{" "}
+								<Movement pointsGained={entry.points_gained} rankChange={entry.rank_change} />
 							</span>
 						</div>
 					))}
@@ -9,13 +9,15 @@ import { borderRadius, colors, spacing, typography } from "./ui/tokens";
 const DEFAULT_AVATAR = "🙂";
 
 interface JoinFormProps {
-	onJoined: (info: SessionInfo, displayName: string, avatar: string) => void;
+	/** `team` is empty when the player left it blank. */
+	onJoined: (info: SessionInfo, displayName: string, avatar: string, team: string) => void;
 }
 
 export function JoinForm({ onJoined }: JoinFormProps) {
 	const [joinCode, setJoinCode] = useState("");
 	const [displayName, setDisplayName] = useState("");
 	const [avatar, setAvatar] = useState(DEFAULT_AVATAR);
+	const [team, setTeam] = useState("");
 	const [isModalOpen, setIsModalOpen] = useState(false);
 	const [loading, setLoading] = useState(false);
 	const [error, setError] = useState<string | null>(null);
@@ -47,7 +49,7 @@ export function JoinForm({ onJoined }: JoinFormProps) {
 
 		try {
 			const info = await getSession(joinCode.trim().toUpperCase());
-			onJoined(info, displayName.trim(), avatar);
+			onJoined(info, displayName.trim(), avatar, team.trim());
 		} catch (err) {
 			const apiError = err as ApiError;
 			setError(apiError.message || "Failed to join game");
@@ -172,6 +174,39 @@ export function JoinForm({ onJoined }: JoinFormProps) {
 						}}
 					/>
 				</div>
+				<label
+					htmlFor="team"
+					style={{
+						display: "block",
+						color: colors.textSecondary,
+						fontSize: typography.sizes.sm,
+						marginBottom: spacing.xs,
+					}}
+				>
+					Team (optional)
+				</label>
+				<input
+					id="team"
+					type="text"
+					value={team}
+					onChange={(e) => setTeam(e.target.value)}
+					placeholder="Join or start a team"
+					autoComplete="off"
+					maxLength={30}
+					style={{
+						display: "block",
+						width: "100%",
+						padding: spacing.md,
+						marginBottom: spacing.lg,
+						fontSize: typography.sizes.md,
+						border: `2px solid ${colors.border}`,
+						borderRadius: "8px",
+						backgroundColor: colors.background,
+						color: colors.text,
+						fontFamily: typography.fontBody,
+						boxSizing: "border-box",
+					}}
+				/>
 				<Button onClick={handleSubmit} loading={loading} style={{ width: "100%" }}>
 					Join Game
 				</Button>
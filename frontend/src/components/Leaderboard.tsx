@@ -1,4 +1,9 @@
-import type { LeaderboardEntryPayload } from "../services/messages";
+import type {
+	LeaderboardEntryPayload,
+	PlayersEliminatedPayload,
+	TeamLeaderboardEntryPayload,
+	TieBreaker,
+} from "../services/messages";
 import { Podium } from "./Podium";
 import { Card } from "./ui/Card";
 import { colors, spacing, typography } from "./ui/tokens";
@@ -6,9 +11,75 @@ import { colors, spacing, typography } from "./ui/tokens";
 interface LeaderboardProps {
 	entries: LeaderboardEntryPayload[];
 	isFinal: boolean;
+	/** Replaces the default heading, e.g. for a round's standings. */
+	title?: string;
+	/** How equal scores were ordered; noted under the final standings. */
+	tieBreaker?: TieBreaker;
+	/** Team standings, shown above the players' in a team game. */
+	teamEntries?: TeamLeaderboardEntryPayload[];
 }
 
-export function Leaderboard({ entries, isFinal }: LeaderboardProps) {
+const TIE_BREAKER_NOTES: Record<TieBreaker, string | null> = {
+	none: null,
+	answer_time: "Ties broken by total answer time",
+	correct_count: "Ties broken by number of correct answers",
+	sudden_death: "First-place ties broken by sudden death",
+};
+
+interface MovementProps {
+	pointsGained?: number;
+	rankChange?: number;
+}
+
+/** "+850 ↑3": points gained and places climbed since the previous question. */
+export function Movement({ pointsGained, rankChange }: MovementProps) {
+	if (pointsGained === undefined) return null;
+	return (
+		<span style={{ fontSize: typography.sizes.sm, fontFamily: typography.fontBody }}>
+			<span style={{ color: pointsGained < 0 ? colors.error : colors.textSecondary }}>
+				{pointsGained < 0 ? "−" : "+"}
+				{Math.abs(pointsGained)}
+			</span>
+			{rankChange !== undefined && rankChange !== 0 && (
+				<span
+					style={{
+						color: rankChange > 0 ? colors.success : colors.error,
+						marginLeft: spacing.xs,
+					}}
+					aria-label={rankChange > 0 ? `up ${rankChange}` : `down ${-rankChange}`}
+				>
+					{rankChange > 0 ? "↑" : "↓"}
+					{Math.abs(rankChange)}
+				</span>
+			)}
+		</span>
+	);
+}
+
+/** Who went out or lost a life on the last question of an elimination game. */
+export function EliminationNote({ result }: { result: PlayersEliminatedPayload | null }) {
+	if (!result) return null;
+	const names = (players: { display_name: string; avatar: string }[]) =>
+		players.map((p) => `${p.avatar} ${p.display_name}`).join(", ");
+	return (
+		<p
+			aria-live="polite"
+			style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}
+		>
+			{result.eliminated.length > 0 ? `Out: ${names(result.eliminated)}. ` : "Nobody went out. "}
+			{result.lost_life.length > 0 && `Lost a life: ${names(result.lost_life)}. `}
+			{result.remaining} still in.
+		</p>
+	);
+}
+
+export function Leaderboard({
+	entries,
+	isFinal,
+	title,
+	tieBreaker = "none",
+	teamEntries = [],
+}: LeaderboardProps) {
 	return (
 		<Card
 			style={{ maxWidth: "500px", width: "100%" }}
@@ -23,8 +94,35 @@ export function Leaderboard({ entries, isFinal }: LeaderboardProps) {
 					textAlign: "center",
 				}}
 			>
-				{isFinal ? "Final Results" : "Leaderboard"}
+				{title ?? (isFinal ? "Final Results" : "Leaderboard")}
 			</h2>
+			{teamEntries.length > 0 && (
+				<ul aria-label="Team standings" style={{ listStyle: "none", padding: 0, margin: 0 }}>
+					{teamEntries.map((team) => (
+						<li
+							key={team.team}
+							aria-label={`Rank ${team.rank}: team ${team.team}, ${team.score} points`}
+							style={{
+								display: "flex",
+								justifyContent: "space-between",
+								padding: spacing.sm,
+								borderBottom: `1px solid ${colors.borderDim}`,
+								color: team.rank === 1 ? colors.winner : colors.text,
+								fontFamily: typography.fontDisplay,
+							}}
+						>
+							<span>
+								#{team.rank} {team.team}
+								{team.is_winner && " ★"}{" "}
+								<span style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+									({team.member_count} player{team.member_count !== 1 ? "s" : ""})
+								</span>
+							</span>
+							<span style={{ fontWeight: typography.weights.bold }}>{team.score}</span>
+						</li>
+					))}
+				</ul>
+			)}
 			{isFinal && <Podium entries={entries} />}
 			<ul style={{ listStyle: "none", padding: 0, margin: 0 }}>
 				{entries.map((entry) => (
@@ -39,6 +137,7 @@ export function Leaderboard({ entries, isFinal }: LeaderboardProps) {
 							borderBottom: `1px solid ${colors.borderDim}`,
 							borderLeft: entry.rank === 1 ? `3px solid ${colors.winner}` : `3px solid transparent`,
 							backgroundColor: entry.rank === 1 ? `${colors.winner}0a` : "transparent",
+							opacity: entry.eliminated ? 0.6 : 1,
 						}}
 					>
 						<div style={{ display: "flex", alignItems: "center", gap: spacing.md }}>
@@ -69,7 +168,9 @@ export function Leaderboard({ entries, isFinal }: LeaderboardProps) {
 								<p
 									style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}
 								>
-									{entry.correct_count} correct
+									{entry.eliminated && "Out · "}
+									{entry.correct_count} correct{" "}
+									<Movement pointsGained={entry.points_gained} rankChange={entry.rank_change} />
 								</p>
 							</div>
 						</div>
@@ -86,6 +187,19 @@ export function Leaderboard({ entries, isFinal }: LeaderboardProps) {
 					</li>
 				))}
 			</ul>
+			{isFinal && TIE_BREAKER_NOTES[tieBreaker] && (
+				<p
+					style={{
+						color: colors.textSecondary,
+						fontSize: typography.sizes.sm,
+						marginTop: spacing.md,
+						marginBottom: 0,
+						textAlign: "center",
+					}}
+				>
+					{TIE_BREAKER_NOTES[tieBreaker]}
+				</p>
+			)}
 		</Card>
 	);
 }
@@ -1,6 +1,15 @@
-import { useState } from "react";
+import { useEffect, useState } from "react";
 import type { GameState } from "../hooks/useGameState";
-import type { ScoringRuleName } from "../services/messages";
+import { type QuestionDraw, getScoringRules } from "../services/api";
+import type {
+	EliminationSettings,
+	ScoringRuleName,
+	SetScoringRulePayload,
+	SetShufflePayload,
+	SetTeamsPayload,
+	TeamAggregation,
+	TieBreaker,
+} from "../services/messages";
 import { Button } from "./ui/Button";
 import { Card } from "./ui/Card";
 import { colors, spacing, typography } from "./ui/tokens";
@@ -10,12 +19,25 @@ interface LobbyProps {
 	gameState: GameState;
 	isHost: boolean;
 	onStartGame?: () => void;
-	onScoringRuleChange?: (rule: ScoringRuleName) => void;
+	onScoringRuleChange?: (rule: SetScoringRulePayload) => void;
 	timeLimitSec?: number;
 	onTimeLimitChange?: (seconds: number) => void;
+	onShuffleChange?: (shuffle: SetShufflePayload) => void;
+	onTieBreakerChange?: (tieBreaker: TieBreaker) => void;
+	onEliminationChange?: (elimination: EliminationSettings) => void;
+	onTeamsChange?: (teams: SetTeamsPayload) => void;
+	onAutoBalanceTeams?: () => void;
+	/** Set when the session plays a subset of the quiz. */
+	draw?: QuestionDraw | null;
 }
 
-const SCORING_RULES: { value: ScoringRuleName; label: string; description: string }[] = [
+const SCORING_RULES: {
+	value: ScoringRuleName;
+	label: string;
+	description: string;
+	/** Sent instead of the bare rule name when the option needs parameters. */
+	payload?: SetScoringRulePayload;
+}[] = [
 	{ value: "stepped_decay", label: "Stepped Decay", description: "Score drops every 5 seconds" },
 	{ value: "linear_decay", label: "Linear Decay", description: "Score drops every second" },
 	{ value: "fixed_score", label: "Fixed Score", description: "Full points regardless of time" },
@@ -29,8 +51,31 @@ const SCORING_RULES: { value: ScoringRuleName; label: string; description: strin
 		label: "Position Race",
 		description: "Points by answer order: 1st→1000, 2nd→750, 3rd→500, 4th+→250",
 	},
+	{
+		value: "composite",
+		label: "Linear Decay + Streak",
+		description: "Score drops every second, multiplied by the answer streak",
+		payload: { rule: "composite", base: "linear_decay", modifiers: [{ modifier: "streak" }] },
+	},
+];
+
+const TIE_BREAKERS: { value: TieBreaker; label: string }[] = [
+	{ value: "none", label: "None — equal scores share a place" },
+	{ value: "answer_time", label: "Total answer time — faster wins" },
+	{ value: "correct_count", label: "Correct answers — more wins" },
+	{ value: "sudden_death", label: "Sudden death — tied leaders answer tiebreaker questions" },
+];
+
+const TEAM_AGGREGATIONS: { value: TeamAggregation; label: string }[] = [
+	{ value: "sum", label: "Sum — every member's points count" },
+	{ value: "average", label: "Average — team size does not matter" },
+	{ value: "best", label: "Best — the top member's score" },
 ];
 
+function scoringRulePayload(value: ScoringRuleName): SetScoringRulePayload {
+	return SCORING_RULES.find((rule) => rule.value === value)?.payload ?? { rule: value };
+}
+
 export function Lobby({
 	joinCode,
 	gameState,
@@ -39,7 +84,44 @@ export function Lobby({
 	onScoringRuleChange,
 	timeLimitSec = 20,
 	onTimeLimitChange,
+	onShuffleChange,
+	onTieBreakerChange,
+	onEliminationChange,
+	onTeamsChange,
+	onAutoBalanceTeams,
+	draw,
 }: LobbyProps) {
+	const { shuffle, teams, elimination } = gameState;
+	const aggregation = teams?.aggregation ?? "sum";
+	const [teamNames, setTeamNames] = useState("");
+
+	const handleSetTeams = () => {
+		const names = teamNames
+			.split(",")
+			.map((name) => name.trim())
+			.filter((name) => name !== "");
+		onTeamsChange?.({ names, aggregation });
+	};
+
+	// Rules the server offers beyond the built-in ones
+	const [customRules, setCustomRules] = useState<typeof SCORING_RULES>([]);
+	useEffect(() => {
+		if (!isHost) return;
+		getScoringRules()
+			.then((rules) =>
+				setCustomRules(
+					rules
+						.filter((rule) => !SCORING_RULES.some((known) => known.value === rule.name))
+						.map((rule) => ({
+							value: rule.name,
+							label: rule.display_name,
+							description: "Provided by this server",
+						})),
+				),
+			)
+			.catch(() => setCustomRules([]));
+	}, [isHost]);
+
 	const [localTimeLimit, setLocalTimeLimit] = useState<string>(String(timeLimitSec));
 
 	const timeLimitNum = Number(localTimeLimit);
@@ -156,7 +238,9 @@ export function Lobby({
 					<select
 						id="scoring_rule"
 						value={gameState.scoringRule}
-						onChange={(e) => onScoringRuleChange?.(e.target.value as ScoringRuleName)}
+						onChange={(e) =>
+							onScoringRuleChange?.(scoringRulePayload(e.target.value as ScoringRuleName))
+						}
 						style={{
 							width: "100%",
 							padding: `${spacing.xs} ${spacing.sm}`,
@@ -168,7 +252,7 @@ export function Lobby({
 							cursor: "pointer",
 						}}
 					>
-						{SCORING_RULES.map((rule) => (
+						{[...SCORING_RULES, ...customRules].map((rule) => (
 							<option key={rule.value} value={rule.value}>
 								{rule.label} — {rule.description}
 							</option>
@@ -234,6 +318,320 @@ export function Lobby({
 					)}
 				</fieldset>
 			)}
+			{isHost && (
+				<fieldset
+					aria-label="Shuffle"
+					style={{
+						border: `1px solid ${colors.border}`,
+						borderRadius: "8px",
+						padding: spacing.md,
+						marginBottom: spacing.md,
+						textAlign: "left",
+					}}
+				>
+					<legend
+						style={{
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							padding: `0 ${spacing.xs}`,
+						}}
+					>
+						Shuffle
+					</legend>
+					<label style={{ display: "block", color: colors.text, marginBottom: spacing.xs }}>
+						<input
+							type="checkbox"
+							checked={shuffle.questions}
+							onChange={(e) =>
+								onShuffleChange?.({ ...shuffle, questions: e.target.checked, seed: undefined })
+							}
+						/>{" "}
+						Question order
+					</label>
+					<label style={{ display: "block", color: colors.text, marginBottom: spacing.xs }}>
+						<input
+							type="checkbox"
+							checked={shuffle.options}
+							onChange={(e) =>
+								onShuffleChange?.({ ...shuffle, options: e.target.checked, seed: undefined })
+							}
+						/>{" "}
+						Answer order (different for each player)
+					</label>
+					{(shuffle.questions || shuffle.options) && (
+						<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+							Seed {shuffle.seed}
+						</p>
+					)}
+				</fieldset>
+			)}
+			{isHost && (
+				<div
+					style={{
+						border: `1px solid ${colors.border}`,
+						borderRadius: "8px",
+						padding: spacing.md,
+						marginBottom: spacing.md,
+						textAlign: "left",
+					}}
+				>
+					<label
+						htmlFor="tie_breaker"
+						style={{
+							display: "block",
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							marginBottom: spacing.xs,
+						}}
+					>
+						Tie-Breaker
+					</label>
+					<select
+						id="tie_breaker"
+						value={gameState.tieBreaker}
+						onChange={(e) => onTieBreakerChange?.(e.target.value as TieBreaker)}
+						style={{
+							width: "100%",
+							padding: `${spacing.xs} ${spacing.sm}`,
+							background: colors.surface,
+							color: colors.text,
+							border: `1px solid ${colors.border}`,
+							borderRadius: "4px",
+							fontSize: typography.sizes.md,
+							cursor: "pointer",
+						}}
+					>
+						{TIE_BREAKERS.map((tieBreaker) => (
+							<option key={tieBreaker.value} value={tieBreaker.value}>
+								{tieBreaker.label}
+							</option>
+						))}
+					</select>
+				</div>
+			)}
+			{!isHost && elimination.enabled && (
+				<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+					Elimination game: {elimination.lives} {elimination.lives === 1 ? "life" : "lives"}
+					{elimination.max_answer_sec !== null &&
+						`, answer within ${elimination.max_answer_sec} s`}
+				</p>
+			)}
+			{isHost && (
+				<fieldset
+					aria-label="Elimination"
+					style={{
+						border: `1px solid ${colors.border}`,
+						borderRadius: "8px",
+						padding: spacing.md,
+						marginBottom: spacing.md,
+						textAlign: "left",
+					}}
+				>
+					<legend
+						style={{
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							padding: `0 ${spacing.xs}`,
+						}}
+					>
+						Elimination
+					</legend>
+					<label style={{ display: "block", color: colors.text, marginBottom: spacing.xs }}>
+						<input
+							type="checkbox"
+							checked={elimination.enabled}
+							onChange={(e) => onEliminationChange?.({ ...elimination, enabled: e.target.checked })}
+						/>{" "}
+						Knock out players who miss a question
+					</label>
+					{elimination.enabled && (
+						<div style={{ display: "flex", gap: spacing.md, color: colors.textSecondary }}>
+							<label style={{ fontSize: typography.sizes.sm }}>
+								Lives{" "}
+								<input
+									type="number"
+									min={1}
+									max={5}
+									value={elimination.lives}
+									onChange={(e) =>
+										onEliminationChange?.({ ...elimination, lives: Number(e.target.value) })
+									}
+									style={{ width: "56px" }}
+								/>
+							</label>
+							<label style={{ fontSize: typography.sizes.sm }}>
+								Answer within{" "}
+								<input
+									type="number"
+									min={1}
+									max={60}
+									placeholder="any"
+									value={elimination.max_answer_sec ?? ""}
+									onChange={(e) =>
+										onEliminationChange?.({
+											...elimination,
+											max_answer_sec: e.target.value === "" ? null : Number(e.target.value),
+										})
+									}
+									style={{ width: "56px" }}
+								/>{" "}
+								s
+							</label>
+						</div>
+					)}
+				</fieldset>
+			)}
+			{teams && teams.teams.length > 0 && (
+				<ul
+					aria-label="Teams"
+					style={{
+						marginBottom: spacing.lg,
+						textAlign: "left",
+						listStyle: "none",
+						padding: 0,
+					}}
+				>
+					{teams.teams.map((team) => (
+						<li
+							key={team.name}
+							style={{
+								padding: spacing.sm,
+								borderBottom: `1px solid ${colors.borderDim}`,
+								color: colors.text,
+							}}
+						>
+							<strong>{team.name}</strong>{" "}
+							<span style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+								{team.members.length > 0
+									? team.members.map((m) => m.display_name).join(", ")
+									: "No players yet"}
+							</span>
+						</li>
+					))}
+				</ul>
+			)}
+			{isHost && (
+				<fieldset
+					aria-label="Teams"
+					style={{
+						border: `1px solid ${colors.border}`,
+						borderRadius: "8px",
+						padding: spacing.md,
+						marginBottom: spacing.md,
+						textAlign: "left",
+					}}
+				>
+					<legend
+						style={{
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							padding: `0 ${spacing.xs}`,
+						}}
+					>
+						Teams
+					</legend>
+					<div style={{ display: "flex", gap: spacing.sm, marginBottom: spacing.sm }}>
+						<input
+							type="text"
+							aria-label="Team names"
+							placeholder="Red, Blue — leave empty to play solo"
+							value={teamNames}
+							onChange={(e) => setTeamNames(e.target.value)}
+							style={{
+								flex: 1,
+								padding: `${spacing.xs} ${spacing.sm}`,
+								border: `1px solid ${colors.border}`,
+								borderRadius: "4px",
+								backgroundColor: colors.surface,
+								color: colors.text,
+								fontSize: typography.sizes.md,
+								fontFamily: typography.fontBody,
+							}}
+						/>
+						<Button variant="secondary" onClick={handleSetTeams}>
+							Set
+						</Button>
+					</div>
+					<select
+						aria-label="Team score"
+						value={aggregation}
+						onChange={(e) =>
+							onTeamsChange?.({
+								names: teams?.teams.map((team) => team.name) ?? [],
+								aggregation: e.target.value as TeamAggregation,
+							})
+						}
+						style={{
+							width: "100%",
+							padding: `${spacing.xs} ${spacing.sm}`,
+							background: colors.surface,
+							color: colors.text,
+							border: `1px solid ${colors.border}`,
+							borderRadius: "4px",
+							fontSize: typography.sizes.md,
+							cursor: "pointer",
+							marginBottom: spacing.sm,
+						}}
+					>
+						{TEAM_AGGREGATIONS.map((option) => (
+							<option key={option.value} value={option.value}>
+								{option.label}
+							</option>
+						))}
+					</select>
+					<Button
+						variant="secondary"
+						onClick={onAutoBalanceTeams}
+						disabled={!teams || teams.teams.length < 2}
+						style={{ width: "100%" }}
+					>
+						Auto-balance players
+					</Button>
+				</fieldset>
+			)}
+			{isHost && draw && (
+				<fieldset
+					aria-label="Questions Drawn"
+					style={{
+						border: `1px solid ${colors.border}`,
+						borderRadius: "8px",
+						padding: spacing.md,
+						marginBottom: spacing.md,
+						textAlign: "left",
+					}}
+				>
+					<legend
+						style={{
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							padding: `0 ${spacing.xs}`,
+						}}
+					>
+						Questions Drawn
+					</legend>
+					<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+						{draw.questions.length} of {draw.bank_size} questions, seed {draw.seed}
+					</p>
+					<ol
+						style={{
+							color: colors.text,
+							fontSize: typography.sizes.sm,
+							maxHeight: "160px",
+							overflowY: "auto",
+							marginBottom: 0,
+						}}
+					>
+						{draw.questions.map((q) => (
+							<li key={q.bank_index} value={q.bank_index + 1}>
+								{q.text}
+								{q.tags.length > 0 && (
+									<span style={{ color: colors.textSecondary }}> ({q.tags.join(", ")})</span>
+								)}
+							</li>
+						))}
+					</ol>
+				</fieldset>
+			)}
 			{isHost && (
 				<Button
 					onClick={onStartGame}
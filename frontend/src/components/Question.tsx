@@ -5,12 +5,13 @@ import { Card } from "./ui/Card";
 import { Timer } from "./ui/Timer";
 import { borderRadius, colors, spacing, typography } from "./ui/tokens";
 
-const SCORING_RULE_LABELS: Record<ScoringRuleName, string> = {
+const SCORING_RULE_LABELS: Record<string, string> = {
 	stepped_decay: "Stepped Decay",
 	linear_decay: "Linear Decay",
 	fixed_score: "Fixed Score",
 	streak_bonus: "Streak Bonus",
 	position_race: "Position Race",
+	composite: "Combined",
 };
 
 // Handles 1st/2nd/3rd correctly; falls back to "Nth" for N≥4.
@@ -32,12 +33,19 @@ interface QuestionProps {
 	answerResult?: {
 		correct: boolean;
 		points_awarded: number;
-		correct_index: number;
+		correct_index: number | null;
 		streak_multiplier: number;
 		position?: number;
+		penalty?: number;
 	} | null;
 	phase: "question" | "question_ended";
 	scoringRule: ScoringRuleName;
+	/** Server-built label, e.g. "Linear Decay + Streak"; preferred over the rule name. */
+	scoringRuleDisplayName?: string;
+	/** Points multiplier; anything but 1 is announced as a bonus. */
+	weight?: number;
+	/** Eliminated players watch without answering. */
+	spectating?: boolean;
 }
 
 export function Question({
@@ -50,6 +58,9 @@ export function Question({
 	answerResult,
 	phase,
 	scoringRule,
+	scoringRuleDisplayName,
+	weight = 1,
+	spectating = false,
 }: QuestionProps) {
 	const [selectedIndex, setSelectedIndex] = useState<number | null>(null);
 	const prefersReducedMotion = useReducedMotion();
@@ -62,7 +73,7 @@ export function Question({
 	const hasAnswered = selectedIndex !== null;
 
 	const handleSelect = (index: number) => {
-		if (hasAnswered) return;
+		if (hasAnswered || spectating) return;
 		setSelectedIndex(index);
 		onAnswer(index);
 	};
@@ -110,6 +121,7 @@ export function Question({
 			textAlign: "left",
 			width: "100%",
 			fontFamily: typography.fontBody,
+			whiteSpace: "pre-wrap",
 		};
 	};
 
@@ -139,7 +151,12 @@ export function Question({
 						fontFamily: typography.fontBody,
 					}}
 				>
-					{SCORING_RULE_LABELS[scoringRule]}
+					{scoringRuleDisplayName ?? SCORING_RULE_LABELS[scoringRule] ?? scoringRule}
+					{weight !== 1 && (
+						<strong style={{ color: colors.primary, marginLeft: spacing.xs }}>
+							×{weight} points
+						</strong>
+					)}
 				</span>
 				<Timer
 					key={questionIndex}
@@ -154,6 +171,7 @@ export function Question({
 					fontFamily: typography.fontBody,
 					marginBottom: spacing.lg,
 					textAlign: "center",
+					whiteSpace: "pre-wrap",
 				}}
 			>
 				{text}
@@ -164,7 +182,7 @@ export function Question({
 						key={option}
 						type="button"
 						onClick={() => handleSelect(i)}
-						disabled={hasAnswered}
+						disabled={hasAnswered || spectating}
 						style={getOptionStyle(i)}
 						aria-label={`Answer option ${i + 1}: ${option}`}
 					>
@@ -198,7 +216,7 @@ export function Question({
 					>
 						{answerResult.correct ? "Correct!" : "Incorrect"}
 					</p>
-					{scoringRule === "streak_bonus" &&
+					{(scoringRule === "streak_bonus" || scoringRule === "composite") &&
 						answerResult.correct &&
 						answerResult.streak_multiplier > 1.0 && (
 							<p
@@ -212,7 +230,7 @@ export function Question({
 								×{answerResult.streak_multiplier.toFixed(1)} streak
 							</p>
 						)}
-					{scoringRule === "position_race" &&
+					{(scoringRule === "position_race" || scoringRule === "composite") &&
 						answerResult.correct &&
 						answerResult.position != null && (
 							<p
@@ -233,7 +251,9 @@ export function Question({
 							margin: `${spacing.xs} 0 0`,
 						}}
 					>
-						+{answerResult.points_awarded} pts
+						{answerResult.points_awarded < 0 ? "−" : "+"}
+						{Math.abs(answerResult.points_awarded)} pts
+						{(answerResult.penalty ?? 0) > 0 && " (wrong-answer penalty)"}
 					</p>
 				</div>
 			)}
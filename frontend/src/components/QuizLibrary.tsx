@@ -0,0 +1,65 @@
+import { useEffect, useState } from "react";
+import type { LibraryQuizSummary } from "../services/api";
+import { listLibraryQuizzes } from "../services/api";
+import { Button } from "./ui/Button";
+import { Card } from "./ui/Card";
+import { colors, spacing, typography } from "./ui/tokens";
+
+interface QuizLibraryProps {
+	onHost: (quiz: LibraryQuizSummary) => void;
+}
+
+/** Saved quizzes a host can start a game from without re-uploading. */
+export function QuizLibrary({ onHost }: QuizLibraryProps) {
+	const [quizzes, setQuizzes] = useState<LibraryQuizSummary[]>([]);
+
+	useEffect(() => {
+		listLibraryQuizzes()
+			.then(setQuizzes)
+			.catch(() => setQuizzes([]));
+	}, []);
+
+	if (quizzes.length === 0) {
+		return null;
+	}
+
+	return (
+		<Card style={{ maxWidth: "500px", width: "100%", marginTop: spacing.lg }}>
+			<h2
+				style={{
+					color: colors.text,
+					fontSize: typography.sizes.lg,
+					fontFamily: typography.fontDisplay,
+					marginBottom: spacing.md,
+				}}
+			>
+				Saved Quizzes
+			</h2>
+			<ul aria-label="Saved quizzes" style={{ listStyle: "none", padding: 0, margin: 0 }}>
+				{quizzes.map((quiz) => (
+					<li
+						key={quiz.id}
+						style={{
+							display: "flex",
+							alignItems: "center",
+							justifyContent: "space-between",
+							gap: spacing.md,
+							marginBottom: spacing.sm,
+						}}
+					>
+						<span style={{ color: colors.text }}>
+							{quiz.title}
+							<span style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+								{" "}
+								· {quiz.question_count} questions · v{quiz.version}
+							</span>
+						</span>
+						<Button variant="secondary" onClick={() => onHost(quiz)}>
+							Host
+						</Button>
+					</li>
+				))}
+			</ul>
+		</Card>
+	);
+}
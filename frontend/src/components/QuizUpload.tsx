@@ -1,6 +1,6 @@
-import { useRef, useState } from "react";
-import type { ApiError, QuizPreview } from "../services/api";
-import { uploadQuiz } from "../services/api";
+import { useEffect, useRef, useState } from "react";
+import type { ApiError, ParseErrorMessage, QuizPreview, ServerInfo } from "../services/api";
+import { getServerInfo, uploadQuiz } from "../services/api";
 import { Button } from "./ui/Button";
 import { Card } from "./ui/Card";
 import { colors, spacing, typography } from "./ui/tokens";
@@ -9,12 +9,26 @@ interface QuizUploadProps {
 	onQuizUploaded: (preview: QuizPreview) => void;
 }
 
+function errorLocation(e: ParseErrorMessage): string {
+	if (e.row !== undefined) {
+		return e.column ? `Row ${e.row}, column "${e.column}"` : `Row ${e.row}`;
+	}
+	return e.path ?? `Line ${e.line}`;
+}
+
 export function QuizUpload({ onQuizUploaded }: QuizUploadProps) {
 	const [loading, setLoading] = useState(false);
 	const [error, setError] = useState<string | null>(null);
-	const [errors, setErrors] = useState<{ line: number; message: string }[]>([]);
+	const [errors, setErrors] = useState<ParseErrorMessage[]>([]);
+	const [serverInfo, setServerInfo] = useState<ServerInfo | null>(null);
 	const fileInputRef = useRef<HTMLInputElement>(null);
 
+	useEffect(() => {
+		getServerInfo()
+			.then(setServerInfo)
+			.catch(() => setServerInfo(null));
+	}, []);
+
 	const handleUpload = async () => {
 		const file = fileInputRef.current?.files?.[0];
 		if (!file) {
@@ -55,11 +69,13 @@ export function QuizUpload({ onQuizUploaded }: QuizUploadProps) {
 			</h2>
 			<p style={{ color: colors.textSecondary, marginBottom: spacing.lg }}>
 				Select a .txt quiz file to upload
+				{serverInfo &&
+					` (${serverInfo.option_limits.min}–${serverInfo.option_limits.max} options per question)`}
 			</p>
 			<input
 				ref={fileInputRef}
 				type="file"
-				accept=".txt,text/plain"
+				accept=".txt,text/plain,.json,application/json,.yaml,.yml,.csv,text/csv,.zip,application/zip"
 				aria-label="Quiz file"
 				style={{
 					display: "block",
@@ -90,8 +106,11 @@ export function QuizUpload({ onQuizUploaded }: QuizUploadProps) {
 						Validation errors:
 					</p>
 					{errors.map((e) => (
-						<p key={e.line} style={{ color: colors.error, fontSize: typography.sizes.sm }}>
-							Line {e.line}: {e.message}
+						<p
+							key={`${e.line}-${e.path ?? ""}-${e.row ?? ""}-${e.column ?? ""}`}
+							style={{ color: colors.error, fontSize: typography.sizes.sm }}
+						>
+							{errorLocation(e)}: {e.message}
 						</p>
 					))}
 				</div>
@@ -0,0 +1,62 @@
+import type { GameState } from "../hooks/useGameState";
+import { Leaderboard } from "./Leaderboard";
+import { colors, spacing, typography } from "./ui/tokens";
+
+const RULE_LABELS: Record<string, string> = {
+	stepped_decay: "Stepped Decay",
+	linear_decay: "Linear Decay",
+	fixed_score: "Fixed Score",
+	streak_bonus: "Streak Bonus",
+	position_race: "Position Race",
+	composite: "Combined",
+};
+
+interface RoundBannerProps {
+	gameState: GameState;
+}
+
+/** Interstitial shown between rounds: the next round's title, or the round just played. */
+export function RoundBanner({ gameState }: RoundBannerProps) {
+	const { phase, round, roundResult } = gameState;
+
+	if (phase === "round_ended" && roundResult) {
+		return (
+			<Leaderboard
+				entries={roundResult.leaderboard}
+				isFinal={false}
+				title={`${roundResult.title} Results`}
+			/>
+		);
+	}
+
+	if (phase !== "round_started" || !round) return null;
+
+	return (
+		<div style={{ textAlign: "center" }} aria-live="polite">
+			<p
+				style={{
+					color: colors.textSecondary,
+					fontSize: typography.sizes.md,
+					fontFamily: typography.fontDisplay,
+					margin: 0,
+				}}
+			>
+				Round {round.round_index + 1} of {round.total_rounds}
+			</p>
+			<h2
+				style={{
+					color: colors.primary,
+					fontSize: typography.sizes.xl,
+					fontFamily: typography.fontDisplay,
+					marginBottom: spacing.md,
+				}}
+			>
+				{round.title}
+			</h2>
+			<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+				{round.question_count} {round.question_count === 1 ? "question" : "questions"} ·{" "}
+				{round.time_limit_sec}s each · {round.scoring_rule_display_name ?? RULE_LABELS[round.scoring_rule]}
+			</p>
+		</div>
+	);
+}
@@ -0,0 +1,162 @@
+import { useEffect, useState } from "react";
+import type { GameState } from "../hooks/useGameState";
+import type { TiebreakContender } from "../services/messages";
+import { Card } from "./ui/Card";
+import { Timer } from "./ui/Timer";
+import { borderRadius, colors, spacing, typography } from "./ui/tokens";
+
+interface TiebreakProps {
+	gameState: GameState;
+	/** Present for players; contenders answer through it, everyone else spectates. */
+	onAnswer?: (selectedIndex: number) => void;
+}
+
+function names(contenders: TiebreakContender[]): string {
+	return contenders.map((c) => `${c.avatar} ${c.display_name}`).join(", ");
+}
+
+/** Sudden-death round between the players tied for first; the rest watch. */
+export function Tiebreak({ gameState, onAnswer }: TiebreakProps) {
+	const { tiebreak, tiebreakQuestion: question, tiebreakResult, answerCount } = gameState;
+	const [selectedIndex, setSelectedIndex] = useState<number | null>(null);
+
+	const questionIndex = question?.tiebreak_index;
+	// biome-ignore lint/correctness/useExhaustiveDependencies: questionIndex is intentionally used to reset state on question change
+	useEffect(() => {
+		setSelectedIndex(null);
+	}, [questionIndex]);
+
+	if (!tiebreak) return null;
+
+	const result =
+		tiebreakResult && tiebreakResult.tiebreak_index === questionIndex ? tiebreakResult : null;
+	const canAnswer = Boolean(question?.contender && onAnswer) && selectedIndex === null && !result;
+	const contenders = question?.contenders ?? tiebreak.contenders;
+
+	const handleSelect = (index: number) => {
+		if (!canAnswer || !onAnswer) return;
+		setSelectedIndex(index);
+		onAnswer(index);
+	};
+
+	const optionColor = (index: number) => {
+		if (result) return index === result.correct_index ? colors.success : colors.textSecondary;
+		return index === selectedIndex ? colors.primary : colors.text;
+	};
+
+	return (
+		<Card style={{ maxWidth: "600px", width: "100%" }}>
+			<div style={{ textAlign: "center", marginBottom: spacing.md }} aria-live="polite">
+				<h2
+					style={{
+						color: colors.primary,
+						fontSize: typography.sizes.xl,
+						fontFamily: typography.fontDisplay,
+						margin: 0,
+					}}
+				>
+					Sudden Death
+				</h2>
+				<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+					Tied on {tiebreak.score} points: {names(contenders)}
+				</p>
+				{onAnswer && !(question?.contender ?? tiebreak.contender) && (
+					<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+						You're watching — only the tied players answer.
+					</p>
+				)}
+				{!onAnswer && answerCount && (
+					<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+						{answerCount.answered}/{answerCount.total} answered
+					</p>
+				)}
+			</div>
+
+			{question && (
+				<>
+					<div
+						style={{
+							display: "flex",
+							justifyContent: "space-between",
+							alignItems: "center",
+							marginBottom: spacing.md,
+						}}
+					>
+						<span
+							style={{
+								color: colors.textSecondary,
+								fontSize: typography.sizes.sm,
+								fontFamily: typography.fontDisplay,
+							}}
+						>
+							Tiebreaker {question.tiebreak_index + 1}/{question.total_tiebreakers}
+						</span>
+						<Timer
+							key={question.tiebreak_index}
+							totalSeconds={question.time_limit_sec}
+							running={!result && selectedIndex === null}
+						/>
+					</div>
+					<h3
+						style={{
+							color: colors.text,
+							fontSize: typography.sizes.xl,
+							fontFamily: typography.fontBody,
+							marginBottom: spacing.lg,
+							textAlign: "center",
+							whiteSpace: "pre-wrap",
+						}}
+					>
+						{question.text}
+					</h3>
+					<div>
+						{question.options.map((option, i) => (
+							<button
+								key={option}
+								type="button"
+								onClick={() => handleSelect(i)}
+								disabled={!canAnswer}
+								style={{
+									padding: spacing.md,
+									marginBottom: spacing.sm,
+									borderRadius: borderRadius.md,
+									border: `1px solid ${colors.border}`,
+									borderLeft: `4px solid ${optionColor(i)}`,
+									backgroundColor: colors.background,
+									color: optionColor(i),
+									fontSize: typography.sizes.lg,
+									cursor: canAnswer ? "pointer" : "default",
+									textAlign: "left",
+									width: "100%",
+									fontFamily: typography.fontBody,
+									whiteSpace: "pre-wrap",
+								}}
+								aria-label={`Answer option ${i + 1}: ${option}`}
+							>
+								{option}
+							</button>
+						))}
+					</div>
+				</>
+			)}
+
+			{result && (
+				<div style={{ marginTop: spacing.md, textAlign: "center" }}>
+					<p style={{ color: colors.success, fontSize: typography.sizes.md, margin: 0 }}>
+						Answer: {result.correct_text}
+					</p>
+					{result.explanation && (
+						<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm }}>
+							{result.explanation}
+						</p>
+					)}
+					<p style={{ color: colors.textSecondary, fontSize: typography.sizes.sm, margin: 0 }}>
+						{result.eliminated.length > 0
+							? `Out: ${names(result.eliminated)}`
+							: "Nobody got it right — everyone stays in."}
+					</p>
+				</div>
+			)}
+		</Card>
+	);
+}
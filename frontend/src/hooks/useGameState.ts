@@ -1,7 +1,9 @@
 import { useCallback, useReducer } from "react";
 import {
 	type AnswerCountPayload,
+	type AnswerDistributionPayload,
 	type AnswerResultPayload,
+	type EliminationSettings,
 	type GameFinishedPayload,
 	type GameStartingPayload,
 	type LeaderboardEntryPayload,
@@ -9,12 +11,24 @@ import {
 	type PlayerJoinedPayload,
 	type PlayerLeftPayload,
 	type PlayerReconnectedPayload,
+	type PlayersEliminatedPayload,
 	type QuestionEndedPayload,
 	type QuestionPayload,
+	type RoundEndedPayload,
+	type RoundStartedPayload,
 	type ScoringRuleName,
 	type ScoringRuleSetPayload,
+	type ShuffleSettings,
+	type TeamLeaderboardEntryPayload,
+	type TeamsUpdatedPayload,
+	type TieBreaker,
+	type TieBreakerSetPayload,
+	type TiebreakQuestionPayload,
+	type TiebreakResultPayload,
+	type TiebreakStartedPayload,
 	type TimeLimitSetPayload,
 	type WsMessage,
+	type YourStandingPayload,
 } from "../services/messages";
 
 export type GamePhase =
@@ -22,6 +36,9 @@ export type GamePhase =
 	| "starting"
 	| "question"
 	| "question_ended"
+	| "round_started"
+	| "round_ended"
+	| "tiebreak"
 	| "finished"
 	| "paused";
 
@@ -33,10 +50,29 @@ export interface GameState {
 	currentQuestion: QuestionPayload | null;
 	answerResult: AnswerResultPayload | null;
 	answerCount: AnswerCountPayload | null;
+	/** How players answered the last question; host only. */
+	answerDistribution: AnswerDistributionPayload | null;
 	leaderboard: LeaderboardEntryPayload[];
+	/** This player's place after the last question; players only. */
+	standing: YourStandingPayload | null;
 	countdown: number;
 	scoringRule: ScoringRuleName;
 	timeLimitSec: number;
+	shuffle: ShuffleSettings;
+	tieBreaker: TieBreaker;
+	round: RoundStartedPayload | null;
+	roundResult: RoundEndedPayload | null;
+	/** Set once players tied for first go into sudden death. */
+	tiebreak: TiebreakStartedPayload | null;
+	tiebreakQuestion: TiebreakQuestionPayload | null;
+	tiebreakResult: TiebreakResultPayload | null;
+	/** Null until the host sets up teams or a player joins one. */
+	teams: TeamsUpdatedPayload | null;
+	/** Team standings after the last question; empty outside team games. */
+	teamLeaderboard: TeamLeaderboardEntryPayload[];
+	elimination: EliminationSettings;
+	/** Who went out or lost a life on the last question of an elimination game. */
+	eliminationResult: PlayersEliminatedPayload | null;
 }
 
 const initialState: GameState = {
@@ -47,10 +83,23 @@ const initialState: GameState = {
 	currentQuestion: null,
 	answerResult: null,
 	answerCount: null,
+	answerDistribution: null,
 	leaderboard: [],
+	standing: null,
 	countdown: 0,
 	scoringRule: "stepped_decay",
 	timeLimitSec: 20,
+	shuffle: { questions: false, options: false, seed: 0 },
+	tieBreaker: "none",
+	round: null,
+	roundResult: null,
+	tiebreak: null,
+	tiebreakQuestion: null,
+	tiebreakResult: null,
+	teams: null,
+	teamLeaderboard: [],
+	elimination: { enabled: false, lives: 1, max_answer_sec: null },
+	eliminationResult: null,
 };
 
 type Action = { type: "WS_MESSAGE"; message: WsMessage } | { type: "RESET" };
@@ -103,6 +152,8 @@ function reducer(state: GameState, action: Action): GameState {
 				currentQuestion: p,
 				answerResult: null,
 				answerCount: null,
+				answerDistribution: null,
+				eliminationResult: null,
 				scoringRule: p.scoring_rule,
 			};
 		}
@@ -112,11 +163,56 @@ function reducer(state: GameState, action: Action): GameState {
 			return { ...state, answerResult: message.payload as AnswerResultPayload };
 		case MSG.QUESTION_ENDED: {
 			const p = message.payload as QuestionEndedPayload;
-			return { ...state, phase: "question_ended", leaderboard: p.leaderboard };
+			return {
+				...state,
+				phase: "question_ended",
+				leaderboard: p.leaderboard,
+				teamLeaderboard: p.team_leaderboard ?? [],
+			};
 		}
+		case MSG.ANSWER_DISTRIBUTION:
+			return { ...state, answerDistribution: message.payload as AnswerDistributionPayload };
+		case MSG.YOUR_STANDING:
+			return { ...state, standing: message.payload as YourStandingPayload };
+		case MSG.ROUND_STARTED:
+			return {
+				...state,
+				phase: "round_started",
+				round: message.payload as RoundStartedPayload,
+				roundResult: null,
+			};
+		case MSG.ROUND_ENDED:
+			return {
+				...state,
+				phase: "round_ended",
+				roundResult: message.payload as RoundEndedPayload,
+			};
+		case MSG.TIEBREAK_STARTED:
+			return {
+				...state,
+				phase: "tiebreak",
+				tiebreak: message.payload as TiebreakStartedPayload,
+				tiebreakQuestion: null,
+				tiebreakResult: null,
+			};
+		case MSG.TIEBREAK_QUESTION:
+			return {
+				...state,
+				phase: "tiebreak",
+				tiebreakQuestion: message.payload as TiebreakQuestionPayload,
+				answerCount: null,
+			};
+		case MSG.TIEBREAK_RESULT:
+			return { ...state, tiebreakResult: message.payload as TiebreakResultPayload };
 		case MSG.GAME_FINISHED: {
 			const p = message.payload as GameFinishedPayload;
-			return { ...state, phase: "finished", leaderboard: p.leaderboard };
+			return {
+				...state,
+				phase: "finished",
+				leaderboard: p.leaderboard,
+				tieBreaker: p.tie_breaker,
+				teamLeaderboard: p.team_leaderboard ?? [],
+			};
 		}
 		case MSG.SCORING_RULE_SET: {
 			const p = message.payload as ScoringRuleSetPayload;
@@ -126,6 +222,18 @@ function reducer(state: GameState, action: Action): GameState {
 			const p = message.payload as TimeLimitSetPayload;
 			return { ...state, timeLimitSec: p.seconds };
 		}
+		case MSG.TIE_BREAKER_SET: {
+			const p = message.payload as TieBreakerSetPayload;
+			return { ...state, tieBreaker: p.tie_breaker };
+		}
+		case MSG.ELIMINATION_SET:
+			return { ...state, elimination: message.payload as EliminationSettings };
+		case MSG.PLAYERS_ELIMINATED:
+			return { ...state, eliminationResult: message.payload as PlayersEliminatedPayload };
+		case MSG.TEAMS_UPDATED:
+			return { ...state, teams: message.payload as TeamsUpdatedPayload };
+		case MSG.SHUFFLE_SET:
+			return { ...state, shuffle: message.payload as ShuffleSettings };
 		case MSG.GAME_PAUSED:
 			return { ...state, phase: "paused" };
 		case MSG.GAME_RESUMED:
@@ -2,13 +2,29 @@ import { useCallback, useState } from "react";
 import { HostDashboard } from "../components/HostDashboard";
 import { Leaderboard } from "../components/Leaderboard";
 import { Lobby } from "../components/Lobby";
+import { QuizLibrary } from "../components/QuizLibrary";
 import { QuizUpload } from "../components/QuizUpload";
+import { RoundBanner } from "../components/RoundBanner";
+import { Tiebreak } from "../components/Tiebreak";
 import { colors, spacing, typography } from "../components/ui/tokens";
 import { useGameState } from "../hooks/useGameState";
 import { useWebSocket } from "../hooks/useWebSocket";
-import type { QuizPreview } from "../services/api";
-import { createSession } from "../services/api";
-import { MSG, type ScoringRuleName } from "../services/messages";
+import type {
+	CreateSessionResponse,
+	DrawOptions,
+	LibraryQuizSummary,
+	QuestionDraw,
+	QuizPreview,
+} from "../services/api";
+import { createSession, createSessionFromLibrary } from "../services/api";
+import {
+	type EliminationSettings,
+	MSG,
+	type SetScoringRulePayload,
+	type SetShufflePayload,
+	type SetTeamsPayload,
+	type TieBreaker,
+} from "../services/messages";
 import { buildWsUrl } from "../services/ws-url";
 
 type HostPhase = "upload" | "lobby" | "playing" | "finished";
@@ -18,6 +34,8 @@ export function HostPage() {
 	const [joinCode, setJoinCode] = useState("");
 	const [wsUrl, setWsUrl] = useState<string | null>(null);
 	const [error, setError] = useState<string | null>(null);
+	const [questionCount, setQuestionCount] = useState("");
+	const [draw, setDraw] = useState<QuestionDraw | null>(null);
 
 	const { gameState, handleMessage } = useGameState();
 
@@ -39,9 +57,14 @@ export function HostPage() {
 		onMessage,
 	});
 
-	const handleQuizUploaded = async (preview: QuizPreview) => {
+	const startSession = async (create: () => Promise<CreateSessionResponse>) => {
 		try {
-			const session = await createSession(preview.quiz_id);
+			const session = await create();
+			// Quiz front-matter may seed the lobby settings
+			handleMessage({ type: MSG.SCORING_RULE_SET, payload: { rule: session.scoring_rule } });
+			handleMessage({ type: MSG.TIME_LIMIT_SET, payload: { seconds: session.time_limit_sec } });
+			handleMessage({ type: MSG.SHUFFLE_SET, payload: session.shuffle });
+			setDraw(session.draw);
 			setJoinCode(session.join_code);
 			setWsUrl(buildWsUrl(session.ws_url));
 			setPhase("lobby");
@@ -50,18 +73,48 @@ export function HostPage() {
 		}
 	};
 
+	// A blank count plays every question
+	const drawOptions = (): DrawOptions =>
+		Number(questionCount) > 0 ? { question_count: Number(questionCount) } : {};
+
+	const handleQuizUploaded = (preview: QuizPreview) =>
+		startSession(() => createSession(preview.quiz_id, drawOptions()));
+
+	const handleHostSaved = (quiz: LibraryQuizSummary) =>
+		startSession(() => createSessionFromLibrary(quiz.id, quiz.version, drawOptions()));
+
 	const handleStartGame = () => {
 		send({ type: MSG.START_GAME, payload: {} });
 	};
 
-	const handleScoringRuleChange = (rule: ScoringRuleName) => {
-		send({ type: MSG.SET_SCORING_RULE, payload: { rule } });
+	const handleScoringRuleChange = (rule: SetScoringRulePayload) => {
+		send({ type: MSG.SET_SCORING_RULE, payload: rule });
 	};
 
 	const handleTimeLimitChange = (seconds: number) => {
 		send({ type: MSG.SET_TIME_LIMIT, payload: { seconds } });
 	};
 
+	const handleTieBreakerChange = (tieBreaker: TieBreaker) => {
+		send({ type: MSG.SET_TIE_BREAKER, payload: { tie_breaker: tieBreaker } });
+	};
+
+	const handleShuffleChange = (shuffle: SetShufflePayload) => {
+		send({ type: MSG.SET_SHUFFLE, payload: shuffle });
+	};
+
+	const handleEliminationChange = (elimination: EliminationSettings) => {
+		send({ type: MSG.SET_ELIMINATION, payload: elimination });
+	};
+
+	const handleTeamsChange = (teams: SetTeamsPayload) => {
+		send({ type: MSG.SET_TEAMS, payload: teams });
+	};
+
+	const handleAutoBalanceTeams = () => {
+		send({ type: MSG.AUTO_BALANCE_TEAMS, payload: {} });
+	};
+
 	const handleEndQuestion = () => {
 		send({ type: MSG.END_QUESTION, payload: {} });
 	};
@@ -96,7 +149,37 @@ export function HostPage() {
 
 			{error && <p style={{ color: colors.error, marginBottom: spacing.md }}>{error}</p>}
 
-			{phase === "upload" && <QuizUpload onQuizUploaded={handleQuizUploaded} />}
+			{phase === "upload" && (
+				<>
+					<label
+						style={{
+							color: colors.textSecondary,
+							fontSize: typography.sizes.sm,
+							marginBottom: spacing.md,
+						}}
+					>
+						Questions to play{" "}
+						<input
+							type="number"
+							min={1}
+							placeholder="All"
+							value={questionCount}
+							onChange={(e) => setQuestionCount(e.target.value)}
+							style={{
+								width: "80px",
+								padding: `${spacing.xs} ${spacing.sm}`,
+								border: `1px solid ${colors.border}`,
+								borderRadius: "4px",
+								backgroundColor: colors.surface,
+								color: colors.text,
+								fontFamily: typography.fontBody,
+							}}
+						/>
+					</label>
+					<QuizUpload onQuizUploaded={handleQuizUploaded} />
+					<QuizLibrary onHost={handleHostSaved} />
+				</>
+			)}
 
 			{phase === "lobby" && (
 				<Lobby
@@ -105,16 +188,34 @@ export function HostPage() {
 					isHost={true}
 					onStartGame={handleStartGame}
 					onScoringRuleChange={handleScoringRuleChange}
+					onTieBreakerChange={handleTieBreakerChange}
 					timeLimitSec={gameState.timeLimitSec}
 					onTimeLimitChange={handleTimeLimitChange}
+					onShuffleChange={handleShuffleChange}
+					onEliminationChange={handleEliminationChange}
+					onTeamsChange={handleTeamsChange}
+					onAutoBalanceTeams={handleAutoBalanceTeams}
+					draw={draw}
 				/>
 			)}
 
-			{phase === "playing" && (
-				<HostDashboard gameState={gameState} onEndQuestion={handleEndQuestion} />
+			{phase === "playing" &&
+				(gameState.phase === "round_started" || gameState.phase === "round_ended" ? (
+					<RoundBanner gameState={gameState} />
+				) : gameState.phase === "tiebreak" ? (
+					<Tiebreak gameState={gameState} />
+				) : (
+					<HostDashboard gameState={gameState} onEndQuestion={handleEndQuestion} />
+				))}
+
+			{phase === "finished" && (
+				<Leaderboard
+					entries={gameState.leaderboard}
+					isFinal={true}
+					tieBreaker={gameState.tieBreaker}
+					teamEntries={gameState.teamLeaderboard}
+				/>
 			)}
-
-			{phase === "finished" && <Leaderboard entries={gameState.leaderboard} isFinal={true} />}
 		</main>
 	);
 }
@@ -1,8 +1,10 @@
 import { useCallback, useState } from "react";
 import { JoinForm } from "../components/JoinForm";
-import { Leaderboard } from "../components/Leaderboard";
+import { EliminationNote, Leaderboard, Movement } from "../components/Leaderboard";
 import { Lobby } from "../components/Lobby";
 import { Question } from "../components/Question";
+import { RoundBanner } from "../components/RoundBanner";
+import { Tiebreak } from "../components/Tiebreak";
 import { colors, spacing, typography } from "../components/ui/tokens";
 import { useGameState } from "../hooks/useGameState";
 import { useWebSocket } from "../hooks/useWebSocket";
@@ -10,7 +12,16 @@ import type { SessionInfo } from "../services/api";
 import { MSG } from "../services/messages";
 import { buildWsUrl } from "../services/ws-url";
 
-type PlayerPhase = "join" | "lobby" | "starting" | "question" | "question_ended" | "finished";
+type PlayerPhase =
+	| "join"
+	| "lobby"
+	| "starting"
+	| "round_started"
+	| "question"
+	| "question_ended"
+	| "round_ended"
+	| "tiebreak"
+	| "finished";
 
 export function PlayerPage() {
 	const [phase, setPhase] = useState<PlayerPhase>("join");
@@ -33,6 +44,15 @@ export function PlayerPage() {
 				case MSG.QUESTION_ENDED:
 					setPhase("question_ended");
 					break;
+				case MSG.ROUND_STARTED:
+					setPhase("round_started");
+					break;
+				case MSG.ROUND_ENDED:
+					setPhase("round_ended");
+					break;
+				case MSG.TIEBREAK_STARTED:
+					setPhase("tiebreak");
+					break;
 				case MSG.GAME_FINISHED:
 					setPhase("finished");
 					break;
@@ -46,16 +66,19 @@ export function PlayerPage() {
 		onMessage,
 	});
 
-	const handleJoined = (info: SessionInfo, displayName: string, avatar: string) => {
+	const handleJoined = (info: SessionInfo, displayName: string, avatar: string, team: string) => {
 		setJoinCode(info.join_code);
+		const teamParam = team ? `&team=${encodeURIComponent(team)}` : "";
 		setWsUrl(
 			buildWsUrl(
-				`${info.ws_url}?name=${encodeURIComponent(displayName)}&avatar=${encodeURIComponent(avatar)}`,
+				`${info.ws_url}?name=${encodeURIComponent(displayName)}&avatar=${encodeURIComponent(avatar)}${teamParam}`,
 			),
 		);
 		setPhase("lobby");
 	};
 
+	const eliminated = gameState.standing?.eliminated ?? false;
+
 	const handleAnswer = (selectedIndex: number) => {
 		if (gameState.currentQuestion) {
 			send({
@@ -68,6 +91,18 @@ export function PlayerPage() {
 		}
 	};
 
+	const handleTiebreakAnswer = (selectedIndex: number) => {
+		if (gameState.tiebreakQuestion) {
+			send({
+				type: MSG.SUBMIT_TIEBREAK_ANSWER,
+				payload: {
+					tiebreak_index: gameState.tiebreakQuestion.tiebreak_index,
+					selected_index: selectedIndex,
+				},
+			});
+		}
+	};
+
 	return (
 		<main
 			style={{
@@ -106,6 +141,10 @@ export function PlayerPage() {
 				</div>
 			)}
 
+			{(phase === "round_started" || phase === "round_ended") && (
+				<RoundBanner gameState={gameState} />
+			)}
+
 			{(phase === "question" || phase === "question_ended") && gameState.currentQuestion && (
 				<Question
 					questionIndex={gameState.currentQuestion.question_index}
@@ -117,10 +156,52 @@ export function PlayerPage() {
 					answerResult={gameState.answerResult}
 					phase={phase}
 					scoringRule={gameState.scoringRule}
+					scoringRuleDisplayName={gameState.currentQuestion.scoring_rule_display_name}
+					weight={gameState.currentQuestion.weight}
+					spectating={eliminated}
 				/>
 			)}
 
-			{phase === "finished" && <Leaderboard entries={gameState.leaderboard} isFinal={true} />}
+			{eliminated && phase === "question" && (
+				<p style={{ color: colors.textSecondary, marginTop: spacing.md }}>
+					You're out — watching the rest of the game.
+				</p>
+			)}
+
+			{phase === "question_ended" && gameState.standing && (
+				<p
+					aria-live="polite"
+					style={{
+						color: colors.text,
+						fontSize: typography.sizes.md,
+						fontFamily: typography.fontDisplay,
+						marginTop: spacing.md,
+					}}
+				>
+					You're #{gameState.standing.rank} of {gameState.standing.total_players}{" "}
+					<Movement
+						pointsGained={gameState.standing.points_gained}
+						rankChange={gameState.standing.rank_change}
+					/>
+					{gameState.standing.eliminated
+						? " · You're out"
+						: gameState.standing.lives_left !== null &&
+							` · ${gameState.standing.lives_left} ${gameState.standing.lives_left === 1 ? "life" : "lives"} left`}
+				</p>
+			)}
+
+			{phase === "question_ended" && <EliminationNote result={gameState.eliminationResult} />}
+
+			{phase === "tiebreak" && <Tiebreak gameState={gameState} onAnswer={handleTiebreakAnswer} />}
+
+			{phase === "finished" && (
+				<Leaderboard
+					entries={gameState.leaderboard}
+					isFinal={true}
+					tieBreaker={gameState.tieBreaker}
+					teamEntries={gameState.teamLeaderboard}
+				/>
+			)}
 		</main>
 	);
 }
@@ -1,8 +1,60 @@
+import type { ScoringRuleName, ShuffleSettings } from "./messages";
+
 export interface QuizPreview {
 	title: string;
 	question_count: number;
-	preview: { text: string; option_count: number }[];
+	preview: {
+		text: string;
+		option_count: number;
+		has_explanation: boolean;
+		has_media: boolean;
+	}[];
 	quiz_id: string;
+	asset_count: number;
+	metadata: QuizMetadata;
+	warnings: ParseWarningMessage[];
+}
+
+export interface QuizMetadata {
+	author?: string;
+	description?: string;
+	scoring_rule?: ScoringRuleName;
+	time_limit_sec?: number;
+	shuffle_questions: boolean;
+	shuffle_options: boolean;
+	tags: string[];
+	min_options?: number;
+	max_options?: number;
+}
+
+/** Server-enforced limits, for guiding quiz authors before upload. */
+export interface ServerInfo {
+	option_limits: { min: number; max: number };
+	time_limit_sec: { min: number; max: number; default: number };
+	max_upload_bytes: number;
+}
+
+/** A rule hosts can pick, from `GET /api/scoring-rules`. */
+export interface ScoringRuleInfo {
+	name: string;
+	display_name: string;
+	/** JSON Schema for the rule's `set_scoring_rule` parameters. */
+	params_schema: Record<string, unknown>;
+}
+
+/** The latest revision of a saved quiz, or one revision in its history. */
+export interface LibraryQuizSummary {
+	id: string;
+	version: number;
+	/** Unix timestamp in seconds. */
+	saved_at: number;
+	title: string;
+	question_count: number;
+}
+
+export interface SaveQuizResponse {
+	quiz: LibraryQuizSummary;
+	warnings: ParseWarningMessage[];
 }
 
 export interface SessionInfo {
@@ -17,12 +69,96 @@ export interface CreateSessionResponse {
 	join_code: string;
 	session_status: string;
 	ws_url: string;
+	scoring_rule: ScoringRuleName;
+	time_limit_sec: number;
+	shuffle: ShuffleSettings;
+	total_questions: number;
+	draw: QuestionDraw | null;
+}
+
+/** Questions a session drew from a larger quiz, by index into the full quiz. */
+export interface QuestionDraw {
+	seed: number;
+	bank_size: number;
+	questions: { bank_index: number; text: string; tags: string[] }[];
+}
+
+/** Play a random subset of the quiz instead of every question. */
+export interface DrawOptions {
+	question_count?: number;
+	tag_quotas?: Record<string, number>;
+	draw_seed?: number;
+}
+
+/** 1-based character range in the uploaded file; `end_column` is exclusive. */
+export interface TextSpan {
+	start_line: number;
+	start_column: number;
+	end_line: number;
+	end_column: number;
+}
+
+/** A quiz validation error: text files report `line`, JSON/YAML a `path`, CSV a `row`/`column`. */
+export interface ParseErrorMessage {
+	line: number;
+	path?: string;
+	row?: number;
+	column?: string;
+	span?: TextSpan;
+	message: string;
+}
+
+export type WarningCode =
+	| "duplicate_question"
+	| "duplicate_option"
+	| "long_text"
+	| "ignored_title"
+	| "suspicious_whitespace"
+	| "too_many_questions";
+
+/** A non-fatal issue, located like `ParseErrorMessage`. */
+export interface ParseWarningMessage {
+	code: WarningCode;
+	line: number;
+	path?: string;
+	row?: number;
+	span?: TextSpan;
+	message: string;
+}
+
+export interface LintResult {
+	valid: boolean;
+	errors: ParseErrorMessage[];
+	warnings: ParseWarningMessage[];
 }
 
 export interface ApiError {
 	error: string;
 	message: string;
-	messages?: { line: number; message: string }[];
+	messages?: ParseErrorMessage[];
+}
+
+export async function getServerInfo(): Promise<ServerInfo> {
+	const response = await fetch("/api/info");
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+
+	return response.json();
+}
+
+/** Built-in rules first, then any the server's embedding application registered. */
+export async function getScoringRules(): Promise<ScoringRuleInfo[]> {
+	const response = await fetch("/api/scoring-rules");
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+
+	return response.json();
 }
 
 export async function uploadQuiz(file: File): Promise<QuizPreview> {
@@ -42,11 +178,84 @@ export async function uploadQuiz(file: File): Promise<QuizPreview> {
 	return response.json();
 }
 
-export async function createSession(quizId: string): Promise<CreateSessionResponse> {
+/** Validate a quiz file without uploading it for play. */
+export async function lintQuiz(file: File): Promise<LintResult> {
+	const formData = new FormData();
+	formData.append("quiz_file", file);
+
+	const response = await fetch("/api/quiz/lint", {
+		method: "POST",
+		body: formData,
+	});
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+
+	return response.json();
+}
+
+export async function listLibraryQuizzes(): Promise<LibraryQuizSummary[]> {
+	const response = await fetch("/api/quizzes");
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+
+	const body: { quizzes: LibraryQuizSummary[] } = await response.json();
+	return body.quizzes;
+}
+
+/** Save a quiz file to the library as the next revision of `id`. */
+export async function saveLibraryQuiz(id: string, file: File): Promise<SaveQuizResponse> {
+	const formData = new FormData();
+	formData.append("quiz_file", file);
+
+	const response = await fetch(`/api/quizzes/${encodeURIComponent(id)}`, {
+		method: "PUT",
+		body: formData,
+	});
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+
+	return response.json();
+}
+
+export async function deleteLibraryQuiz(id: string): Promise<void> {
+	const response = await fetch(`/api/quizzes/${encodeURIComponent(id)}`, { method: "DELETE" });
+
+	if (!response.ok) {
+		const error: ApiError = await response.json();
+		throw error;
+	}
+}
+
+export async function createSession(
+	quizId: string,
+	draw: DrawOptions = {},
+): Promise<CreateSessionResponse> {
+	return postSession({ quiz_id: quizId, ...draw });
+}
+
+/** Start a session from a saved quiz, at its latest revision unless `version` is given. */
+export async function createSessionFromLibrary(
+	libraryId: string,
+	version?: number,
+	draw: DrawOptions = {},
+): Promise<CreateSessionResponse> {
+	return postSession({ library_id: libraryId, version, ...draw });
+}
+
+async function postSession(body: object): Promise<CreateSessionResponse> {
 	const response = await fetch("/api/sessions", {
 		method: "POST",
 		headers: { "Content-Type": "application/json" },
-		body: JSON.stringify({ quiz_id: quizId }),
+		body: JSON.stringify(body),
 	});
 
 	if (!response.ok) {
@@ -11,6 +11,8 @@ export interface PlayerJoinedPayload {
 	display_name: string;
 	avatar: string;
 	player_count: number;
+	/** The team the player joined, if any. */
+	team?: string | null;
 }
 
 export interface PlayerLeftPayload {
@@ -31,6 +33,8 @@ export interface PlayerReconnectedPayload {
 export interface GameStartingPayload {
 	countdown_sec: number;
 	total_questions: number;
+	/** 0 when the quiz is not split into rounds. */
+	total_rounds: number;
 }
 
 export interface QuestionPayload {
@@ -38,8 +42,20 @@ export interface QuestionPayload {
 	total_questions: number;
 	text: string;
 	options: string[];
+	/** Session-scoped URL of the question's image/audio, if any. */
+	media_url: string | null;
+	/** Per-option media URLs, parallel to `options`. */
+	option_media_urls: (string | null)[];
 	time_limit_sec: number;
 	scoring_rule: ScoringRuleName;
+	/** e.g. "Linear Decay + Streak" for a composite rule. */
+	scoring_rule_display_name: string;
+	/** True for opinion polls, which have no correct answer and award no points. */
+	poll: boolean;
+	/** Null when the quiz is not split into rounds. */
+	round_index: number | null;
+	/** Points multiplier: 2 for a double-points question, 1 normally. */
+	weight: number;
 }
 
 export interface AnswerCountPayload {
@@ -48,12 +64,17 @@ export interface AnswerCountPayload {
 }
 
 export interface AnswerResultPayload {
+	/** Present (and true) only when the answered question is a poll. */
+	poll?: boolean;
 	correct: boolean;
 	points_awarded: number;
-	correct_index: number;
+	/** Null for poll questions. */
+	correct_index: number | null;
 	streak_multiplier: number;
 	/** 1-based rank among correct responders; present only for PositionRace + correct answer. */
 	position?: number;
+	/** Points deducted for a wrong answer, already included in `points_awarded`. */
+	penalty: number;
 }
 
 export interface LeaderboardEntryPayload {
@@ -63,17 +84,97 @@ export interface LeaderboardEntryPayload {
 	score: number;
 	correct_count: number;
 	is_winner?: boolean;
+	/** Movement since the previous question; present in `question_ended` only. */
+	points_gained?: number;
+	/** Null after the first question. */
+	previous_rank?: number | null;
+	/** Places climbed: positive moved up, negative moved down. */
+	rank_change?: number;
+	/** Out of an elimination game. */
+	eliminated?: boolean;
+}
+
+/** Sent to each player after every question, wherever they are in the standings. */
+export interface YourStandingPayload {
+	rank: number;
+	total_players: number;
+	score: number;
+	points_gained: number;
+	previous_rank: number | null;
+	rank_change: number;
+	/** Null outside an elimination game. */
+	lives_left: number | null;
+	eliminated: boolean;
 }
 
 export interface QuestionEndedPayload {
-	correct_index: number;
-	correct_text: string;
+	/** Present (and true) only when the question was a poll. */
+	poll?: boolean;
+	/** Null for poll questions. */
+	correct_index: number | null;
+	/** Null for poll questions. */
+	correct_text: string | null;
+	/** Per-option vote counts; present only for poll questions. */
+	votes?: number[];
+	/** Author-supplied "why" for the answer; null when the question has none. */
+	explanation: string | null;
+	leaderboard: LeaderboardEntryPayload[];
+	/** Empty unless the session is a team game. */
+	team_leaderboard: TeamLeaderboardEntryPayload[];
+}
+
+/** Host-only companion to `question_ended`; option counts are in the host's order. */
+export interface AnswerDistributionPayload {
+	question_index: number;
+	poll: boolean;
+	/** Players who picked each option. */
+	picks: number[];
+	/** Null for polls. */
+	correct_index: number | null;
+	correct_count: number | null;
+	/** Share of all players, answered or not; null for polls. */
+	correct_percentage: number | null;
+	answered: number;
+	no_answer: number;
+	/** Null when nobody answered. */
+	average_time_ms: number | null;
+	median_time_ms: number | null;
+}
+
+export interface RoundStartedPayload {
+	round_index: number;
+	total_rounds: number;
+	title: string;
+	question_count: number;
+	first_question_index: number;
+	/** The round's own rule and time limit, or the session's. */
+	scoring_rule: ScoringRuleName;
+	scoring_rule_display_name: string;
+	time_limit_sec: number;
+	countdown_sec: number;
+}
+
+export interface RoundEndedPayload {
+	round_index: number;
+	total_rounds: number;
+	title: string;
+	/** Standings counting only this round's questions. */
 	leaderboard: LeaderboardEntryPayload[];
 }
 
 export interface GameFinishedPayload {
 	leaderboard: LeaderboardEntryPayload[];
 	total_questions: number;
+	/** The orderings used, so the game can be replayed with the same seed. */
+	shuffle: ShuffleSettings;
+	/** Which questions were drawn from the quiz, when the session played a subset. */
+	draw: { seed: number; bank_size: number; question_indices: number[] } | null;
+	/** How equal scores were ordered in `leaderboard`. */
+	tie_breaker: TieBreaker;
+	/** Empty unless the session is a team game. */
+	team_leaderboard: TeamLeaderboardEntryPayload[];
+	team_aggregation: TeamAggregation;
+	elimination: EliminationSettings;
 }
 
 export interface GamePausedPayload {
@@ -113,14 +214,166 @@ export type ScoringRuleName =
 	| "linear_decay"
 	| "fixed_score"
 	| "streak_bonus"
-	| "position_race";
+	| "position_race"
+	| "composite"
+	// A custom rule registered by the application embedding the server
+	| (string & {});
+
+/** Rule parameters; which ones apply depends on the rule, and omitted ones keep their defaults. */
+export interface ScoringRuleParams {
+	max_points?: number;
+	/** stepped_decay: seconds per step. */
+	step_sec?: number;
+	/** streak_bonus: multiplier added per consecutive correct answer. */
+	streak_increment?: number;
+	/** streak_bonus: highest multiplier a streak reaches. */
+	streak_cap?: number;
+	/** position_race: points by arrival order; the last entry covers later positions. */
+	schedule?: number[];
+	/** composite: the rule whose points the modifiers adjust (not itself composite). */
+	base?: ScoringRuleName | SetScoringRulePayload;
+	/** composite: applied to the base points in order. */
+	modifiers?: ScoringModifier[];
+}
+
+export type ScoringModifier =
+	| { modifier: "streak"; streak_increment?: number; streak_cap?: number }
+	/** Extra points for the first correct answers; none once the schedule runs out. */
+	| { modifier: "position_bonus"; schedule?: number[] }
+	/** Points taken for a wrong answer; unanswered questions cost nothing. */
+	| { modifier: "wrong_answer_penalty"; points?: number };
 
-export interface SetScoringRulePayload {
+export interface SetScoringRulePayload extends ScoringRuleParams {
 	rule: ScoringRuleName;
 }
 
-export interface ScoringRuleSetPayload {
+/** The rule with every parameter spelled out. */
+export interface ScoringRuleSetPayload extends ScoringRuleParams {
 	rule: ScoringRuleName;
+	display_name: string;
+}
+
+// === Shuffle Types ===
+
+/** Question order is shared by the session; option order differs per player. */
+export interface ShuffleSettings {
+	questions: boolean;
+	options: boolean;
+	seed: number;
+}
+
+/** Omit `seed` to draw a new one. */
+export interface SetShufflePayload {
+	questions: boolean;
+	options: boolean;
+	seed?: number;
+}
+
+// === Tie-Breaker Types ===
+
+/**
+ * `none` lets equal scores share a rank; the others order them. `sudden_death`
+ * plays the quiz's tiebreaker questions among the players tied for first.
+ */
+export type TieBreaker = "none" | "answer_time" | "correct_count" | "sudden_death";
+
+export interface SetTieBreakerPayload {
+	tie_breaker: TieBreaker;
+}
+
+export interface TieBreakerSetPayload {
+	tie_breaker: TieBreaker;
+}
+
+// === Team Types ===
+
+/** How a team's score is built from its members' scores. */
+export type TeamAggregation = "sum" | "average" | "best";
+
+export interface TeamLeaderboardEntryPayload {
+	rank: number;
+	team: string;
+	score: number;
+	correct_count: number;
+	member_count: number;
+	is_winner?: boolean;
+}
+
+export interface TeamMember {
+	player_id: string;
+	display_name: string;
+}
+
+export interface TeamsUpdatedPayload {
+	teams: { name: string; members: TeamMember[] }[];
+	aggregation: TeamAggregation;
+}
+
+/** An empty `names` list ends team play; `aggregation` is kept when left out. */
+export interface SetTeamsPayload {
+	names: string[];
+	aggregation?: TeamAggregation;
+}
+
+// === Elimination Types ===
+
+/** Players who miss `lives` questions are out; with `max_answer_sec`, slow answers count as misses. */
+export interface EliminationSettings {
+	enabled: boolean;
+	lives: number;
+	max_answer_sec: number | null;
+}
+
+export interface EliminatedPlayer {
+	display_name: string;
+	avatar: string;
+}
+
+/** Broadcast after each scored question of an elimination game. */
+export interface PlayersEliminatedPayload {
+	question_index: number;
+	eliminated: EliminatedPlayer[];
+	lost_life: (EliminatedPlayer & { lives_left: number })[];
+	/** Players still in the game. */
+	remaining: number;
+}
+
+// === Sudden-Death Tiebreak Types ===
+
+export interface TiebreakContender {
+	display_name: string;
+	avatar: string;
+}
+
+export interface TiebreakStartedPayload {
+	contenders: TiebreakContender[];
+	/** The score the contenders are tied on. */
+	score: number;
+	total_tiebreakers: number;
+	/** Whether the receiving player answers; false for the host and spectators. */
+	contender: boolean;
+}
+
+export interface TiebreakQuestionPayload {
+	tiebreak_index: number;
+	total_tiebreakers: number;
+	text: string;
+	options: string[];
+	media_url: string | null;
+	option_media_urls: (string | null)[];
+	time_limit_sec: number;
+	contenders: TiebreakContender[];
+	contender: boolean;
+}
+
+export interface TiebreakResultPayload {
+	tiebreak_index: number;
+	correct_index: number;
+	correct_text: string;
+	explanation: string | null;
+	/** Still tied after this question; everyone survives when nobody was correct. */
+	survivors: TiebreakContender[];
+	eliminated: TiebreakContender[];
 }
 
 // === Client → Server Payloads ===
@@ -130,6 +383,11 @@ export interface SubmitAnswerPayload {
 	selected_index: number;
 }
 
+export interface SubmitTiebreakAnswerPayload {
+	tiebreak_index: number;
+	selected_index: number;
+}
+
 // === Message Type Constants ===
 export const MSG = {
 	// Server → Client
@@ -141,6 +399,10 @@ export const MSG = {
 	ANSWER_COUNT: "answer_count",
 	ANSWER_RESULT: "answer_result",
 	QUESTION_ENDED: "question_ended",
+	YOUR_STANDING: "your_standing",
+	ANSWER_DISTRIBUTION: "answer_distribution",
+	ROUND_STARTED: "round_started",
+	ROUND_ENDED: "round_ended",
 	GAME_FINISHED: "game_finished",
 	GAME_PAUSED: "game_paused",
 	GAME_RESUMED: "game_resumed",
@@ -160,4 +422,28 @@ export const MSG = {
 	END_QUESTION: "end_question",
 	// Server → Client (time limit)
 	TIME_LIMIT_SET: "time_limit_set",
+	// Client → Server (shuffle)
+	SET_SHUFFLE: "set_shuffle",
+	// Server → Client (shuffle, host only)
+	SHUFFLE_SET: "shuffle_set",
+	// Client → Server (tie-breaker)
+	SET_TIE_BREAKER: "set_tie_breaker",
+	// Server → Client (tie-breaker)
+	TIE_BREAKER_SET: "tie_breaker_set",
+	// Server → Client (sudden-death tiebreak)
+	TIEBREAK_STARTED: "tiebreak_started",
+	TIEBREAK_QUESTION: "tiebreak_question",
+	TIEBREAK_RESULT: "tiebreak_result",
+	// Client → Server (sudden-death tiebreak)
+	SUBMIT_TIEBREAK_ANSWER: "submit_tiebreak_answer",
+	// Client → Server (elimination)
+	SET_ELIMINATION: "set_elimination",
+	// Server → Client (elimination)
+	ELIMINATION_SET: "elimination_set",
+	PLAYERS_ELIMINATED: "players_eliminated",
+	// Client → Server (teams)
+	SET_TEAMS: "set_teams",
+	AUTO_BALANCE_TEAMS: "auto_balance_teams",
+	// Server → Client (teams)
+	TEAMS_UPDATED: "teams_updated",
 } as const;
@@ -140,7 +140,7 @@ describe("JoinForm", () => {
 		fireEvent.click(screen.getByRole("button", { name: "Join Game" }));
 
 		await waitFor(() => {
-			expect(mockOnJoined).toHaveBeenCalledWith(mockSession, "Alice", "🙂");
+			expect(mockOnJoined).toHaveBeenCalledWith(mockSession, "Alice", "🙂", "");
 		});
 	});
 
@@ -170,7 +170,35 @@ describe("JoinForm", () => {
 		fireEvent.click(screen.getByRole("button", { name: "Join Game" }));
 
 		await waitFor(() => {
-			expect(mockOnJoined).toHaveBeenCalledWith(mockSession, "Alice", "🦁");
+			expect(mockOnJoined).toHaveBeenCalledWith(mockSession, "Alice", "🦁", "");
+		});
+	});
+
+	it("calls onJoined with the trimmed team name", async () => {
+		const mockSession: SessionInfo = {
+			join_code: "ABC123",
+			ws_url: "/ws/player/ABC123",
+			session_status: "lobby",
+			player_count: 0,
+			quiz_title: "Test Quiz",
+		};
+		mockGetSession.mockResolvedValueOnce(mockSession);
+
+		render(<JoinForm onJoined={mockOnJoined} />);
+
+		fireEvent.change(screen.getByPlaceholderText("Enter 6-character code"), {
+			target: { value: "ABC123" },
+		});
+		fireEvent.change(screen.getByPlaceholderText("Your name"), {
+			target: { value: "Alice" },
+		});
+		fireEvent.change(screen.getByLabelText("Team (optional)"), {
+			target: { value: " Red " },
+		});
+		fireEvent.click(screen.getByRole("button", { name: "Join Game" }));
+
+		await waitFor(() => {
+			expect(mockOnJoined).toHaveBeenCalledWith(mockSession, "Alice", "🙂", "Red");
 		});
 	});
 
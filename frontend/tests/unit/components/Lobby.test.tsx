@@ -11,10 +11,23 @@ const emptyGameState: GameState = {
 	currentQuestion: null,
 	answerResult: null,
 	answerCount: null,
+	answerDistribution: null,
 	leaderboard: [],
+	standing: null,
 	countdown: 0,
 	scoringRule: "stepped_decay",
 	timeLimitSec: 20,
+	shuffle: { questions: false, options: false, seed: 0 },
+	tieBreaker: "none",
+	round: null,
+	roundResult: null,
+	tiebreak: null,
+	tiebreakQuestion: null,
+	tiebreakResult: null,
+	teams: null,
+	teamLeaderboard: [],
+	elimination: { enabled: false, lives: 1, max_answer_sec: null },
+	eliminationResult: null,
 };
 
 describe("Lobby — scoring rule selector", () => {
@@ -72,4 +72,31 @@ describe("useGameState", () => {
 		expect(result.current.gameState.players).toHaveLength(1);
 		expect(result.current.gameState.players[0].avatar).toBe("🦁");
 	});
+
+	it("tracks elimination settings and clears the last result on the next question", () => {
+		const { result } = renderHook(() => useGameState());
+
+		act(() => {
+			result.current.handleMessage({
+				type: "elimination_set",
+				payload: { enabled: true, lives: 2, max_answer_sec: null },
+			});
+			result.current.handleMessage({
+				type: "players_eliminated",
+				payload: { question_index: 0, eliminated: [], lost_life: [], remaining: 3 },
+			});
+		});
+
+		expect(result.current.gameState.elimination.lives).toBe(2);
+		expect(result.current.gameState.eliminationResult?.remaining).toBe(3);
+
+		act(() => {
+			result.current.handleMessage({
+				type: "question",
+				payload: { question_index: 1, scoring_rule: "stepped_decay" },
+			});
+		});
+
+		expect(result.current.gameState.eliminationResult).toBeNull();
+	});
 });